@@ -39,6 +39,12 @@ fn clif_sig_from_fn_abi<'tcx>(
         | Conv::AmdGpuKernel
         | Conv::AvrInterrupt
         | Conv::AvrNonBlockingInterrupt => todo!("{:?}", fn_abi.conv),
+        Conv::CheriotCompartmentCall | Conv::CheriotCompartmentCallee | Conv::CheriCCall => {
+            tcx.sess.fatal(&format!(
+                "the {:?} calling convention is not supported by Cranelift",
+                fn_abi.conv
+            ))
+        }
     };
     let inputs = fn_abi.args.iter().map(|arg_abi| arg_abi.get_abi_param(tcx).into_iter()).flatten();
 
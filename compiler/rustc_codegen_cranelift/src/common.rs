@@ -7,7 +7,7 @@
     FnAbiError, FnAbiOfHelpers, FnAbiRequest, LayoutError, LayoutOfHelpers,
 };
 use rustc_span::SourceFile;
-use rustc_target::abi::call::FnAbi;
+use rustc_target::abi::call::{AdjustForForeignAbiError, FnAbi};
 use rustc_target::abi::{Integer, Primitive};
 use rustc_target::spec::{HasTargetSpec, Target};
 
@@ -455,7 +455,9 @@ fn handle_fn_abi_err(
         span: Span,
         fn_abi_request: FnAbiRequest<'tcx>,
     ) -> ! {
-        if let FnAbiError::Layout(LayoutError::SizeOverflow(_)) = err {
+        if let FnAbiError::Layout(LayoutError::SizeOverflow(_))
+        | FnAbiError::AdjustForForeignAbi(AdjustForForeignAbiError::MissingSealedPair) = err
+        {
             self.0.sess.span_fatal(span, &err.to_string())
         } else {
             match fn_abi_request {
@@ -817,6 +817,42 @@ fn swap(bcx: &mut FunctionBuilder<'_>, v: Value) -> Value {
             ret.write_cvalue(fx, CValue::by_val(val, fx.layout_of(fx.tcx.types.u8)));
         }
 
+        // Cranelift has no CHERI targets, so capabilities are exactly equal if their addresses are.
+        sym::cheri_cap_equal_exact => {
+            intrinsic_args!(fx, args => (a, b); intrinsic);
+
+            let val = crate::num::codegen_ptr_binop(fx, BinOp::Eq, a, b);
+            ret.write_cvalue(fx, val);
+        }
+
+        // Pointers are plain addresses, so they are queried like unsealed capabilities covering
+        // the whole address space with every permission, which are only invalid if null.
+        sym::cheri_cap_base_get
+        | sym::cheri_cap_length_get
+        | sym::cheri_cap_perms_get
+        | sym::cheri_cap_sealed_get
+        | sym::cheri_cap_tag_get
+        | sym::cheri_cap_type_get => {
+            intrinsic_args!(fx, args => (ptr); intrinsic);
+            let ptr = ptr.load_scalar(fx);
+
+            let val = match intrinsic {
+                sym::cheri_cap_base_get | sym::cheri_cap_type_get => {
+                    fx.bcx.ins().iconst(fx.pointer_type, 0)
+                }
+                sym::cheri_cap_length_get | sym::cheri_cap_perms_get => {
+                    fx.bcx.ins().iconst(fx.pointer_type, -1)
+                }
+                sym::cheri_cap_sealed_get => fx.bcx.ins().iconst(types::I8, 0),
+                sym::cheri_cap_tag_get => {
+                    let is_non_null = fx.bcx.ins().icmp_imm(IntCC::NotEqual, ptr, 0);
+                    fx.bcx.ins().bint(types::I8, is_non_null)
+                }
+                _ => unreachable!(),
+            };
+            ret.write_cvalue(fx, CValue::by_val(val, ret.layout()));
+        }
+
         sym::caller_location => {
             intrinsic_args!(fx, args => (); intrinsic);
 
@@ -773,6 +773,29 @@ pub(super) fn codegen_simd_intrinsic_call<'tcx>(
             });
         }
 
+        // Cranelift has no CHERI targets, so pointers and their addresses are the same values.
+        sym::simd_expose_addr | sym::simd_addr => {
+            intrinsic_args!(fx, args => (a); intrinsic);
+
+            if !a.layout().ty.is_simd() {
+                report_simd_type_validation_error(fx, intrinsic, span, a.layout().ty);
+                return;
+            }
+
+            ret.write_cvalue_transmute(fx, a);
+        }
+
+        sym::simd_with_addr => {
+            intrinsic_args!(fx, args => (ptrs, addrs); intrinsic);
+
+            if !ptrs.layout().ty.is_simd() {
+                report_simd_type_validation_error(fx, intrinsic, span, ptrs.layout().ty);
+                return;
+            }
+
+            ret.write_cvalue_transmute(fx, addrs);
+        }
+
         // simd_arith_offset
         // simd_scatter
         // simd_gather
@@ -966,6 +966,16 @@ fn fcmp(&mut self, op: RealPredicate, lhs: RValue<'gcc>, rhs: RValue<'gcc>) -> R
         self.context.new_comparison(None, op.to_gcc_comparison(), lhs, rhs)
     }
 
+    fn ptr_from_exposed_addr(&mut self, addr: RValue<'gcc>, dest_ty: Type<'gcc>) -> RValue<'gcc> {
+        // GCC doesn't target capability architectures, so every pointer is a plain address.
+        self.inttoptr(addr, dest_ty)
+    }
+
+    fn restrict_vtable_ptr(&mut self, vtable: RValue<'gcc>, _size: Option<u64>) -> RValue<'gcc> {
+        // Without capabilities there are no permissions or bounds to restrict the vtable to.
+        vtable
+    }
+
     /* Miscellaneous instructions */
     fn memcpy(&mut self, dst: RValue<'gcc>, _dst_align: Align, src: RValue<'gcc>, _src_align: Align, size: RValue<'gcc>, flags: MemFlags) {
         assert!(!flags.contains(MemFlags::NONTEMPORAL), "non-temporal memcpy not supported");
@@ -14,6 +14,7 @@
 use rustc_middle::ty::layout::{FnAbiError, FnAbiOfHelpers, FnAbiRequest, HasParamEnv, HasTyCtxt, LayoutError, TyAndLayout, LayoutOfHelpers};
 use rustc_session::Session;
 use rustc_span::{Span, source_map::respan};
+use rustc_target::abi::call::AdjustForForeignAbiError;
 use rustc_target::abi::{call::FnAbi, HasDataLayout, PointeeInfo, Size, TargetDataLayout, VariantIdx};
 use rustc_target::spec::{HasTargetSpec, Target, TlsModel};
 
@@ -478,7 +479,9 @@ fn handle_fn_abi_err(
         span: Span,
         fn_abi_request: FnAbiRequest<'tcx>,
     ) -> ! {
-        if let FnAbiError::Layout(LayoutError::SizeOverflow(_)) = err {
+        if let FnAbiError::Layout(LayoutError::SizeOverflow(_))
+        | FnAbiError::AdjustForForeignAbi(AdjustForForeignAbiError::MissingSealedPair) = err
+        {
             self.sess().emit_fatal(respan(span, err))
         } else {
             match fn_abi_request {
@@ -410,6 +410,8 @@ fn llvm_cconv(&self) -> llvm::CallConv {
             Conv::AmdGpuKernel => llvm::AmdGpuKernel,
             Conv::AvrInterrupt => llvm::AvrInterrupt,
             Conv::AvrNonBlockingInterrupt => llvm::AvrNonBlockingInterrupt,
+            Conv::CheriotCompartmentCall | Conv::CheriCCall => llvm::CheriCCall,
+            Conv::CheriotCompartmentCallee => llvm::CheriCCallee,
             Conv::ArmAapcs => llvm::ArmAapcsCallConv,
             Conv::Msp430Intr => llvm::Msp430Intr,
             Conv::PtxKernel => llvm::PtxKernel,
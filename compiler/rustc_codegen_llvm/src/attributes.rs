@@ -368,6 +368,17 @@ pub fn from_fn_attrs<'ll, 'tcx>(
     if codegen_fn_attrs.flags.contains(CodegenFnAttrFlags::CMSE_NONSECURE_ENTRY) {
         to_add.push(llvm::CreateAttrString(cx.llcx, "cmse_nonsecure_entry"));
     }
+    if let Some(compartment) = codegen_fn_attrs.cheriot_compartment {
+        // The CHERIoT backend keys both tables off this attribute and the calling convention:
+        // a `chericcalleecc` definition gets a `__export_<compartment>_<symbol>` entry in
+        // `.compartment_exports`, and each `chericcallcc` call to a declaration loads its
+        // target from a `__import_<compartment>_<symbol>` entry in `.compartment_imports`.
+        to_add.push(llvm::CreateAttrStringValue(
+            cx.llcx,
+            "cheri-compartment",
+            compartment.name().as_str(),
+        ));
+    }
     if let Some(align) = codegen_fn_attrs.alignment {
         llvm::set_alignment(llfn, align as usize);
     }
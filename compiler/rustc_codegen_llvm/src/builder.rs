@@ -19,8 +19,9 @@
     FnAbiError, FnAbiOfHelpers, FnAbiRequest, LayoutError, LayoutOfHelpers, TyAndLayout,
 };
 use rustc_middle::ty::{self, Ty, TyCtxt};
+use rustc_session::config::CheriIntToPtr;
 use rustc_span::Span;
-use rustc_target::abi::{self, call::FnAbi, Align, Size, WrappingRange};
+use rustc_target::abi::{self, call::FnAbi, AddressSpace, Align, Size, WrappingRange};
 use rustc_target::spec::{HasTargetSpec, Target};
 use std::borrow::Cow;
 use std::ffi::CStr;
@@ -873,6 +874,51 @@ fn set_pointer_address(&mut self, ptr: &'ll Value, addr: &'ll Value) -> &'ll Val
         self.call_intrinsic("llvm.cheri.cap.address.set", &[ptr, addr])
     }
 
+    fn ptr_from_exposed_addr(&mut self, addr: &'ll Value, dest_ty: &'ll Type) -> &'ll Value {
+        if self.cx.address_space(dest_ty).0 != 200 {
+            return self.inttoptr(addr, dest_ty);
+        }
+
+        match self.sess().opts.unstable_opts.cheri_int_to_ptr {
+            // `-Z cheri-int-to-ptr=error` has already been reported by the caller, keep going with
+            // the default lowering so that we can report as many errors as possible.
+            CheriIntToPtr::NullDerived | CheriIntToPtr::Error => self.inttoptr(addr, dest_ty),
+            CheriIntToPtr::DdcDerived => {
+                // Like CHERI C in hybrid mode, a zero address always yields the null capability
+                // rather than a valid capability to address zero.
+                let ddc = self.call_intrinsic("llvm.cheri.ddc.get", &[]);
+                let derived = self.call_intrinsic("llvm.cheri.cap.address.set", &[ddc, addr]);
+                let derived = self.pointercast(derived, dest_ty);
+                let zero = self.const_usize(0);
+                let is_zero = self.icmp(IntPredicate::IntEQ, addr, zero);
+                let null = self.const_null(dest_ty);
+                self.select(is_zero, null, derived)
+            }
+        }
+    }
+
+    fn restrict_vtable_ptr(&mut self, vtable: &'ll Value, size: Option<u64>) -> &'ll Value {
+        let ptr_ty = self.cx.val_ty(vtable);
+        if self.cx.address_space(ptr_ty).0 != 200 {
+            return vtable;
+        }
+
+        let mut cap = self.pointercast(vtable, self.type_i8p_ext(AddressSpace(200)));
+        if let Some(size) = size {
+            let size = self.const_usize(size);
+            cap = self.call_intrinsic("llvm.cheri.cap.bounds.set.exact", &[cap, size]);
+        }
+        // Keep only the global, load and load-capability permissions.
+        let perms = if self.sess().target.arch == "morello+c64" {
+            (1 << 17) | (1 << 14) | 1
+        } else {
+            (1 << 4) | (1 << 2) | 1
+        };
+        let perms = self.const_usize(perms);
+        cap = self.call_intrinsic("llvm.cheri.cap.perms.and", &[cap, perms]);
+        self.pointercast(cap, ptr_ty)
+    }
+
     /* Miscellaneous instructions */
     fn memcpy(
         &mut self,
@@ -26,6 +26,7 @@
 use rustc_session::config::{CrateType, DebugInfo, PAuthKey, PacRet};
 use rustc_session::Session;
 use rustc_span::source_map::Span;
+use rustc_target::abi::call::AdjustForForeignAbiError;
 use rustc_target::abi::{
     call::FnAbi, AddressSpace, HasDataLayout, PointeeInfo, Size, TargetDataLayout, VariantIdx,
 };
@@ -973,6 +974,19 @@ macro_rules! mk_struct {
             fn(self.type_i8p_ext(AddressSpace(200)), t_isize) -> self.type_i8p_ext(AddressSpace(200))
         );
 
+        let i8p_cap = self.type_i8p_ext(AddressSpace(200));
+        ifn!("llvm.cheri.cap.base.get".to_string(), fn(i8p_cap) -> t_isize);
+        ifn!("llvm.cheri.cap.length.get".to_string(), fn(i8p_cap) -> t_isize);
+        ifn!("llvm.cheri.cap.perms.get".to_string(), fn(i8p_cap) -> t_isize);
+        ifn!("llvm.cheri.cap.type.get".to_string(), fn(i8p_cap) -> t_isize);
+        ifn!("llvm.cheri.cap.sealed.get".to_string(), fn(i8p_cap) -> i1);
+        ifn!("llvm.cheri.cap.tag.get".to_string(), fn(i8p_cap) -> i1);
+        ifn!("llvm.cheri.cap.equal.exact".to_string(), fn(i8p_cap, i8p_cap) -> i1);
+        ifn!("llvm.cheri.cap.bounds.set.exact".to_string(), fn(i8p_cap, t_isize) -> i8p_cap);
+        ifn!("llvm.cheri.cap.perms.and".to_string(), fn(i8p_cap, t_isize) -> i8p_cap);
+        ifn!("llvm.cheri.ddc.get".to_string(), fn() -> i8p_cap);
+        ifn!("llvm.cheri.pcc.get".to_string(), fn() -> i8p_cap);
+
         None
     }
 
@@ -1072,7 +1086,9 @@ fn handle_fn_abi_err(
         span: Span,
         fn_abi_request: FnAbiRequest<'tcx>,
     ) -> ! {
-        if let FnAbiError::Layout(LayoutError::SizeOverflow(_)) = err {
+        if let FnAbiError::Layout(LayoutError::SizeOverflow(_))
+        | FnAbiError::AdjustForForeignAbi(AdjustForForeignAbiError::MissingSealedPair) = err
+        {
             self.sess().span_fatal(span, &err.to_string())
         } else {
             match fn_abi_request {
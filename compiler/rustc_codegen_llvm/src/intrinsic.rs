@@ -18,7 +18,7 @@
 use rustc_middle::ty::{self, Ty};
 use rustc_middle::{bug, span_bug};
 use rustc_span::{sym, symbol::kw, Span, Symbol};
-use rustc_target::abi::{self, Align, HasDataLayout, Primitive};
+use rustc_target::abi::{self, AddressSpace, Align, HasDataLayout, Primitive};
 use rustc_target::spec::{HasTargetSpec, PanicStrategy};
 
 use std::cmp::Ordering;
@@ -371,6 +371,40 @@ fn codegen_intrinsic_call(
                 return;
             }
 
+            sym::cheri_cap_base_get
+            | sym::cheri_cap_length_get
+            | sym::cheri_cap_perms_get
+            | sym::cheri_cap_sealed_get
+            | sym::cheri_cap_tag_get
+            | sym::cheri_cap_type_get => cheri_cap_query(self, name, args[0].immediate()),
+
+            sym::cheri_cap_equal_exact => {
+                let a = args[0].immediate();
+                let b = args[1].immediate();
+                let ptr_ty = self.val_ty(a);
+                if self.cx.address_space(ptr_ty).0 != 200 {
+                    self.icmp(IntPredicate::IntEQ, a, b)
+                } else {
+                    let i8p_cap = self.type_i8p_ext(AddressSpace(200));
+                    let a = self.pointercast(a, i8p_cap);
+                    let b = self.pointercast(b, i8p_cap);
+                    self.call_intrinsic("llvm.cheri.cap.equal.exact", &[a, b])
+                }
+            }
+
+            sym::cheri_ddc_get | sym::cheri_pcc_get => {
+                if self.cx.address_space(llret_ty).0 != 200 {
+                    self.const_null(llret_ty)
+                } else {
+                    let llvm_name = match name {
+                        sym::cheri_ddc_get => "llvm.cheri.ddc.get",
+                        _ => "llvm.cheri.pcc.get",
+                    };
+                    let cap = self.call_intrinsic(llvm_name, &[]);
+                    self.pointercast(cap, llret_ty)
+                }
+            }
+
             _ if name.as_str().starts_with("simd_") => {
                 match generic_simd_intrinsic(self, name, callee_ty, args, ret_ty, llret_ty, span) {
                     Ok(llval) => llval,
@@ -462,6 +496,43 @@ fn try_intrinsic<'ll>(
     }
 }
 
+// Reads a field of a capability. On targets where pointers are plain integers there is no
+// capability to inspect, so we produce the values of a capability that is unsealed, valid, and
+// covers the whole address space with every permission, unless the pointer is null.
+fn cheri_cap_query<'ll>(
+    bx: &mut Builder<'_, 'll, '_>,
+    name: Symbol,
+    ptr: &'ll Value,
+) -> &'ll Value {
+    let ptr_ty = bx.val_ty(ptr);
+    if bx.cx.address_space(ptr_ty).0 != 200 {
+        return match name {
+            sym::cheri_cap_base_get | sym::cheri_cap_type_get => bx.const_usize(0),
+            sym::cheri_cap_length_get | sym::cheri_cap_perms_get => {
+                bx.const_int(bx.type_isize(), -1)
+            }
+            sym::cheri_cap_sealed_get => bx.const_bool(false),
+            sym::cheri_cap_tag_get => {
+                let null = bx.const_null(ptr_ty);
+                bx.icmp(IntPredicate::IntNE, ptr, null)
+            }
+            _ => bug!("unknown CHERI capability query '{}'", name),
+        };
+    }
+
+    let llvm_name = match name {
+        sym::cheri_cap_base_get => "llvm.cheri.cap.base.get",
+        sym::cheri_cap_length_get => "llvm.cheri.cap.length.get",
+        sym::cheri_cap_perms_get => "llvm.cheri.cap.perms.get",
+        sym::cheri_cap_sealed_get => "llvm.cheri.cap.sealed.get",
+        sym::cheri_cap_tag_get => "llvm.cheri.cap.tag.get",
+        sym::cheri_cap_type_get => "llvm.cheri.cap.type.get",
+        _ => bug!("unknown CHERI capability query '{}'", name),
+    };
+    let ptr = bx.pointercast(ptr, bx.type_i8p_ext(AddressSpace(200)));
+    bx.call_intrinsic(llvm_name, &[ptr])
+}
+
 // MSVC's definition of the `rust_try` function.
 //
 // This implementation uses the new exception handling instructions in LLVM
@@ -1824,7 +1895,7 @@ macro_rules! bitwise_red {
         }
     }
 
-    if name == sym::simd_expose_addr {
+    if name == sym::simd_expose_addr || name == sym::simd_addr {
         require_simd!(ret_ty, "return");
         let (out_len, out_elem) = ret_ty.simd_size_and_type(bx.tcx());
         require!(
@@ -1846,8 +1917,78 @@ macro_rules! bitwise_red {
             _ => return_error!("expected `usize`, got `{}`", out_elem),
         }
 
-        let addr = bx.get_pointer_address(args[0].immediate());
-        return Ok(&bx.intcast(addr, llret_ty, false));
+        let ptrs = args[0].immediate();
+        let llptr_ty = bx.element_type(bx.val_ty(ptrs));
+        if bx.cx.address_space(llptr_ty).0 != 200 {
+            return Ok(bx.ptrtoint(ptrs, llret_ty));
+        }
+
+        // Capability addresses can only be read one at a time, so convert each lane separately.
+        let llelem_ty = bx.element_type(llret_ty);
+        let mut addrs = bx.const_undef(llret_ty);
+        for i in 0..out_len {
+            let idx = bx.const_i32(i as i32);
+            let ptr = bx.extract_element(ptrs, idx);
+            let addr = bx.get_pointer_address(ptr);
+            let addr = bx.intcast(addr, llelem_ty, false);
+            addrs = bx.insert_element(addrs, addr, idx);
+        }
+        return Ok(addrs);
+    }
+
+    if name == sym::simd_with_addr {
+        require!(
+            ret_ty == in_ty,
+            "expected return type `{}` (same as input type), found `{}`",
+            in_ty,
+            ret_ty
+        );
+        require_simd!(arg_tys[1], "address");
+        let (addr_len, addr_elem) = arg_tys[1].simd_size_and_type(bx.tcx());
+        require!(
+            in_len == addr_len,
+            "expected address type with length {} (same as input type `{}`), \
+                  found `{}` with length {}",
+            in_len,
+            in_ty,
+            arg_tys[1],
+            addr_len
+        );
+
+        match in_elem.kind() {
+            ty::RawPtr(_) => {}
+            _ => return_error!("expected pointer, got `{}`", in_elem),
+        }
+        match addr_elem.kind() {
+            ty::Uint(ty::UintTy::Usize) => {}
+            _ => return_error!("expected `usize`, got `{}`", addr_elem),
+        }
+
+        // Like `pointer::with_addr`, each lane keeps the provenance of its pointer. Capabilities
+        // only get their address replaced, so their tags survive; other pointers are offset by the
+        // difference between the addresses.
+        let ptrs = args[0].immediate();
+        let addrs = args[1].immediate();
+        let llelem_ty = bx.element_type(llret_ty);
+        let addr_space = bx.cx.address_space(llelem_ty);
+        let mut new_ptrs = bx.const_undef(llret_ty);
+        for i in 0..in_len {
+            let idx = bx.const_i32(i as i32);
+            let ptr = bx.extract_element(ptrs, idx);
+            let addr = bx.extract_element(addrs, idx);
+            let ptr = if addr_space.0 == 200 {
+                let cap = bx.set_pointer_address(ptr, addr);
+                bx.pointercast(cap, llelem_ty)
+            } else {
+                let old_addr = bx.ptrtoint(ptr, bx.type_isize());
+                let offset = bx.sub(addr, old_addr);
+                let byte_ptr = bx.pointercast(ptr, bx.type_i8p_ext(addr_space));
+                let byte_ptr = bx.gep(bx.type_i8(), byte_ptr, &[offset]);
+                bx.pointercast(byte_ptr, llelem_ty)
+            };
+            new_ptrs = bx.insert_element(new_ptrs, ptr, idx);
+        }
+        return Ok(new_ptrs);
     }
 
     if name == sym::simd_from_exposed_addr {
@@ -1872,8 +2013,21 @@ macro_rules! bitwise_red {
             _ => return_error!("expected pointer, got `{}`", out_elem),
         }
 
-        let nullptr = bx.const_null(llret_ty);
-        return Ok(bx.set_pointer_address(nullptr, args[0].immediate()));
+        let addrs = args[0].immediate();
+        let llelem_ty = bx.element_type(llret_ty);
+        if bx.cx.address_space(llelem_ty).0 != 200 {
+            return Ok(bx.inttoptr(addrs, llret_ty));
+        }
+
+        // Capabilities can only be derived one at a time, so convert each lane separately.
+        let mut ptrs = bx.const_undef(llret_ty);
+        for i in 0..out_len {
+            let idx = bx.const_i32(i as i32);
+            let addr = bx.extract_element(addrs, idx);
+            let ptr = bx.ptr_from_exposed_addr(addr, llelem_ty);
+            ptrs = bx.insert_element(ptrs, ptr, idx);
+        }
+        return Ok(ptrs);
     }
 
     if name == sym::simd_cast || name == sym::simd_as {
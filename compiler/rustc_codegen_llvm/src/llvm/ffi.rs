@@ -103,6 +103,10 @@ pub enum CallConv {
     AvrNonBlockingInterrupt = 84,
     AvrInterrupt = 85,
     AmdGpuKernel = 91,
+    // `CHERI_CCall` and `CHERI_CCallee` in the CHERI LLVM fork's `CallingConv.h`, printed as
+    // `chericcallcc` and `chericcalleecc` in IR.
+    CheriCCall = 200,
+    CheriCCallee = 201,
 }
 
 /// LLVMRustLinkage
@@ -146,7 +146,11 @@ pub fn time_trace_profiler_finish(file_name: &Path) {
 // which might lead to failures if the oldest tested / supported LLVM version
 // doesn't yet support the relevant intrinsics
 pub fn to_llvm_features<'a>(sess: &Session, s: &'a str) -> SmallVec<[&'a str; 2]> {
-    let arch = if sess.target.arch == "x86_64" { "x86" } else { &*sess.target.arch };
+    let arch = match &*sess.target.arch {
+        "x86_64" => "x86",
+        "morello+c64" => "aarch64",
+        arch => arch,
+    };
     match (arch, s) {
         ("x86", "sse4.2") => {
             if get_version() >= (14, 0, 0) {
@@ -1657,6 +1657,30 @@ fn add_pre_link_objects(
     }
 }
 
+/// Add the startup object that initializes capabilities from `__cap_relocs` on freestanding
+/// capability targets, which have no runtime linker to do so.
+///
+/// Its entry point, `__rust_cheri_start`, processes the relocations and then branches to the
+/// program's `_start`, so it becomes the entry point of the image.
+fn add_cheri_caprelocs_startup_object(
+    cmd: &mut dyn Linker,
+    sess: &Session,
+    flavor: LinkerFlavor,
+    link_output_kind: LinkOutputKind,
+    self_contained: bool,
+) {
+    if !sess.opts.unstable_opts.cheri_self_init_caprelocs
+        || matches!(link_output_kind, LinkOutputKind::DynamicDylib | LinkOutputKind::StaticDylib)
+    {
+        return;
+    }
+    cmd.add_object(&get_object_file_path(sess, "rscaprelocs.o", self_contained));
+    match flavor {
+        LinkerFlavor::Gnu(Cc::Yes, _) => cmd.arg("-Wl,--entry=__rust_cheri_start"),
+        _ => cmd.arg("--entry=__rust_cheri_start"),
+    }
+}
+
 /// Add post-link object files defined by the target spec.
 fn add_post_link_objects(
     cmd: &mut dyn Linker,
@@ -1988,6 +2012,8 @@ fn linker_with_args<'a>(
     // Pre-link CRT objects.
     add_pre_link_objects(cmd, sess, flavor, link_output_kind, self_contained);
 
+    add_cheri_caprelocs_startup_object(cmd, sess, flavor, link_output_kind, self_contained);
+
     add_linked_symbol_object(
         cmd,
         sess,
@@ -33,7 +33,7 @@
 use rustc_middle::ty::query::Providers;
 use rustc_middle::ty::{self, Instance, Ty, TyCtxt};
 use rustc_session::cgu_reuse_tracker::CguReuse;
-use rustc_session::config::{self, CrateType, EntryFnType, OutputType};
+use rustc_session::config::{self, CheriVtables, CrateType, EntryFnType, OutputType};
 use rustc_session::Session;
 use rustc_span::symbol::sym;
 use rustc_span::Symbol;
@@ -132,6 +132,30 @@ pub fn compare_simd_types<'a, 'tcx, Bx: BuilderMethods<'a, 'tcx>>(
     bx.sext(cmp, ret_ty)
 }
 
+/// Prepares a pointer for a comparison with `icmp`.
+///
+/// Raw pointers compare by address only. Capabilities carry bounds and permissions alongside
+/// their address, so reduce them to the address explicitly instead of relying on how the backend
+/// compares them. Other values are returned unchanged.
+pub fn ptr_cmp_operand<'a, 'tcx, Bx: BuilderMethods<'a, 'tcx>>(
+    bx: &mut Bx,
+    val: Bx::Value,
+) -> Bx::Value {
+    let ty = bx.cx().val_ty(val);
+    if bx.cx().type_kind(ty) != TypeKind::Pointer {
+        return val;
+    }
+
+    let address_space = bx.cx().address_space(ty);
+    let is_capability = bx
+        .tcx()
+        .data_layout
+        .pointer_layouts
+        .iter()
+        .any(|&(space, layout)| space == address_space && layout.is_fat_ty);
+    if is_capability { bx.get_pointer_address(val) } else { val }
+}
+
 /// Retrieves the information we are losing (making dynamic) in an unsizing
 /// adjustment.
 ///
@@ -186,14 +210,26 @@ pub fn unsized_info<'a, 'tcx, Bx: BuilderMethods<'a, 'tcx>>(
                 bx.nonnull_metadata(new_vptr);
                 // VTable loads are invariant.
                 bx.set_invariant_load(new_vptr);
-                bx.pointercast(new_vptr, vtable_ptr_ty)
+                let new_vptr = bx.pointercast(new_vptr, vtable_ptr_ty);
+                if cx.sess().opts.unstable_opts.cheri_vtables == CheriVtables::Restricted {
+                    bx.restrict_vtable_ptr(new_vptr, None)
+                } else {
+                    new_vptr
+                }
             } else {
                 old_info
             }
         }
         (_, &ty::Dynamic(ref data, _, target_dyn_kind)) => {
             let vtable_ptr_ty = vtable_ptr_ty(cx, target, target_dyn_kind);
-            cx.const_ptrcast(meth::get_vtable(cx, source, data.principal()), vtable_ptr_ty)
+            let vtable =
+                cx.const_ptrcast(meth::get_vtable(cx, source, data.principal()), vtable_ptr_ty);
+            if cx.sess().opts.unstable_opts.cheri_vtables == CheriVtables::Restricted {
+                let size = meth::vtable_size(cx.tcx(), source, data.principal());
+                bx.restrict_vtable_ptr(vtable, Some(size))
+            } else {
+                vtable
+            }
         }
         _ => bug!("unsized_info: invalid unsizing {:?} -> {:?}", source, target),
     }
@@ -286,9 +322,23 @@ pub fn cast_to_dyn_star<'a, 'tcx, Bx: BuilderMethods<'a, 'tcx>>(
     // FIXME(dyn-star): this is probably not the best way to check if this is
     // a pointer, and really we should ensure that the value is a suitable
     // pointer earlier in the compilation process.
-    let src = match src_ty_and_layout.pointee_info_at(bx.cx(), Size::ZERO) {
-        Some(_) => bx.get_pointer_address(src),
-        None => bx.bitcast(src, bx.type_isize()),
+    let is_pointer = src_ty_and_layout.pointee_info_at(bx.cx(), Size::ZERO).is_some();
+    let src = if bx.tcx().data_layout.ptr_layout(None).is_fat_ty {
+        // The data of a `dyn*` is a capability on CHERI targets. Pointers are stored as they are
+        // so that they keep their tag, and anything else becomes the address of a null-derived
+        // capability.
+        let dst_layout = bx.cx().layout_of(dst_ty);
+        let lldata_ty = bx.cx().scalar_pair_element_backend_type(dst_layout, 0, true);
+        if is_pointer {
+            bx.pointercast(src, lldata_ty)
+        } else {
+            let null = bx.const_null(lldata_ty);
+            bx.set_pointer_address(null, src)
+        }
+    } else if is_pointer {
+        bx.get_pointer_address(src)
+    } else {
+        bx.bitcast(src, bx.type_isize())
     };
     (src, unsized_info(bx, src_ty_and_layout.ty, dst_ty, old_info))
 }
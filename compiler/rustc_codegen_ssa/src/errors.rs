@@ -354,3 +354,12 @@ fn into_diagnostic(self, handler: &Handler) -> DiagnosticBuilder<'_, ErrorGuaran
         diag
     }
 }
+
+#[derive(Diagnostic)]
+#[diag(codegen_ssa_cheri_int_to_ptr_cast)]
+#[note]
+#[help]
+pub struct CheriIntToPtrCast {
+    #[primary_span]
+    pub span: Span,
+}
@@ -1,6 +1,6 @@
 use crate::traits::*;
 
-use rustc_middle::ty::{self, subst::GenericArgKind, Ty};
+use rustc_middle::ty::{self, subst::GenericArgKind, Ty, TyCtxt};
 use rustc_session::config::Lto;
 use rustc_symbol_mangling::typeid_for_trait_ref;
 use rustc_target::abi::call::FnAbi;
@@ -88,6 +88,17 @@ fn expect_dyn_trait_in_self<'tcx>(ty: Ty<'tcx>) -> ty::PolyExistentialTraitRef<'
     bug!("expected a `dyn Trait` ty, found {ty:?}")
 }
 
+/// Returns the size in bytes of the vtable `get_vtable` emits for the given type and vtable
+/// origin.
+pub fn vtable_size<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    ty: Ty<'tcx>,
+    trait_ref: Option<ty::PolyExistentialTraitRef<'tcx>>,
+) -> u64 {
+    let vtable_alloc_id = tcx.vtable_allocation((ty, trait_ref));
+    tcx.global_alloc(vtable_alloc_id).unwrap_memory().inner().size().bytes()
+}
+
 /// Creates a dynamic vtable for the given type and vtable origin.
 /// This is used only for objects.
 ///
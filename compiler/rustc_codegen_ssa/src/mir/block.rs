@@ -826,6 +826,10 @@ fn codegen_call_terminator(
             return;
         }
 
+        if intrinsic == Some(sym::simd_from_exposed_addr) {
+            self.check_ptr_from_exposed_addr(&bx, source_info);
+        }
+
         match intrinsic {
             None | Some(sym::drop_in_place) => {}
             Some(sym::copy_nonoverlapping) => unreachable!(),
@@ -1731,7 +1735,9 @@ fn codegen_transmute_into(
                 }
                 if src_scalar.primitive() != abi::Pointer && dst_scalar.primitive() == abi::Pointer {
                     // We are generating an invalid pointer - IE with no provenance - with the
-                    // address set to the given scalar.
+                    // address set to the given scalar. Unlike `as` casts this does not depend on
+                    // `-Z cheri-int-to-ptr`: transmuting an integer never exposes a provenance,
+                    // so the result is always derived from the null capability.
                     assert_eq!(src_scalar.val_size(bx), dst_scalar.val_size(bx));
 
                     // We need to basically set the address of a null pointer to be the src value.
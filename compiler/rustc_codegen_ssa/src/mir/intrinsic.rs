@@ -1,6 +1,7 @@
 use super::operand::{OperandRef, OperandValue};
 use super::place::PlaceRef;
 use super::FunctionCx;
+use crate::base;
 use crate::common::{span_invalid_monomorphization_error, IntPredicate};
 use crate::glue;
 use crate::meth;
@@ -557,8 +558,8 @@ pub fn codegen_intrinsic_call(
             }
 
             sym::ptr_guaranteed_cmp => {
-                let a = args[0].immediate();
-                let b = args[1].immediate();
+                let a = base::ptr_cmp_operand(bx, args[0].immediate());
+                let b = base::ptr_cmp_operand(bx, args[1].immediate());
                 bx.icmp(IntPredicate::IntEQ, a, b)
             }
 
@@ -336,7 +336,7 @@ pub fn codegen_rvalue_operand(
                             (CastTy::Int(i), CastTy::Ptr(_)) => {
                                 let usize_llval =
                                     bx.intcast(llval, bx.cx().type_isize(), i.is_signed());
-                                bx.inttoptr(usize_llval, ll_t_out)
+                                bx.ptr_from_exposed_addr(usize_llval, ll_t_out)
                             }
                             (CastTy::Float, CastTy::Int(IntTy::I)) => {
                                 bx.cast_float_to_int(true, llval, ll_t_out)
@@ -622,6 +622,8 @@ pub fn codegen_scalar_binop(
                 if is_float {
                     bx.fcmp(base::bin_op_to_fcmp_predicate(op.to_hir_binop()), lhs, rhs)
                 } else {
+                    let lhs = base::ptr_cmp_operand(bx, lhs);
+                    let rhs = base::ptr_cmp_operand(bx, rhs);
                     bx.icmp(base::bin_op_to_icmp_predicate(op.to_hir_binop(), is_signed), lhs, rhs)
                 }
             }
@@ -638,6 +640,10 @@ pub fn codegen_fat_ptr_binop(
         rhs_extra: Bx::Value,
         _input_ty: Ty<'tcx>,
     ) -> Bx::Value {
+        let lhs_addr = base::ptr_cmp_operand(bx, lhs_addr);
+        let lhs_extra = base::ptr_cmp_operand(bx, lhs_extra);
+        let rhs_addr = base::ptr_cmp_operand(bx, rhs_addr);
+        let rhs_extra = base::ptr_cmp_operand(bx, rhs_extra);
         match op {
             mir::BinOp::Eq => {
                 let lhs = bx.icmp(IntPredicate::IntEQ, lhs_addr, rhs_addr);
@@ -1,8 +1,10 @@
 use rustc_middle::mir;
 use rustc_middle::mir::NonDivergingIntrinsic;
+use rustc_session::config::CheriIntToPtr;
 
 use super::FunctionCx;
 use super::LocalRef;
+use crate::errors;
 use crate::traits::BuilderMethods;
 use crate::traits::*;
 
@@ -12,6 +14,9 @@ pub fn codegen_statement(&mut self, mut bx: Bx, statement: &mir::Statement<'tcx>
         self.set_debug_loc(&mut bx, statement.source_info);
         match statement.kind {
             mir::StatementKind::Assign(box (ref place, ref rvalue)) => {
+                if let mir::Rvalue::Cast(mir::CastKind::PointerFromExposedAddress, ..) = rvalue {
+                    self.check_ptr_from_exposed_addr(&bx, statement.source_info);
+                }
                 if let Some(index) = place.as_local() {
                     match self.locals[index] {
                         LocalRef::Place(cg_dest) => self.codegen_rvalue(bx, cg_dest, rvalue),
@@ -103,4 +108,34 @@ pub fn codegen_statement(&mut self, mut bx: Bx, statement: &mir::Statement<'tcx>
             | mir::StatementKind::Nop => bx,
         }
     }
+
+    /// Rejects integer-to-pointer casts on capability targets under `-Z cheri-int-to-ptr=error`,
+    /// both `as` casts and the lane-wise casts of `simd_from_exposed_addr`.
+    ///
+    /// This has to happen after monomorphization: the standard library is built without the flag
+    /// and only the casts that end up in the crate being compiled should be reported.
+    pub(super) fn check_ptr_from_exposed_addr(&self, bx: &Bx, source_info: mir::SourceInfo) {
+        let tcx = bx.tcx();
+        if tcx.sess.opts.unstable_opts.cheri_int_to_ptr != CheriIntToPtr::Error
+            || !tcx.data_layout.ptr_layout(None).is_fat_ty
+        {
+            return;
+        }
+
+        // Point at the outermost call site if the cast was inlined, so that uses of
+        // `ptr::from_exposed_addr` blame the caller rather than libcore.
+        let mut span = source_info.span;
+        let mut scope = source_info.scope;
+        loop {
+            let scope_data = &self.mir.source_scopes[scope];
+            if let Some((_, callsite_span)) = scope_data.inlined {
+                span = callsite_span;
+            }
+            match scope_data.inlined_parent_scope {
+                Some(parent) => scope = parent,
+                None => break,
+            }
+        }
+        tcx.sess.emit_err(errors::CheriIntToPtrCast { span });
+    }
 }
@@ -84,6 +84,8 @@
     ("lor", None),
     // FEAT_LSE
     ("lse", None),
+    // Morello capabilities
+    ("morello", Some(sym::morello_target_feature)),
     // FEAT_MTE
     ("mte", None),
     // FEAT_AdvSimd & FEAT_FP
@@ -299,7 +301,7 @@ pub fn all_known_features() -> impl Iterator<Item = (&'static str, Option<Symbol
 pub fn supported_target_features(sess: &Session) -> &'static [(&'static str, Option<Symbol>)] {
     match &*sess.target.arch {
         "arm" => ARM_ALLOWED_FEATURES,
-        "aarch64" => AARCH64_ALLOWED_FEATURES,
+        "aarch64" | "morello+c64" => AARCH64_ALLOWED_FEATURES,
         "x86" | "x86_64" => X86_ALLOWED_FEATURES,
         "hexagon" => HEXAGON_ALLOWED_FEATURES,
         "mips" | "mips64" => MIPS_ALLOWED_FEATURES,
@@ -313,7 +315,7 @@ pub fn supported_target_features(sess: &Session) -> &'static [(&'static str, Opt
 
 pub fn tied_target_features(sess: &Session) -> &'static [&'static [&'static str]] {
     match &*sess.target.arch {
-        "aarch64" => AARCH64_TIED_FEATURES,
+        "aarch64" | "morello+c64" => AARCH64_TIED_FEATURES,
         _ => &[],
     }
 }
@@ -230,6 +230,13 @@ fn cast_float_to_int(
 
     fn get_pointer_address(&mut self, ptr: Self::Value) -> Self::Value;
     fn set_pointer_address(&mut self, ptr: Self::Value, addr: Self::Value) -> Self::Value;
+    /// Converts an integer address to a pointer of type `dest_ty`, as for an `as` cast or
+    /// `ptr::from_exposed_addr`. On capability targets the provenance of the result is chosen by
+    /// `-Z cheri-int-to-ptr`.
+    fn ptr_from_exposed_addr(&mut self, addr: Self::Value, dest_ty: Self::Type) -> Self::Value;
+    /// Restricts a capability to a vtable so that it can only be used to load from the vtable,
+    /// bounding it to exactly `size` bytes if given. Used for `-Z cheri-vtables=restricted`.
+    fn restrict_vtable_ptr(&mut self, vtable: Self::Value, size: Option<u64>) -> Self::Value;
 
     fn memcpy(
         &mut self,
@@ -8,7 +8,7 @@
 use rustc_middle::ty::adjustment::PointerCast;
 use rustc_middle::ty::layout::{IntegerExt, LayoutOf, TyAndLayout};
 use rustc_middle::ty::{self, FloatTy, Ty, TypeAndMut};
-use rustc_target::abi::Integer;
+use rustc_target::abi::{Integer, Size};
 use rustc_type_ir::sty::TyKind::*;
 
 use super::{
@@ -127,7 +127,20 @@ pub fn cast(
                     let vtable = self.get_vtable_ptr(src.layout.ty, data.principal())?;
                     let vtable = Scalar::from_maybe_pointer(vtable, self);
                     let data = self.read_immediate(src)?.to_scalar();
-                    let _assert_pointer_sized = data.to_pointer(self)?;
+                    let data = if self.tcx.data_layout.ptr_layout(None).is_fat_ty {
+                        // The data of a `dyn*` is a capability on CHERI targets, like in codegen.
+                        // Pointers are stored as they are, so that they keep their provenance,
+                        // and anything else becomes the address of a null-derived capability.
+                        if src.layout.pointee_info_at(self, Size::ZERO).is_some() {
+                            Scalar::from_maybe_pointer(data.to_pointer(self)?, self)
+                        } else {
+                            let addr = data.to_machine_usize(self)?;
+                            Scalar::from_uint(addr, self.pointer_ty_size())
+                        }
+                    } else {
+                        let _assert_pointer_sized = data.to_pointer(self)?;
+                        data
+                    };
                     let val = Immediate::ScalarPair(data, vtable);
                     self.write_immediate(val, dest)?;
                 } else {
@@ -138,6 +138,13 @@ fn enforce_abi(_ecx: &InterpCx<'mir, 'tcx, Self>) -> bool {
         true
     }
 
+    /// Whether copying only some of the bytes of a pointer drops that pointer's provenance
+    /// (like CHERI clears the tag of a partially copied capability) rather than being reported as
+    /// unsupported.
+    fn partial_ptr_copy_drops_provenance(_ecx: &InterpCx<'mir, 'tcx, Self>) -> bool {
+        false
+    }
+
     /// Whether CheckedBinOp MIR statements should actually check for overflow.
     fn checked_binop_checks_overflow(_ecx: &InterpCx<'mir, 'tcx, Self>) -> bool;
 
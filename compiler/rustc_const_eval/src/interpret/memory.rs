@@ -1100,16 +1100,19 @@ pub fn mem_copy_repeatedly(
         };
 
         // Checks provenance edges on the src, which needs to happen before
-        // `prepare_provenance_copy`.
-        if src_alloc
-            .range_has_provenance(&tcx, alloc_range(src_range.start, Size::ZERO, Size::ZERO))
-        {
-            throw_unsup!(PartialPointerCopy(Pointer::new(src_alloc_id, src_range.start)));
-        }
-        if src_alloc
-            .range_has_provenance(&tcx, alloc_range(src_range.end(), Size::ZERO, Size::ZERO))
-        {
-            throw_unsup!(PartialPointerCopy(Pointer::new(src_alloc_id, src_range.end())));
+        // `prepare_provenance_copy`. If the machine allows it, pointers that are only partially
+        // copied simply lose their provenance, which `prepare_provenance_copy` takes care of.
+        if !M::partial_ptr_copy_drops_provenance(self) {
+            if src_alloc
+                .range_has_provenance(&tcx, alloc_range(src_range.start, Size::ZERO, Size::ZERO))
+            {
+                throw_unsup!(PartialPointerCopy(Pointer::new(src_alloc_id, src_range.start)));
+            }
+            if src_alloc
+                .range_has_provenance(&tcx, alloc_range(src_range.end(), Size::ZERO, Size::ZERO))
+            {
+                throw_unsup!(PartialPointerCopy(Pointer::new(src_alloc_id, src_range.end())));
+            }
         }
         let src_bytes = src_alloc.get_bytes_unchecked(src_range).as_ptr(); // raw ptr, so we can also get a ptr to the destination allocation
         // first copy the provenance to a temporary buffer, because
@@ -119,3 +119,7 @@ codegen_ssa_thorin_object_read = {$error}
 codegen_ssa_thorin_object_write = {$error}
 codegen_ssa_thorin_gimli_read = {$error}
 codegen_ssa_thorin_gimli_write = {$error}
+
+codegen_ssa_cheri_int_to_ptr_cast = casting an integer to a pointer is not allowed with `-Z cheri-int-to-ptr=error`
+    .note = on this target pointers are capabilities, and an integer carries no provenance to derive one from
+    .help = use `ptr.with_addr(addr)` or `ptr.map_addr(..)` to derive the pointer from an existing one
@@ -25,6 +25,10 @@ session_profile_sample_use_file_does_not_exist = file `{$path}` passed to `-C pr
 
 session_target_requires_unwind_tables = target requires unwind tables, they cannot be disabled with `-C force-unwind-tables=no`
 
+session_cheri_vtables_requires_purecap = `-Z cheri-vtables=restricted` is only supported on CHERI pure-capability targets
+
+session_cheri_self_init_caprelocs_requires_freestanding_purecap = `-Z cheri-self-init-caprelocs` is only supported on freestanding CHERI pure-capability targets
+
 session_sanitizer_not_supported = {$us} sanitizer is not supported for this target
 
 session_sanitizers_not_supported = {$us} sanitizers are not supported for this target
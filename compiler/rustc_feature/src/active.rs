@@ -255,6 +255,7 @@ pub fn set(&self, features: &mut Features, span: Span) {
     (active, f16c_target_feature, "1.36.0", Some(44839), None),
     (active, hexagon_target_feature, "1.27.0", Some(44839), None),
     (active, mips_target_feature, "1.27.0", Some(44839), None),
+    (active, morello_target_feature, "1.67.0", None, None),
     (active, movbe_target_feature, "1.34.0", Some(44839), None),
     (active, powerpc_target_feature, "1.27.0", Some(44839), None),
     (active, riscv_target_feature, "1.45.0", Some(44839), None),
@@ -280,6 +281,8 @@ pub fn set(&self, features: &mut Features, span: Span) {
     (active, abi_avr_interrupt, "1.45.0", Some(69664), None),
     /// Allows `extern "C-cmse-nonsecure-call" fn()`.
     (active, abi_c_cmse_nonsecure_call, "1.51.0", Some(81391), None),
+    /// Allows `extern "cheri-ccall" fn()`.
+    (active, abi_cheri_ccall, "1.67.0", None, None),
     /// Allows using the `efiapi` ABI.
     (active, abi_efiapi, "1.40.0", Some(65815), None),
     /// Allows `extern "msp430-interrupt" fn()`.
@@ -330,6 +333,8 @@ pub fn set(&self, features: &mut Features, span: Span) {
     (active, cfg_target_thread_local, "1.7.0", Some(29594), None),
     /// Allow conditional compilation depending on rust version
     (active, cfg_version, "1.45.0", Some(64796), None),
+    /// Allows `#[cheriot_compartment]` and the `extern "cheriot-cross-compartment"` ABI.
+    (active, cheriot_compartment, "1.67.0", None, None),
     /// Allows `for<...>` on closures and generators.
     (active, closure_lifetime_binder, "1.64.0", Some(97362), None),
     /// Allows `#[track_caller]` on closures and generators.
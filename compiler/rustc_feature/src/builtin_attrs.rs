@@ -472,6 +472,10 @@ pub struct BuiltinAttribute {
         cmse_nonsecure_entry, Normal, template!(Word), WarnFollowing,
         experimental!(cmse_nonsecure_entry)
     ),
+    gated!(
+        cheriot_compartment, Normal, template!(NameValueStr: "name"), ErrorPreceding,
+        experimental!(cheriot_compartment)
+    ),
     // RFC 2632
     gated!(
         const_trait, Normal, template!(Word), WarnFollowing, const_trait_impl,
@@ -109,7 +109,16 @@ pub fn intrinsic_operation_unsafety(tcx: TyCtxt<'_>, intrinsic_id: DefId) -> hir
         | sym::forget
         | sym::black_box
         | sym::variant_count
-        | sym::ptr_mask => hir::Unsafety::Normal,
+        | sym::ptr_mask
+        | sym::cheri_cap_base_get
+        | sym::cheri_cap_equal_exact
+        | sym::cheri_cap_length_get
+        | sym::cheri_cap_perms_get
+        | sym::cheri_cap_sealed_get
+        | sym::cheri_cap_tag_get
+        | sym::cheri_cap_type_get
+        | sym::cheri_ddc_get
+        | sym::cheri_pcc_get => hir::Unsafety::Normal,
         _ => hir::Unsafety::Unsafe,
     };
 
@@ -432,6 +441,19 @@ pub fn check_intrinsic_type(tcx: TyCtxt<'_>, it: &hir::ForeignItem<'_>) {
                 (0, vec![tcx.mk_imm_ptr(tcx.mk_unit())], tcx.types.usize)
             }
 
+            sym::cheri_cap_base_get | sym::cheri_cap_length_get | sym::cheri_cap_perms_get => {
+                (1, vec![tcx.mk_imm_ptr(param(0))], tcx.types.usize)
+            }
+            sym::cheri_cap_type_get => (1, vec![tcx.mk_imm_ptr(param(0))], tcx.types.isize),
+            sym::cheri_cap_equal_exact => {
+                (1, vec![tcx.mk_imm_ptr(param(0)), tcx.mk_imm_ptr(param(0))], tcx.types.bool)
+            }
+            sym::cheri_cap_sealed_get | sym::cheri_cap_tag_get => {
+                (1, vec![tcx.mk_imm_ptr(param(0))], tcx.types.bool)
+            }
+            sym::cheri_ddc_get => (0, vec![], tcx.mk_mut_ptr(tcx.mk_unit())),
+            sym::cheri_pcc_get => (0, vec![], tcx.mk_imm_ptr(tcx.mk_unit())),
+
             other => {
                 tcx.sess.emit_err(UnrecognizedIntrinsicFunction { span: it.span, name: other });
                 return;
@@ -496,8 +518,10 @@ pub fn check_platform_intrinsic_type(tcx: TyCtxt<'_>, it: &hir::ForeignItem<'_>)
         sym::simd_cast
         | sym::simd_as
         | sym::simd_cast_ptr
+        | sym::simd_addr
         | sym::simd_expose_addr
         | sym::simd_from_exposed_addr => (2, vec![param(0)], param(1)),
+        sym::simd_with_addr => (2, vec![param(0), param(1)], param(0)),
         sym::simd_bitmask => (2, vec![param(0)], param(1)),
         sym::simd_select | sym::simd_select_bitmask => {
             (2, vec![param(0), param(1), param(1)], param(1))
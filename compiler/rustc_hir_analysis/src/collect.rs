@@ -30,7 +30,9 @@
 use rustc_hir::weak_lang_items::WEAK_LANG_ITEMS;
 use rustc_hir::{lang_items, GenericParamKind, LangItem, Node};
 use rustc_middle::hir::nested_filter;
-use rustc_middle::middle::codegen_fn_attrs::{CodegenFnAttrFlags, CodegenFnAttrs};
+use rustc_middle::middle::codegen_fn_attrs::{
+    CheriotCompartment, CodegenFnAttrFlags, CodegenFnAttrs,
+};
 use rustc_middle::mir::mono::Linkage;
 use rustc_middle::ty::query::Providers;
 use rustc_middle::ty::util::{Discr, IntTypeExt};
@@ -1549,6 +1551,7 @@ fn from_target_feature(
                 Some(sym::ermsb_target_feature) => rust_features.ermsb_target_feature,
                 Some(sym::bpf_target_feature) => rust_features.bpf_target_feature,
                 Some(sym::aarch64_ver_target_feature) => rust_features.aarch64_ver_target_feature,
+                Some(sym::morello_target_feature) => rust_features.morello_target_feature,
                 Some(name) => bug!("unknown target feature gate {}", name),
                 None => true,
             };
@@ -1762,6 +1765,31 @@ fn codegen_fn_attrs(tcx: TyCtxt<'_>, did: DefId) -> CodegenFnAttrs {
                     .emit();
             }
             codegen_fn_attrs.flags |= CodegenFnAttrFlags::CMSE_NONSECURE_ENTRY;
+        } else if attr.has_name(sym::cheriot_compartment) {
+            if tcx.sess.target.llvm_abiname != "cheriot" {
+                struct_span_err!(
+                    tcx.sess,
+                    attr.span,
+                    E0792,
+                    "`#[cheriot_compartment]` is only valid for CHERIoT targets"
+                )
+                .emit();
+            } else if tcx.fn_sig(did).abi() != abi::Abi::CheriotCrossCompartment {
+                struct_span_err!(
+                    tcx.sess,
+                    attr.span,
+                    E0791,
+                    "`#[cheriot_compartment]` requires the \"cheriot-cross-compartment\" ABI"
+                )
+                .emit();
+            }
+            codegen_fn_attrs.cheriot_compartment = attr.value_str().map(|name| {
+                if tcx.is_foreign_item(did) {
+                    CheriotCompartment::Import(name)
+                } else {
+                    CheriotCompartment::Export(name)
+                }
+            });
         } else if attr.has_name(sym::thread_local) {
             codegen_fn_attrs.flags |= CodegenFnAttrFlags::THREAD_LOCAL;
         } else if attr.has_name(sym::track_caller) {
@@ -3,6 +3,7 @@
 
 use rustc_data_structures::fx::FxHashSet;
 use rustc_errors::{emitter::HumanReadableErrorType, registry, ColorConfig};
+use rustc_session::config::{CheriIntToPtr, CheriVtables};
 use rustc_session::config::InstrumentCoverage;
 use rustc_session::config::Strip;
 use rustc_session::config::{build_configuration, build_session_options, to_crate_config};
@@ -724,6 +725,9 @@ macro_rules! tracked {
         })
     );
     tracked!(chalk, true);
+    tracked!(cheri_int_to_ptr, CheriIntToPtr::Error);
+    tracked!(cheri_self_init_caprelocs, true);
+    tracked!(cheri_vtables, CheriVtables::Restricted);
     tracked!(codegen_backend, Some("abc".to_string()));
     tracked!(crate_attr, vec!["abc".to_string()]);
     tracked!(debug_info_for_profiling, true);
@@ -41,6 +41,31 @@ pub struct CodegenFnAttrs {
     /// The `#[repr(align(...))]` attribute. Indicates the value of which the function should be
     /// aligned to.
     pub alignment: Option<u32>,
+    /// The `#[cheriot_compartment = "..."]` attribute. Indicates that this function is an entry
+    /// point exported from, or imported from, a CHERIoT compartment.
+    pub cheriot_compartment: Option<CheriotCompartment>,
+}
+
+/// Which side of a CHERIoT compartment boundary a `#[cheriot_compartment]` function is on.
+///
+/// The backend lists exports in the `.compartment_exports` section and gives every import that
+/// is called an entry in the `.compartment_imports` section. The CHERIoT linker resolves the
+/// import entries against the export entries of the named compartment.
+#[derive(Copy, Clone, PartialEq, Eq, TyEncodable, TyDecodable, HashStable, Debug)]
+pub enum CheriotCompartment {
+    /// A function defined in this crate and exported from the named compartment.
+    Export(Symbol),
+    /// A foreign function exported from the named compartment.
+    Import(Symbol),
+}
+
+impl CheriotCompartment {
+    /// The name of the compartment that exports the function.
+    pub fn name(self) -> Symbol {
+        match self {
+            CheriotCompartment::Export(name) | CheriotCompartment::Import(name) => name,
+        }
+    }
 }
 
 bitflags! {
@@ -117,6 +142,7 @@ pub const fn new() -> CodegenFnAttrs {
             no_sanitize: SanitizerSet::empty(),
             instruction_set: None,
             alignment: None,
+            cheriot_compartment: None,
         }
     }
 
@@ -133,9 +159,11 @@ pub fn requests_inline(&self) -> bool {
     /// * `#[no_mangle]` is present
     /// * `#[export_name(...)]` is present
     /// * `#[linkage]` is present
+    /// * `#[cheriot_compartment]` is present on a function definition
     pub fn contains_extern_indicator(&self) -> bool {
         self.flags.contains(CodegenFnAttrFlags::NO_MANGLE)
             || self.export_name.is_some()
+            || matches!(self.cheriot_compartment, Some(CheriotCompartment::Export(_)))
             || match self.linkage {
                 // These are private, so make sure we don't try to consider
                 // them external.
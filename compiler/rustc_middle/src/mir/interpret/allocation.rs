@@ -32,12 +32,14 @@
 #[derive(HashStable)]
 pub struct Allocation<Prov = AllocId, Extra = ()> {
     /// The actual bytes of the allocation.
-    /// Note that the bytes of a pointer represent the offset of the pointer.
+    /// Note that the bytes of a pointer represent the offset of the pointer. When pointers are
+    /// wider than their address (i.e. capabilities), the offset is stored as an integer of the
+    /// full pointer width, so the remaining bytes are zero.
     bytes: Box<[u8]>,
     /// Maps from byte addresses to extra provenance data for each pointer.
     /// Only the first byte of a pointer is inserted into the map; i.e.,
-    /// every entry in this map applies to `pointer_size` consecutive bytes starting
-    /// at the given offset.
+    /// every entry in this map applies to `ptr_layout(None).ty_size` consecutive bytes
+    /// starting at the given offset.
     provenance: ProvenanceMap<Prov>,
     /// Denotes which part of this allocation is initialized.
     init_mask: InitMask,
@@ -279,13 +281,13 @@ pub fn adjust_from_tcx<Prov, Extra, Err>(
         // Compute new pointer provenance, which also adjusts the bytes.
         let mut bytes = self.bytes;
         let mut new_provenance = Vec::with_capacity(self.provenance.0.len());
-        // TODO: More complexity needed here. Unsure if this should be val_size or ty_size.
-        let ptr_size = cx.data_layout().ptr_layout(None).val_size.bytes_usize();
+        let ptr_layout = cx.data_layout().ptr_layout(None);
+        let ptr_size = ptr_layout.ty_size.bytes_usize();
         let endian = cx.data_layout().endian;
         for &(offset, alloc_id) in self.provenance.iter() {
             let idx = offset.bytes_usize();
             let ptr_bytes = &mut bytes[idx..idx + ptr_size];
-            let bits = read_target_uint(endian, ptr_bytes).unwrap();
+            let bits = ptr_layout.val_size.truncate(read_target_uint(endian, ptr_bytes).unwrap());
             let (ptr_prov, ptr_offset) =
                 adjust_ptr(Pointer::new(alloc_id, Size::from_bytes(bits)))?.into_parts();
             write_target_uint(endian, ptr_bytes, ptr_offset.bytes().into()).unwrap();
@@ -427,8 +429,10 @@ pub fn read_scalar(
         let bits = read_target_uint(cx.data_layout().endian, bytes).unwrap();
 
         if read_provenance {
-            // TODO: More complexity needed here. val vs ty_size.
-            assert_eq!(range.total_size, cx.data_layout().ptr_layout(None).ty_size);
+            let ptr_layout = cx.data_layout().ptr_layout(None);
+            assert_eq!(range.total_size, ptr_layout.ty_size);
+            // Only the address part of the bits is the offset of the pointer.
+            let bits = ptr_layout.val_size.truncate(bits);
 
             // When reading data with provenance, the easy case is finding provenance exactly where we
             // are reading, then we can put data and provenance back together and return that.
@@ -519,12 +523,11 @@ impl<Prov: Copy, Extra> Allocation<Prov, Extra> {
     /// Returns all provenance overlapping with the given pointer-offset pair.
     fn range_get_provenance(&self, cx: &impl HasDataLayout, range: AllocRange) -> &[(Size, Prov)] {
         // We have to go back `pointer_size - 1` bytes, as that one would still overlap with
-        // the beginning of this range.
-        // TODO: More complexity needed here. val_size vs ty_size.
+        // the beginning of this range. A pointer covers all of its `ty_size` bytes in memory.
         let start = range
             .start
             .bytes()
-            .saturating_sub(cx.data_layout().ptr_layout(None).val_size.bytes() - 1);
+            .saturating_sub(cx.data_layout().ptr_layout(None).ty_size.bytes() - 1);
         self.provenance.range(Size::from_bytes(start)..range.end())
     }
 
@@ -567,8 +570,7 @@ fn clear_provenance(&mut self, cx: &impl HasDataLayout, range: AllocRange) -> Al
 
             (
                 provenance.first().unwrap().0,
-                // TODO: More complexity needed here. val_size vs ty_size.
-                provenance.last().unwrap().0 + cx.data_layout().ptr_layout(None).val_size,
+                provenance.last().unwrap().0 + cx.data_layout().ptr_layout(None).ty_size,
             )
         };
         let start = range.start;
@@ -589,8 +591,7 @@ fn clear_provenance(&mut self, cx: &impl HasDataLayout, range: AllocRange) -> Al
         if last > end {
             if Prov::ERR_ON_PARTIAL_PTR_OVERWRITE {
                 return Err(AllocError::PartialPointerOverwrite(
-                    // TODO: More complexity needed here. val_size vs ty_size.
-                    last - cx.data_layout().ptr_layout(None).val_size,
+                    last - cx.data_layout().ptr_layout(None).ty_size,
                 ));
             }
             warn!(
@@ -647,7 +648,16 @@ pub fn prepare_provenance_copy(
         dest: Size,
         count: u64,
     ) -> AllocationProvenance<Prov> {
-        let provenance = self.range_get_provenance(cx, src);
+        let mut provenance = self.range_get_provenance(cx, src);
+        // Only pointers that are entirely within `src` are copied. Pointers overlapping the edges
+        // are either rejected by the caller or lose their provenance.
+        let ptr_size = cx.data_layout().ptr_layout(None).ty_size;
+        if let [(first, _), rest @ ..] = provenance && *first < src.start {
+            provenance = rest;
+        }
+        if let [rest @ .., (last, _)] = provenance && *last + ptr_size > src.end() {
+            provenance = rest;
+        }
         if provenance.is_empty() {
             return AllocationProvenance { dest_provenance: Vec::new() };
         }
@@ -758,7 +758,13 @@ fn field_ty_or_layout<'tcx>(
 
                 ty::Dynamic(_, _, ty::DynStar) => {
                     if i == 0 {
-                        TyMaybeWithLayout::Ty(tcx.types.usize)
+                        // Matches the capability data slot that `layout_of` gives `dyn*` on
+                        // CHERI targets.
+                        if tcx.data_layout.ptr_layout(None).is_fat_ty {
+                            TyMaybeWithLayout::Ty(tcx.mk_mut_ptr(tcx.mk_unit()))
+                        } else {
+                            TyMaybeWithLayout::Ty(tcx.types.usize)
+                        }
                     } else if i == 1 {
                         // FIXME(dyn-star) same FIXME as above applies here too
                         TyMaybeWithLayout::Ty(
@@ -1097,6 +1103,8 @@ pub fn fn_can_unwind<'tcx>(tcx: TyCtxt<'tcx>, fn_def_id: Option<DefId>, abi: Spe
         | AvrNonBlockingInterrupt
         | CCmseNonSecureCall
         | Wasm
+        | CheriotCrossCompartment
+        | CheriCCall
         | RustIntrinsic
         | PlatformIntrinsic
         | Unadjusted => false,
@@ -29,6 +29,8 @@ fn abi_can_unwind(abi: Abi) -> bool {
         | AvrNonBlockingInterrupt
         | CCmseNonSecureCall
         | Wasm
+        | CheriotCrossCompartment
+        | CheriCCall
         | RustIntrinsic
         | PlatformIntrinsic
         | Unadjusted => false,
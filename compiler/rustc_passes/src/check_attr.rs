@@ -136,6 +136,7 @@ fn check_attributes(
                 | sym::rustc_if_this_changed
                 | sym::rustc_then_this_would_need => self.check_rustc_dirty_clean(&attr),
                 sym::cmse_nonsecure_entry => self.check_cmse_nonsecure_entry(attr, span, target),
+                sym::cheriot_compartment => self.check_cheriot_compartment(attr, span, target),
                 sym::collapse_debuginfo => self.check_collapse_debuginfo(attr, span, target),
                 sym::const_trait => self.check_const_trait(attr, span, target),
                 sym::must_not_suspend => self.check_must_not_suspend(&attr, span, target),
@@ -407,6 +408,23 @@ fn check_cmse_nonsecure_entry(&self, attr: &Attribute, span: Span, target: Targe
         }
     }
 
+    /// Checks if `#[cheriot_compartment]` is applied to a function definition or a foreign
+    /// function.
+    fn check_cheriot_compartment(&self, attr: &Attribute, span: Span, target: Target) -> bool {
+        match target {
+            Target::Fn
+            | Target::ForeignFn
+            | Target::Method(MethodKind::Trait { body: true } | MethodKind::Inherent) => true,
+            _ => {
+                self.tcx.sess.emit_err(errors::AttrShouldBeAppliedToFn {
+                    attr_span: attr.span,
+                    defn_span: span,
+                });
+                false
+            }
+        }
+    }
+
     /// Debugging aid for `object_lifetime_default` query.
     fn check_object_lifetime_default(&self, hir_id: HirId) {
         let tcx = self.tcx;
@@ -2766,10 +2766,10 @@ pub fn needs_analysis(&self) -> bool {
 /// how the hash should be calculated when adding a new command-line argument.
 pub(crate) mod dep_tracking {
     use super::{
-        BranchProtection, CFGuard, CFProtection, CrateType, DebugInfo, ErrorOutputType,
-        InstrumentCoverage, LdImpl, LinkerPluginLto, LocationDetail, LtoCli, OomStrategy, OptLevel,
-        OutputType, OutputTypes, Passes, SourceFileHashAlgorithm, SplitDwarfKind,
-        SwitchWithOptPath, SymbolManglingVersion, TrimmedDefPaths,
+        BranchProtection, CFGuard, CFProtection, CheriIntToPtr, CheriVtables, CrateType, DebugInfo,
+        ErrorOutputType, InstrumentCoverage, LdImpl, LinkerPluginLto, LocationDetail, LtoCli,
+        OomStrategy, OptLevel, OutputType, OutputTypes, Passes, SourceFileHashAlgorithm,
+        SplitDwarfKind, SwitchWithOptPath, SymbolManglingVersion, TrimmedDefPaths,
     };
     use crate::lint;
     use crate::options::WasiExecModel;
@@ -2852,6 +2852,8 @@ fn hash(
         SanitizerSet,
         CFGuard,
         CFProtection,
+        CheriIntToPtr,
+        CheriVtables,
         TargetTriple,
         Edition,
         LinkerPluginLto,
@@ -2980,6 +2982,35 @@ pub fn should_panic(self) -> u8 {
     }
 }
 
+/// How casts from integers to pointers are lowered on targets where pointers are capabilities.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub enum CheriIntToPtr {
+    /// Derive the pointer from the null capability. The result has the requested address but its
+    /// tag is clear, so it can be compared and formatted but faults if dereferenced.
+    #[default]
+    NullDerived,
+
+    /// Derive the pointer from the default data capability (DDC), as CHERI C does in hybrid code.
+    /// A zero address still produces the null capability.
+    DdcDerived,
+
+    /// Reject integer-to-pointer casts at compile time.
+    Error,
+}
+
+/// How vtables are protected on targets where pointers are capabilities.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub enum CheriVtables {
+    /// Vtable pointers carry whatever bounds and permissions the linker gives the vtable.
+    #[default]
+    Plain,
+
+    /// Vtable pointers in trait objects created by compiled code are bounded to exactly their
+    /// vtable and can only be used to load from it, so a corrupted trait object can't redirect
+    /// dynamic dispatch. The vtables themselves are emitted as usual.
+    Restricted,
+}
+
 /// How to run proc-macro code when building this crate
 #[derive(Clone, Copy, PartialEq, Hash, Debug)]
 pub enum ProcMacroExecutionStrategy {
@@ -99,6 +99,14 @@ pub struct CannotMixAndMatchSanitizers {
 #[diag(session_unstable_virtual_function_elimination)]
 pub struct UnstableVirtualFunctionElimination;
 
+#[derive(Diagnostic)]
+#[diag(session_cheri_vtables_requires_purecap)]
+pub struct CheriVtablesRequiresPurecap;
+
+#[derive(Diagnostic)]
+#[diag(session_cheri_self_init_caprelocs_requires_freestanding_purecap)]
+pub struct CheriSelfInitCaprelocsRequiresFreestandingPurecap;
+
 #[derive(Diagnostic)]
 #[diag(session_unsupported_dwarf_version)]
 pub struct UnsupportedDwarfVersion {
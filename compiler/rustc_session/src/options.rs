@@ -372,6 +372,8 @@ mod desc {
     pub const parse_sanitizer_memory_track_origins: &str = "0, 1, or 2";
     pub const parse_cfguard: &str =
         "either a boolean (`yes`, `no`, `on`, `off`, etc), `checks`, or `nochecks`";
+    pub const parse_cheri_int_to_ptr: &str = "one of: `null-derived` (default), `ddc-derived`, or `error`";
+    pub const parse_cheri_vtables: &str = "either `plain` (default) or `restricted`";
     pub const parse_cfprotection: &str = "`none`|`no`|`n` (default), `branch`, `return`, or `full`|`yes`|`y` (equivalent to `branch` and `return`)";
     pub const parse_strip: &str = "either `none`, `debuginfo`, or `symbols`";
     pub const parse_linker_flavor: &str = ::rustc_target::spec::LinkerFlavorCli::one_of();
@@ -755,6 +757,25 @@ pub(crate) fn parse_cfprotection(slot: &mut CFProtection, v: Option<&str>) -> bo
         true
     }
 
+    pub(crate) fn parse_cheri_int_to_ptr(slot: &mut CheriIntToPtr, v: Option<&str>) -> bool {
+        *slot = match v {
+            Some("null-derived") => CheriIntToPtr::NullDerived,
+            Some("ddc-derived") => CheriIntToPtr::DdcDerived,
+            Some("error") => CheriIntToPtr::Error,
+            _ => return false,
+        };
+        true
+    }
+
+    pub(crate) fn parse_cheri_vtables(slot: &mut CheriVtables, v: Option<&str>) -> bool {
+        *slot = match v {
+            Some("plain") => CheriVtables::Plain,
+            Some("restricted") => CheriVtables::Restricted,
+            _ => return false,
+        };
+        true
+    }
+
     pub(crate) fn parse_linker_flavor(slot: &mut Option<LinkerFlavorCli>, v: Option<&str>) -> bool {
         match v.and_then(LinkerFlavorCli::from_str) {
             Some(lf) => *slot = Some(lf),
@@ -1235,6 +1256,15 @@ pub(crate) fn parse_proc_macro_execution_strategy(
         "the codegen unit partitioning strategy to use"),
     chalk: bool = (false, parse_bool, [TRACKED],
         "enable the experimental Chalk-based trait solving engine"),
+    cheri_int_to_ptr: CheriIntToPtr = (CheriIntToPtr::NullDerived, parse_cheri_int_to_ptr, [TRACKED],
+        "how to lower integer-to-pointer casts on capability targets \
+        (`null-derived` (default), `ddc-derived`, or `error`)"),
+    cheri_self_init_caprelocs: bool = (false, parse_bool, [TRACKED],
+        "initialize capabilities from `__cap_relocs` before the entry point of executables for \
+        freestanding capability targets (default: no)"),
+    cheri_vtables: CheriVtables = (CheriVtables::Plain, parse_cheri_vtables, [TRACKED],
+        "restrict vtable pointers in trait objects on capability targets \
+        (`plain` (default) or `restricted`)"),
     codegen_backend: Option<String> = (None, parse_opt_string, [TRACKED],
         "the backend to use"),
     combine_cgu: bool = (false, parse_bool, [TRACKED],
@@ -1,12 +1,15 @@
 use crate::cgu_reuse_tracker::CguReuseTracker;
 use crate::code_stats::CodeStats;
 pub use crate::code_stats::{DataTypeKind, FieldInfo, SizeKind, VariantInfo};
-use crate::config::{self, CrateType, InstrumentCoverage, OptLevel, OutputType, SwitchWithOptPath};
+use crate::config::{
+    self, CheriVtables, CrateType, InstrumentCoverage, OptLevel, OutputType, SwitchWithOptPath,
+};
 use crate::errors::{
-    CannotEnableCrtStaticLinux, CannotMixAndMatchSanitizers, LinkerPluginToWindowsNotSupported,
-    NotCircumventFeature, ProfileSampleUseFileDoesNotExist, ProfileUseFileDoesNotExist,
-    SanitizerCfiEnabled, SanitizerNotSupported, SanitizersNotSupported, SkippingConstChecks,
-    SplitDebugInfoUnstablePlatform, StackProtectorNotSupportedForTarget,
+    CannotEnableCrtStaticLinux, CannotMixAndMatchSanitizers,
+    CheriSelfInitCaprelocsRequiresFreestandingPurecap, CheriVtablesRequiresPurecap,
+    LinkerPluginToWindowsNotSupported, NotCircumventFeature, ProfileSampleUseFileDoesNotExist,
+    ProfileUseFileDoesNotExist, SanitizerCfiEnabled, SanitizerNotSupported, SanitizersNotSupported,
+    SkippingConstChecks, SplitDebugInfoUnstablePlatform, StackProtectorNotSupportedForTarget,
     TargetRequiresUnwindTables, UnleashedFeatureHelp, UnstableVirtualFunctionElimination,
     UnsupportedDwarfVersion,
 };
@@ -1491,6 +1494,19 @@ fn validate_commandline_args_with_session_available(sess: &Session) {
         }
     }
 
+    // Restricting vtable capabilities only makes sense if pointers are capabilities.
+    if sess.opts.unstable_opts.cheri_vtables == CheriVtables::Restricted
+        && sess.target.pointer_type_width.is_none()
+    {
+        sess.emit_err(CheriVtablesRequiresPurecap);
+    }
+
+    // Hosted targets have a runtime linker that processes `__cap_relocs`, and CHERIoT has no DDC
+    // to derive the capabilities from.
+    if sess.opts.unstable_opts.cheri_self_init_caprelocs && !sess.target.self_init_caprelocs {
+        sess.emit_err(CheriSelfInitCaprelocsRequiresFreestandingPurecap);
+    }
+
     // Sanitizers can only be used on platforms that we know have working sanitizer codegen.
     let supported_sanitizers = sess.target.options.supported_sanitizers;
     let unsupported_sanitizers = sess.opts.unstable_opts.sanitizer - supported_sanitizers;
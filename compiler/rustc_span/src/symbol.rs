@@ -323,6 +323,7 @@
         abi_amdgpu_kernel,
         abi_avr_interrupt,
         abi_c_cmse_nonsecure_call,
+        abi_cheri_ccall,
         abi_efiapi,
         abi_msp430_interrupt,
         abi_ptx,
@@ -476,6 +477,16 @@
         cfg_version,
         cfi,
         char,
+        cheri_cap_base_get,
+        cheri_cap_equal_exact,
+        cheri_cap_length_get,
+        cheri_cap_perms_get,
+        cheri_cap_sealed_get,
+        cheri_cap_tag_get,
+        cheri_cap_type_get,
+        cheri_ddc_get,
+        cheri_pcc_get,
+        cheriot_compartment,
         client,
         clippy,
         clobber_abi,
@@ -966,6 +977,7 @@
         module_path_macro,
         more_qualified_paths,
         more_struct_aliases,
+        morello_target_feature,
         movbe_target_feature,
         move_ref_pattern,
         move_size_limit,
@@ -1337,6 +1349,7 @@
         sig_ign,
         simd,
         simd_add,
+        simd_addr,
         simd_and,
         simd_arith_offset,
         simd_as,
@@ -1400,6 +1413,7 @@
         simd_shuffle,
         simd_sub,
         simd_trunc,
+        simd_with_addr,
         simd_xor,
         since,
         sinf32,
@@ -601,6 +601,14 @@ pub enum Conv {
     AmdGpuKernel,
     AvrInterrupt,
     AvrNonBlockingInterrupt,
+
+    /// A call into another CHERIoT compartment, made through the switcher.
+    CheriotCompartmentCall,
+    /// An entry point exported from the CHERIoT compartment being compiled.
+    CheriotCompartmentCallee,
+
+    /// A domain-crossing call that unseals a sealed code and data capability pair.
+    CheriCCall,
 }
 
 /// Metadata describing how the arguments to a native function
@@ -634,6 +642,8 @@ pub struct FnAbi<'a, Ty> {
 pub enum AdjustForForeignAbiError {
     /// Target architecture doesn't support "foreign" (i.e. non-Rust) ABIs.
     Unsupported { arch: Symbol, abi: spec::abi::Abi },
+    /// A `cheri-ccall` function doesn't take the sealed pair as its first two arguments.
+    MissingSealedPair,
 }
 
 impl fmt::Display for AdjustForForeignAbiError {
@@ -642,6 +652,11 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             Self::Unsupported { arch, abi } => {
                 write!(f, "target architecture {:?} does not support `extern {}` ABI", arch, abi)
             }
+            Self::MissingSealedPair => write!(
+                f,
+                "`extern \"cheri-ccall\"` functions must take the sealed code and data \
+                 capabilities as their first two arguments"
+            ),
         }
     }
 }
@@ -663,6 +678,18 @@ pub fn adjust_for_foreign_abi<C>(
             return Ok(());
         }
 
+        if abi == spec::abi::Abi::CheriCCall {
+            // `CInvoke` and `BRS` unseal the pair from fixed registers, which the backend
+            // fills from the first two arguments.
+            let is_capability = |arg: &ArgAbi<'a, Ty>| match arg.layout.abi {
+                Abi::Scalar(scalar) => scalar.primitive().is_ptr(),
+                _ => false,
+            };
+            if self.args.len() < 2 || !self.args[..2].iter().all(is_capability) {
+                return Err(AdjustForForeignAbiError::MissingSealedPair);
+            }
+        }
+
         match &cx.target_spec().arch[..] {
             "x86" => {
                 let flavor = if let spec::abi::Abi::Fastcall { .. }
@@ -318,13 +318,14 @@ pub fn compute_abi_info<'a, Ty, C>(cx: &C, fn_abi: &mut FnAbi<'a, Ty>)
     C: HasDataLayout + HasTargetSpec,
 {
     let flen = match &cx.target_spec().llvm_abiname[..] {
-        "ilp32f" | "lp64f" => 32,
-        "ilp32d" | "lp64d" => 64,
+        "ilp32f" | "lp64f" | "il32pc64f" | "l64pc128f" => 32,
+        "ilp32d" | "lp64d" | "il32pc64d" | "l64pc128d" => 64,
         _ => 0,
     };
     let xlen: u64 = cx.target_spec().pointer_width.into();
 
-    let mut avail_gprs = 8;
+    // CHERIoT is based on RV32E, which only has six argument registers.
+    let mut avail_gprs = if cx.target_spec().llvm_abiname == "cheriot" { 6 } else { 8 };
     let mut avail_fprs = 8;
 
     if !fn_abi.ret.is_ignore() && classify_ret(cx, &mut fn_abi.ret, xlen, flen) {
@@ -261,7 +261,8 @@ pub fn parse<'a>(target: &'a Target) -> Result<TargetDataLayout, TargetDataLayou
             Err(err) => return Err(TargetDataLayoutErrors::InvalidBitsSize { err }),
         };
 
-        dl.is_cheri_purecap = target.llvm_target.ends_with("purecap");
+        dl.is_cheri_purecap =
+            target.llvm_target.ends_with("purecap") || target.llvm_abiname == "cheriot";
 
         Ok(dl)
     }
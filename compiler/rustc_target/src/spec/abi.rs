@@ -32,6 +32,8 @@ pub enum Abi {
     AvrNonBlockingInterrupt,
     CCmseNonSecureCall,
     Wasm,
+    CheriotCrossCompartment,
+    CheriCCall,
     System { unwind: bool },
     RustIntrinsic,
     RustCall,
@@ -100,6 +102,8 @@ pub struct AbiData {
     AbiData { abi: Abi::AvrNonBlockingInterrupt, name: "avr-non-blocking-interrupt" },
     AbiData { abi: Abi::CCmseNonSecureCall, name: "C-cmse-nonsecure-call" },
     AbiData { abi: Abi::Wasm, name: "wasm" },
+    AbiData { abi: Abi::CheriotCrossCompartment, name: "cheriot-cross-compartment" },
+    AbiData { abi: Abi::CheriCCall, name: "cheri-ccall" },
     AbiData { abi: Abi::System { unwind: false }, name: "system" },
     AbiData { abi: Abi::System { unwind: true }, name: "system-unwind" },
     AbiData { abi: Abi::RustIntrinsic, name: "rust-intrinsic" },
@@ -250,6 +254,14 @@ pub fn is_stable(name: &str) -> Result<(), AbiDisabled> {
             feature: sym::wasm_abi,
             explain: "wasm ABI is experimental and subject to change",
         }),
+        "cheriot-cross-compartment" => Err(AbiDisabled::Unstable {
+            feature: sym::cheriot_compartment,
+            explain: "cheriot-cross-compartment ABI is experimental and subject to change",
+        }),
+        "cheri-ccall" => Err(AbiDisabled::Unstable {
+            feature: sym::abi_cheri_ccall,
+            explain: "cheri-ccall ABI is experimental and subject to change",
+        }),
         _ => Err(AbiDisabled::Unrecognized),
     }
 }
@@ -294,14 +306,16 @@ pub fn index(self) -> usize {
             AvrNonBlockingInterrupt => 25,
             CCmseNonSecureCall => 26,
             Wasm => 27,
+            CheriotCrossCompartment => 28,
+            CheriCCall => 29,
             // Cross-platform ABIs
-            System { unwind: false } => 28,
-            System { unwind: true } => 29,
-            RustIntrinsic => 30,
-            RustCall => 31,
-            PlatformIntrinsic => 32,
-            Unadjusted => 33,
-            RustCold => 34,
+            System { unwind: false } => 30,
+            System { unwind: true } => 31,
+            RustIntrinsic => 32,
+            RustCall => 33,
+            PlatformIntrinsic => 34,
+            Unadjusted => 35,
+            RustCold => 36,
         };
         debug_assert!(
             AbiDatas
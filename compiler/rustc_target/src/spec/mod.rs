@@ -1083,6 +1083,7 @@ fn $module() {
     ("powerpc64-unknown-freebsd", powerpc64_unknown_freebsd),
     ("powerpc64le-unknown-freebsd", powerpc64le_unknown_freebsd),
     ("riscv64gc-unknown-freebsd", riscv64gc_unknown_freebsd),
+    ("riscv64imafdcxcheri-unknown-freebsd-purecap", riscv64imafdcxcheri_unknown_freebsd_purecap),
     ("x86_64-unknown-freebsd", x86_64_unknown_freebsd),
 
     ("x86_64-unknown-dragonfly", x86_64_unknown_dragonfly),
@@ -1191,6 +1192,7 @@ fn $module() {
     ("riscv32im-unknown-none-elf", riscv32im_unknown_none_elf),
     ("riscv32imc-unknown-none-elf", riscv32imc_unknown_none_elf),
     ("riscv32imcxcheri-unknown-none-purecap", riscv32imcxcheri_unknown_none_purecap),
+    ("riscv32cheriot-unknown-none", riscv32cheriot_unknown_none),
     ("riscv32imc-esp-espidf", riscv32imc_esp_espidf),
     ("riscv32imac-unknown-none-elf", riscv32imac_unknown_none_elf),
     ("riscv32imac-unknown-xous-elf", riscv32imac_unknown_xous_elf),
@@ -1349,6 +1351,10 @@ pub struct TargetOptions {
     /// Width of the pointer type itself. Specify for targets where this is not
     /// equal to the pointer address width.
     pub pointer_type_width: Option<u32>,
+    /// Whether freestanding executables for this capability target can initialize their
+    /// capabilities from `__cap_relocs` with `-Z cheri-self-init-caprelocs`, which derives them
+    /// from DDC. Defaults to false.
+    pub self_init_caprelocs: bool,
     /// Width of c_int type. Defaults to "32".
     pub c_int_width: StaticCow<str>,
     /// OS name to use for conditional compilation (`target_os`). Defaults to "none".
@@ -1786,6 +1792,7 @@ fn default() -> TargetOptions {
             is_builtin: false,
             endian: Endian::Little,
             pointer_type_width: None,
+            self_init_caprelocs: false,
             c_int_width: "32".into(),
             os: "none".into(),
             env: "".into(),
@@ -1960,6 +1967,9 @@ pub fn is_abi_supported(&self, abi: Abi) -> Option<bool> {
             AmdGpuKernel => self.arch == "amdgcn",
             AvrInterrupt | AvrNonBlockingInterrupt => self.arch == "avr",
             Wasm => ["wasm32", "wasm64"].contains(&&self.arch[..]),
+            CheriotCrossCompartment => self.llvm_abiname == "cheriot",
+            // CHERIoT has no `CInvoke`; it crosses compartments through its switcher instead.
+            CheriCCall => self.pointer_type_width.is_some() && self.llvm_abiname != "cheriot",
             Thiscall { .. } => self.arch == "x86",
             // On windows these fall-back to platform native calling convention (C) when the
             // architecture is not supported.
@@ -2437,6 +2447,7 @@ macro_rules! key {
 
         key!(is_builtin, bool);
         key!(pointer_type_width, Option<u32>);
+        key!(self_init_caprelocs, bool);
         key!(c_int_width = "target-c-int-width");
         key!(os);
         key!(env);
@@ -2688,6 +2699,7 @@ macro_rules! target_option_val {
         target_option_val!(is_builtin);
         target_option_val!(endian, "target-endian");
         target_option_val!(pointer_type_width, "target-pointer-type-width");
+        target_option_val!(self_init_caprelocs);
         target_option_val!(c_int_width, "target-c-int-width");
         target_option_val!(os);
         target_option_val!(env);
@@ -9,6 +9,7 @@ pub fn target() -> Target {
 
         options: TargetOptions {
             pointer_type_width: Some(128),
+            self_init_caprelocs: true,
             linker_flavor: LinkerFlavor::Gnu(Cc::No, Lld::Yes),
             linker: Some("lld".into()),
             llvm_abiname: "purecap".into(),
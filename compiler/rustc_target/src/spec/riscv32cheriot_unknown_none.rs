@@ -0,0 +1,31 @@
+use crate::spec::{Cc, LinkerFlavor, Lld, PanicStrategy, RelocModel, Target, TargetOptions};
+
+pub fn target() -> Target {
+    Target {
+        data_layout: "e-m:e-pf200:64:64:64:32-p:32:32-i64:64-n32-S128-A200-P200-G200".into(),
+        llvm_target: "riscv32cheriot-unknown-unknown".into(),
+        pointer_width: 32,
+        arch: "riscv32".into(),
+
+        options: TargetOptions {
+            pointer_type_width: Some(64),
+            linker_flavor: LinkerFlavor::Gnu(Cc::No, Lld::Yes),
+            linker: Some("rust-lld".into()),
+            // Exposed as `target_abi = "cheriot"`, so that code for other 64-bit capability
+            // targets, such as `core::arch::cheri`, can leave out what CHERIoT doesn't support.
+            abi: "cheriot".into(),
+            llvm_abiname: "cheriot".into(),
+            cpu: "cheriot".into(),
+            // CHERIoT cores have no atomic extension; the RTOS provides atomics as
+            // library calls.
+            max_atomic_width: Some(32),
+            atomic_cas: false,
+            features: "+e,+m,+c,+xcheri,+cap-mode,+xcheriot".into(),
+            panic_strategy: PanicStrategy::Abort,
+            relocation_model: RelocModel::Static,
+            emit_debug_gdb_scripts: false,
+            eh_frame_header: false,
+            ..Default::default()
+        },
+    }
+}
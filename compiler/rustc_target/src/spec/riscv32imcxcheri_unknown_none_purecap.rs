@@ -9,6 +9,7 @@ pub fn target() -> Target {
 
         options: TargetOptions {
             pointer_type_width: Some(64),
+            self_init_caprelocs: true,
             linker_flavor: LinkerFlavor::Gnu(Cc::No, Lld::Yes),
             linker: Some("rust-lld".into()),
             llvm_abiname: "il32pc64".into(),
@@ -0,0 +1,20 @@
+use crate::spec::{CodeModel, Target, TargetOptions};
+
+pub fn target() -> Target {
+    Target {
+        llvm_target: "riscv64-unknown-freebsd-purecap".into(),
+        pointer_width: 64,
+        data_layout: "e-m:e-pf200:128:128:128:64-p:64:64-i64:64-i128:128-n64-S128-A200-P200-G200"
+            .into(),
+        arch: "riscv64".into(),
+        options: TargetOptions {
+            pointer_type_width: Some(128),
+            code_model: Some(CodeModel::Medium),
+            cpu: "generic-rv64".into(),
+            features: "+m,+a,+f,+d,+c,+xcheri,+cap-mode".into(),
+            llvm_abiname: "l64pc128d".into(),
+            max_atomic_width: Some(64),
+            ..super::freebsd_base::opts()
+        },
+    }
+}
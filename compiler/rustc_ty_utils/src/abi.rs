@@ -151,6 +151,8 @@ fn conv_from_spec_abi(tcx: TyCtxt<'_>, abi: SpecAbi) -> Conv {
         AvrInterrupt => Conv::AvrInterrupt,
         AvrNonBlockingInterrupt => Conv::AvrNonBlockingInterrupt,
         Wasm => Conv::C,
+        CheriotCrossCompartment => Conv::CheriotCompartmentCall,
+        CheriCCall => Conv::CheriCCall,
 
         // These API constants ought to be more specific...
         Cdecl { .. } => Conv::C,
@@ -292,7 +294,15 @@ fn fn_abi_new_uncached<'tcx>(
 ) -> Result<&'tcx FnAbi<'tcx, Ty<'tcx>>, FnAbiError<'tcx>> {
     let sig = cx.tcx.normalize_erasing_late_bound_regions(cx.param_env, sig);
 
-    let conv = conv_from_spec_abi(cx.tcx(), sig.abi);
+    let mut conv = conv_from_spec_abi(cx.tcx(), sig.abi);
+    // Compartment entry points defined in this crate are called directly from within the
+    // compartment; everything else (imports, other crates, function pointers) goes through
+    // the switcher.
+    if conv == Conv::CheriotCompartmentCall
+        && fn_def_id.map_or(false, |def_id| def_id.is_local() && !cx.tcx.is_foreign_item(def_id))
+    {
+        conv = Conv::CheriotCompartmentCallee;
+    }
 
     let mut inputs = sig.inputs();
     let extra_args = if sig.abi == RustCall {
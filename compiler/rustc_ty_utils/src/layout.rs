@@ -433,7 +433,12 @@ fn layout_of_uncached<'tcx>(
 
         ty::Dynamic(_, _, ty::DynStar) => {
             // TODO: More complexity needed here.
-            let mut data = scalar_unit(Int(dl.ptr_sized_integer(None), false));
+            // On CHERI targets the data is a capability, so that a pointer keeps its tag.
+            let mut data = if dl.ptr_layout(None).is_fat_ty {
+                scalar_unit(Pointer)
+            } else {
+                scalar_unit(Int(dl.ptr_sized_integer(None), false))
+            };
             data.valid_range_mut().start = 0;
             let mut vtable = scalar_unit(Pointer);
             vtable.valid_range_mut().start = 1;
@@ -0,0 +1,197 @@
+//! Morello system registers and capability instructions.
+//!
+//! On `morello+c64` targets pointers are capabilities, so the capability operands of these
+//! intrinsics are plain raw pointers.
+
+use crate::arch::asm;
+
+#[allow(improper_ctypes)]
+extern "unadjusted" {
+    #[link_name = "llvm.cheri.ddc.get"]
+    fn ddc_get() -> *mut u8;
+    #[link_name = "llvm.cheri.pcc.get"]
+    fn pcc_get() -> *const u8;
+    #[link_name = "llvm.cheri.cap.address.get"]
+    fn cap_address_get(cap: *const u8) -> usize;
+    #[link_name = "llvm.cheri.cap.bounds.set.exact"]
+    fn cap_bounds_set_exact(cap: *const u8, length: usize) -> *const u8;
+    #[link_name = "llvm.cheri.cap.perms.and"]
+    fn cap_perms_and(cap: *const u8, perms: usize) -> *const u8;
+    #[link_name = "llvm.cheri.cap.seal"]
+    fn cap_seal(cap: *const u8, sealer: *const u8) -> *const u8;
+    #[link_name = "llvm.cheri.cap.build"]
+    fn cap_build(auth: *const u8, bits: *const u8) -> *const u8;
+    #[link_name = "llvm.cheri.cap.from.pointer"]
+    fn cap_from_pointer(auth: *const u8, addr: usize) -> *mut u8;
+    #[link_name = "llvm.cheri.cap.to.pointer"]
+    fn cap_to_pointer(auth: *const u8, cap: *const u8) -> usize;
+}
+
+// The instructions below have no LLVM intrinsic, and inline assembly has no capability register
+// operands, so they are wrapped in functions that take and return their capabilities in the
+// registers of the calling convention.
+
+#[naked]
+unsafe extern "C" fn ddc_set(_ddc: *mut u8) {
+    // SAFETY: the caller of `__ddc_write` upholds its contract.
+    unsafe { asm!("msr DDC, c0", "ret", options(noreturn)) }
+}
+
+#[naked]
+unsafe extern "C" fn rddc_get() -> *mut u8 {
+    // SAFETY: reading RDDC_EL0 has no side effects.
+    unsafe { asm!("mrs c0, RDDC_EL0", "ret", options(noreturn)) }
+}
+
+#[naked]
+unsafe extern "C" fn chktgd(_cap: *const u8) -> bool {
+    // SAFETY: checking the tag has no side effects.
+    unsafe { asm!("chktgd c0", "cset w0, cs", "ret", options(noreturn)) }
+}
+
+/// Reads the capability control register, `CCTLR_EL0`.
+#[inline]
+#[unstable(feature = "stdarch_morello", issue = "none")]
+pub fn __cctlr_el0_read() -> u64 {
+    let value: u64;
+    // SAFETY: reading CCTLR_EL0 has no side effects.
+    unsafe { asm!("mrs {}, CCTLR_EL0", out(reg) value, options(nomem, nostack, preserves_flags)) };
+    value
+}
+
+/// Writes the capability control register, `CCTLR_EL0`.
+///
+/// # Safety
+///
+/// `CCTLR_EL0` changes how capabilities are used by the rest of the program, for example
+/// whether `BLR` and `RET` seal and unseal their targets. The program must be prepared for the
+/// new value.
+#[inline]
+#[unstable(feature = "stdarch_morello", issue = "none")]
+pub unsafe fn __cctlr_el0_write(value: u64) {
+    // SAFETY: the caller upholds the contract above.
+    unsafe { asm!("msr CCTLR_EL0, {}", "isb", in(reg) value, options(nostack, preserves_flags)) };
+}
+
+/// Reads the default data capability, `DDC`.
+#[inline]
+#[unstable(feature = "stdarch_morello", issue = "none")]
+pub fn __ddc_read() -> *mut u8 {
+    // SAFETY: reading DDC has no side effects.
+    unsafe { ddc_get() }
+}
+
+/// Writes the default data capability, `DDC`.
+///
+/// # Safety
+///
+/// Accesses through integer addresses, such as those of hybrid code, are checked against `DDC`
+/// and may start faulting or reach memory that the program doesn't expect.
+#[inline]
+#[unstable(feature = "stdarch_morello", issue = "none")]
+pub unsafe fn __ddc_write(ddc: *mut u8) {
+    // SAFETY: the caller upholds the contract above.
+    unsafe { ddc_set(ddc) }
+}
+
+/// Reads the restricted default data capability, `RDDC_EL0`.
+#[inline]
+#[unstable(feature = "stdarch_morello", issue = "none")]
+pub fn __rddc_read() -> *mut u8 {
+    // SAFETY: reading RDDC_EL0 has no side effects.
+    unsafe { rddc_get() }
+}
+
+/// Returns whether `cap` is tagged, with `CHKTGD`.
+#[inline]
+#[unstable(feature = "stdarch_morello", issue = "none")]
+pub fn __chktgd(cap: *const u8) -> bool {
+    // SAFETY: checking the tag has no side effects.
+    unsafe { chktgd(cap) }
+}
+
+/// Returns the address of `cap`, with `GCVALUE`.
+#[inline]
+#[unstable(feature = "stdarch_morello", issue = "none")]
+pub fn __gcvalue(cap: *const u8) -> usize {
+    // SAFETY: reading the address has no side effects.
+    unsafe { cap_address_get(cap) }
+}
+
+/// Sets the bounds of `cap` to `length` bytes from its address, with `SCBNDSE`.
+///
+/// The result is untagged if the bounds can't be represented exactly or exceed those of `cap`.
+#[inline]
+#[unstable(feature = "stdarch_morello", issue = "none")]
+pub fn __scbndse(cap: *const u8, length: usize) -> *const u8 {
+    // SAFETY: narrowing bounds only restricts what the capability grants.
+    unsafe { cap_bounds_set_exact(cap, length) }
+}
+
+/// Clears the permissions in `perms` from `cap`, with `CLRPERM`.
+#[inline]
+#[unstable(feature = "stdarch_morello", issue = "none")]
+pub fn __clrperm(cap: *const u8, perms: usize) -> *const u8 {
+    // SAFETY: clearing permissions only restricts what the capability grants.
+    unsafe { cap_perms_and(cap, !perms) }
+}
+
+/// Seals `cap` with the object type that is the address of `sealer`, with `SEAL`.
+///
+/// The result is untagged if `sealer` doesn't grant the seal permission for that object type.
+#[inline]
+#[unstable(feature = "stdarch_morello", issue = "none")]
+pub fn __seal(cap: *const u8, sealer: *const u8) -> *const u8 {
+    // SAFETY: a sealed capability grants nothing until it is unsealed.
+    unsafe { cap_seal(cap, sealer) }
+}
+
+/// Rebuilds a tagged capability from the bit pattern `bits`, authorized by `auth`, with `BUILD`.
+///
+/// The result is untagged if `bits` grants more than `auth`.
+#[inline]
+#[unstable(feature = "stdarch_morello", issue = "none")]
+pub fn __build(bits: *const u8, auth: *const u8) -> *const u8 {
+    // SAFETY: the result never grants more than `auth`.
+    unsafe { cap_build(auth, bits) }
+}
+
+/// Converts the integer address `addr` to a capability derived from `DDC`, with `CVTD`.
+///
+/// An address of zero gives a null capability.
+#[inline]
+#[unstable(feature = "stdarch_morello", issue = "none")]
+pub fn __cvtd(addr: usize) -> *mut u8 {
+    // SAFETY: the result never grants more than DDC.
+    unsafe { cap_from_pointer(ddc_get(), addr) }
+}
+
+/// Converts `cap` to an integer address relative to `DDC`, with `CVTD`.
+///
+/// An untagged capability gives zero.
+#[inline]
+#[unstable(feature = "stdarch_morello", issue = "none")]
+pub fn __cvtd_to_addr(cap: *const u8) -> usize {
+    // SAFETY: the conversion has no side effects.
+    unsafe { cap_to_pointer(ddc_get(), cap) }
+}
+
+/// Converts the integer address `addr` to a capability derived from `PCC`, with `CVTP`.
+///
+/// An address of zero gives a null capability.
+#[inline]
+#[unstable(feature = "stdarch_morello", issue = "none")]
+pub fn __cvtp(addr: usize) -> *const u8 {
+    // SAFETY: the result never grants more than PCC.
+    unsafe { cap_from_pointer(pcc_get(), addr) }
+}
+
+/// Converts `cap` to an integer address relative to `PCC`, with `CVTP`.
+///
+/// An untagged capability gives zero.
+#[inline]
+#[unstable(feature = "stdarch_morello", issue = "none")]
+pub fn __cvtp_to_addr(cap: *const u8) -> usize {
+    // SAFETY: the conversion has no side effects.
+    unsafe { cap_to_pointer(pcc_get(), cap) }
+}
@@ -0,0 +1,100 @@
+//! Crossing between CHERI protection domains.
+//!
+//! A domain hands out its entry points as a [`SealedPair`]: a code capability and a data
+//! capability sealed with the same object type. The holder can neither dereference nor modify
+//! either half; all it can do is invoke the pair, which unseals both and transfers control to
+//! the code with the data capability installed (`CInvoke` on CHERI-RISC-V, `BLRS` on Morello).
+//! Neither side has to trust the other with anything it wasn't explicitly passed.
+
+use crate::fmt;
+use crate::marker::PhantomData;
+use crate::mem;
+
+/// A sealed code and data capability pair through which another protection domain is
+/// entered.
+///
+/// `F` is the `extern "cheri-ccall"` function type of the entry point. Its first two parameters
+/// receive the unsealed code and data capabilities, and the remaining ones are the arguments
+/// passed to [`call`](SealedPair::call).
+#[unstable(feature = "cheri_sealed_pair", issue = "none")]
+#[repr(C)]
+pub struct SealedPair<F> {
+    code: *const (),
+    data: *mut (),
+    _marker: PhantomData<F>,
+}
+
+impl<F> SealedPair<F> {
+    /// Creates a pair from a sealed code capability and the data capability sealed with it.
+    ///
+    /// # Safety
+    ///
+    /// `code` and `data` must be sealed with the same object type, and the code they unseal to
+    /// must expect to be entered with the signature `F`.
+    #[unstable(feature = "cheri_sealed_pair", issue = "none")]
+    #[inline]
+    pub const unsafe fn new(code: *const (), data: *mut ()) -> Self {
+        SealedPair { code, data, _marker: PhantomData }
+    }
+
+    /// Returns the sealed code capability.
+    #[unstable(feature = "cheri_sealed_pair", issue = "none")]
+    #[inline]
+    pub const fn code(self) -> *const () {
+        self.code
+    }
+
+    /// Returns the sealed data capability.
+    #[unstable(feature = "cheri_sealed_pair", issue = "none")]
+    #[inline]
+    pub const fn data(self) -> *mut () {
+        self.data
+    }
+}
+
+#[unstable(feature = "cheri_sealed_pair", issue = "none")]
+impl<F> Clone for SealedPair<F> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+#[unstable(feature = "cheri_sealed_pair", issue = "none")]
+impl<F> Copy for SealedPair<F> {}
+
+#[unstable(feature = "cheri_sealed_pair", issue = "none")]
+impl<F> fmt::Debug for SealedPair<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SealedPair").field("code", &self.code).field("data", &self.data).finish()
+    }
+}
+
+macro_rules! sealed_pair_call {
+    ($($arg:ident: $ty:ident),*) => {
+        impl<R, $($ty),*> SealedPair<extern "cheri-ccall" fn(*const (), *mut () $(, $ty)*) -> R> {
+            /// Enters the domain through the pair, passing it the given arguments.
+            ///
+            /// The callee only gains access to the capabilities in the arguments, and the
+            /// caller's state is restored when it returns.
+            #[unstable(feature = "cheri_sealed_pair", issue = "none")]
+            #[inline]
+            pub fn call(self, $($arg: $ty),*) -> R {
+                // SAFETY: `new` guarantees that the pair is entered with this signature. The
+                // `cheri-ccall` convention branches through the sealed pair passed as the first
+                // two arguments, so the code capability is never called directly.
+                let entry: extern "cheri-ccall" fn(*const (), *mut () $(, $ty)*) -> R =
+                    unsafe { mem::transmute(self.code) };
+                entry(self.code, self.data $(, $arg)*)
+            }
+        }
+    };
+}
+
+sealed_pair_call!();
+sealed_pair_call!(a: A);
+sealed_pair_call!(a: A, b: B);
+sealed_pair_call!(a: A, b: B, c: C);
+sealed_pair_call!(a: A, b: B, c: C, d: D);
+sealed_pair_call!(a: A, b: B, c: C, d: D, e: E);
+sealed_pair_call!(a: A, b: B, c: C, d: D, e: E, f: F);
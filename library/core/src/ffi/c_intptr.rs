@@ -0,0 +1,362 @@
+use crate::cmp::Ordering;
+use crate::fmt;
+use crate::hash::{Hash, Hasher};
+use crate::ops::{Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign};
+use crate::ops::{Div, DivAssign, Mul, MulAssign, Not, Rem, RemAssign, Shl, ShlAssign, Shr};
+use crate::ops::{ShrAssign, Sub, SubAssign};
+use crate::ptr;
+
+/// Equivalent to C's `uintptr_t` type, from `stdint.h`.
+///
+/// On most platforms `uintptr_t` is an ordinary integer and this type behaves like [`usize`].
+/// On CHERI platforms, however, `uintptr_t` is a capability: it has the size and alignment of
+/// a pointer, and arithmetic on it changes the address while keeping the provenance, bounds
+/// and permissions of the original pointer. This type has the same in-memory layout, so it can
+/// be used in bindings to C structs and functions that contain `uintptr_t`.
+///
+/// Arithmetic and bitwise operations act on the [address][Self::addr] and wrap on overflow, like
+/// C's unsigned arithmetic. When both operands are `c_uintptr`, the result takes its provenance
+/// from the left-hand operand. The capability stays valid as long as the new address is
+/// representable within its bounds; otherwise the hardware clears its tag, exactly as it would
+/// for the equivalent C code.
+///
+/// Converting to and from raw pointers is lossless. Converting from a [`usize`] produces a
+/// value without provenance, which can't be used to access memory.
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+#[unstable(feature = "c_intptr", issue = "none")]
+pub struct c_uintptr(*mut ());
+
+/// Equivalent to C's `intptr_t` type, from `stdint.h`.
+///
+/// This is the signed counterpart of [`c_uintptr`]: it has the in-memory layout of a pointer,
+/// and its arithmetic, comparisons and formatting treat the address as an [`isize`]. See
+/// [`c_uintptr`] for how provenance is kept through arithmetic on CHERI platforms.
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+#[unstable(feature = "c_intptr", issue = "none")]
+pub struct c_intptr(*mut ());
+
+// These are integers as far as C is concerned; holding one doesn't give access to anything
+// without an `unsafe` dereference of the pointer it is converted back into.
+#[unstable(feature = "c_intptr", issue = "none")]
+unsafe impl Send for c_uintptr {}
+#[unstable(feature = "c_intptr", issue = "none")]
+unsafe impl Sync for c_uintptr {}
+#[unstable(feature = "c_intptr", issue = "none")]
+unsafe impl Send for c_intptr {}
+#[unstable(feature = "c_intptr", issue = "none")]
+unsafe impl Sync for c_intptr {}
+
+macro_rules! c_intptr_impl {
+    ($Ty:ident, $Int:ty) => {
+        impl $Ty {
+            /// Creates a value from a pointer, keeping its provenance.
+            #[inline]
+            #[unstable(feature = "c_intptr", issue = "none")]
+            pub fn from_ptr<T>(ptr: *const T) -> Self {
+                $Ty(ptr as *mut ())
+            }
+
+            /// Creates a value from a mutable pointer, keeping its provenance.
+            #[inline]
+            #[unstable(feature = "c_intptr", issue = "none")]
+            pub fn from_mut_ptr<T>(ptr: *mut T) -> Self {
+                $Ty(ptr.cast())
+            }
+
+            /// Creates a value with the given address and no provenance.
+            ///
+            /// On CHERI platforms this is derived from the NULL capability, so converting it
+            /// back into a pointer gives a pointer that can't be dereferenced.
+            #[inline]
+            #[unstable(feature = "c_intptr", issue = "none")]
+            pub const fn from_addr(addr: $Int) -> Self {
+                $Ty(ptr::invalid_mut(addr as usize))
+            }
+
+            /// Converts back into a pointer, with the provenance this value carries.
+            #[inline]
+            #[unstable(feature = "c_intptr", issue = "none")]
+            pub const fn to_ptr<T>(self) -> *const T {
+                self.0 as *const T
+            }
+
+            /// Converts back into a mutable pointer, with the provenance this value carries.
+            #[inline]
+            #[unstable(feature = "c_intptr", issue = "none")]
+            pub const fn to_mut_ptr<T>(self) -> *mut T {
+                self.0 as *mut T
+            }
+
+            /// Returns the integer value, which is the address of the underlying pointer.
+            #[inline]
+            #[unstable(feature = "c_intptr", issue = "none")]
+            pub fn addr(self) -> $Int {
+                self.0.addr() as $Int
+            }
+
+            /// Creates a new value with the given integer value, keeping the provenance of `self`.
+            #[inline]
+            #[unstable(feature = "c_intptr", issue = "none")]
+            pub fn with_addr(self, addr: $Int) -> Self {
+                $Ty(self.0.with_addr(addr as usize))
+            }
+
+            /// Creates a new value by mapping the integer value, keeping the provenance of `self`.
+            #[inline]
+            #[unstable(feature = "c_intptr", issue = "none")]
+            pub fn map_addr(self, f: impl FnOnce($Int) -> $Int) -> Self {
+                self.with_addr(f(self.addr()))
+            }
+        }
+
+        #[unstable(feature = "c_intptr", issue = "none")]
+        impl<T> From<*const T> for $Ty {
+            #[inline]
+            fn from(ptr: *const T) -> Self {
+                $Ty::from_ptr(ptr)
+            }
+        }
+
+        #[unstable(feature = "c_intptr", issue = "none")]
+        impl<T> From<*mut T> for $Ty {
+            #[inline]
+            fn from(ptr: *mut T) -> Self {
+                $Ty::from_mut_ptr(ptr)
+            }
+        }
+
+        #[unstable(feature = "c_intptr", issue = "none")]
+        impl<T> From<$Ty> for *const T {
+            #[inline]
+            fn from(value: $Ty) -> Self {
+                value.to_ptr()
+            }
+        }
+
+        #[unstable(feature = "c_intptr", issue = "none")]
+        impl<T> From<$Ty> for *mut T {
+            #[inline]
+            fn from(value: $Ty) -> Self {
+                value.to_mut_ptr()
+            }
+        }
+
+        #[unstable(feature = "c_intptr", issue = "none")]
+        impl From<$Int> for $Ty {
+            #[inline]
+            fn from(addr: $Int) -> Self {
+                $Ty::from_addr(addr)
+            }
+        }
+
+        #[unstable(feature = "c_intptr", issue = "none")]
+        impl From<$Ty> for $Int {
+            #[inline]
+            fn from(value: $Ty) -> Self {
+                value.addr()
+            }
+        }
+
+        #[unstable(feature = "c_intptr", issue = "none")]
+        impl Default for $Ty {
+            #[inline]
+            fn default() -> Self {
+                $Ty::from_addr(0)
+            }
+        }
+
+        // Like C, comparisons and hashing only look at the integer value.
+        #[unstable(feature = "c_intptr", issue = "none")]
+        impl PartialEq for $Ty {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.addr() == other.addr()
+            }
+        }
+
+        #[unstable(feature = "c_intptr", issue = "none")]
+        impl Eq for $Ty {}
+
+        #[unstable(feature = "c_intptr", issue = "none")]
+        impl PartialOrd for $Ty {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        #[unstable(feature = "c_intptr", issue = "none")]
+        impl Ord for $Ty {
+            #[inline]
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.addr().cmp(&other.addr())
+            }
+        }
+
+        #[unstable(feature = "c_intptr", issue = "none")]
+        impl Hash for $Ty {
+            #[inline]
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.addr().hash(state)
+            }
+        }
+
+        c_intptr_fmt_impl! { $Ty, Debug Display LowerHex UpperHex Octal Binary }
+
+        c_intptr_binop_impl! { $Ty, $Int,
+            Add add AddAssign add_assign wrapping_add,
+            Sub sub SubAssign sub_assign wrapping_sub,
+            Mul mul MulAssign mul_assign wrapping_mul,
+            Div div DivAssign div_assign wrapping_div,
+            Rem rem RemAssign rem_assign wrapping_rem,
+        }
+
+        c_intptr_bitop_impl! { $Ty, $Int,
+            BitAnd bitand BitAndAssign bitand_assign &,
+            BitOr bitor BitOrAssign bitor_assign |,
+            BitXor bitxor BitXorAssign bitxor_assign ^,
+        }
+
+        #[unstable(feature = "c_intptr", issue = "none")]
+        impl Not for $Ty {
+            type Output = $Ty;
+
+            #[inline]
+            fn not(self) -> $Ty {
+                self.map_addr(|addr| !addr)
+            }
+        }
+
+        #[unstable(feature = "c_intptr", issue = "none")]
+        impl Shl<u32> for $Ty {
+            type Output = $Ty;
+
+            #[inline]
+            fn shl(self, rhs: u32) -> $Ty {
+                self.map_addr(|addr| addr.wrapping_shl(rhs))
+            }
+        }
+
+        #[unstable(feature = "c_intptr", issue = "none")]
+        impl ShlAssign<u32> for $Ty {
+            #[inline]
+            fn shl_assign(&mut self, rhs: u32) {
+                *self = *self << rhs;
+            }
+        }
+
+        #[unstable(feature = "c_intptr", issue = "none")]
+        impl Shr<u32> for $Ty {
+            type Output = $Ty;
+
+            #[inline]
+            fn shr(self, rhs: u32) -> $Ty {
+                self.map_addr(|addr| addr.wrapping_shr(rhs))
+            }
+        }
+
+        #[unstable(feature = "c_intptr", issue = "none")]
+        impl ShrAssign<u32> for $Ty {
+            #[inline]
+            fn shr_assign(&mut self, rhs: u32) {
+                *self = *self >> rhs;
+            }
+        }
+    };
+}
+
+macro_rules! c_intptr_fmt_impl {
+    ($Ty:ident, $($Trait:ident)*) => {$(
+        #[unstable(feature = "c_intptr", issue = "none")]
+        impl fmt::$Trait for $Ty {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::$Trait::fmt(&self.addr(), f)
+            }
+        }
+    )*};
+}
+
+macro_rules! c_intptr_binop_impl {
+    ($Ty:ident, $Int:ty, $($Trait:ident $method:ident $AssignTrait:ident $assign:ident $op:ident,)*) => {$(
+        #[unstable(feature = "c_intptr", issue = "none")]
+        impl $Trait<$Int> for $Ty {
+            type Output = $Ty;
+
+            #[inline]
+            fn $method(self, rhs: $Int) -> $Ty {
+                self.map_addr(|addr| addr.$op(rhs))
+            }
+        }
+
+        #[unstable(feature = "c_intptr", issue = "none")]
+        impl $Trait for $Ty {
+            type Output = $Ty;
+
+            #[inline]
+            fn $method(self, rhs: $Ty) -> $Ty {
+                self.map_addr(|addr| addr.$op(rhs.addr()))
+            }
+        }
+
+        #[unstable(feature = "c_intptr", issue = "none")]
+        impl $AssignTrait<$Int> for $Ty {
+            #[inline]
+            fn $assign(&mut self, rhs: $Int) {
+                *self = $Trait::$method(*self, rhs);
+            }
+        }
+
+        #[unstable(feature = "c_intptr", issue = "none")]
+        impl $AssignTrait for $Ty {
+            #[inline]
+            fn $assign(&mut self, rhs: $Ty) {
+                *self = $Trait::$method(*self, rhs);
+            }
+        }
+    )*};
+}
+
+macro_rules! c_intptr_bitop_impl {
+    ($Ty:ident, $Int:ty, $($Trait:ident $method:ident $AssignTrait:ident $assign:ident $op:tt,)*) => {$(
+        #[unstable(feature = "c_intptr", issue = "none")]
+        impl $Trait<$Int> for $Ty {
+            type Output = $Ty;
+
+            #[inline]
+            fn $method(self, rhs: $Int) -> $Ty {
+                self.map_addr(|addr| addr $op rhs)
+            }
+        }
+
+        #[unstable(feature = "c_intptr", issue = "none")]
+        impl $Trait for $Ty {
+            type Output = $Ty;
+
+            #[inline]
+            fn $method(self, rhs: $Ty) -> $Ty {
+                self.map_addr(|addr| addr $op rhs.addr())
+            }
+        }
+
+        #[unstable(feature = "c_intptr", issue = "none")]
+        impl $AssignTrait<$Int> for $Ty {
+            #[inline]
+            fn $assign(&mut self, rhs: $Int) {
+                *self = $Trait::$method(*self, rhs);
+            }
+        }
+
+        #[unstable(feature = "c_intptr", issue = "none")]
+        impl $AssignTrait for $Ty {
+            #[inline]
+            fn $assign(&mut self, rhs: $Ty) {
+                *self = $Trait::$method(*self, rhs);
+            }
+        }
+    )*};
+}
+
+c_intptr_impl! { c_uintptr, usize }
+c_intptr_impl! { c_intptr, isize }
@@ -17,6 +17,10 @@
 #[stable(feature = "core_c_str", since = "1.64.0")]
 pub use self::c_str::{CStr, FromBytesUntilNulError, FromBytesWithNulError};
 
+#[unstable(feature = "c_intptr", issue = "none")]
+pub use self::c_intptr::{c_intptr, c_uintptr};
+
+mod c_intptr;
 mod c_str;
 
 macro_rules! type_alias_no_nz {
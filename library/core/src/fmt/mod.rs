@@ -2473,8 +2473,60 @@ fn fmt(&self, f: &mut Formatter<'_>) -> Result {
 impl<T: ?Sized> Pointer for *const T {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         // Cast is needed here because `.addr()` requires `T: Sized`.
-        pointer_fmt_inner((*self as *const ()).addr(), f)
+        let ptr = *self as *const ();
+        // On targets where pointers are capabilities, the alternate form shows the metadata
+        // alongside the address.
+        #[cfg(not(bootstrap))]
+        if f.alternate() && mem::size_of::<*const ()>() > mem::size_of::<usize>() {
+            return capability_fmt_inner(ptr, f);
+        }
+        pointer_fmt_inner(ptr.addr(), f)
+    }
+}
+
+/// Formats a capability in the style of CheriBSD's `%#p`: the address followed by the
+/// permissions and bounds, e.g. `0x1000 [rwRW,0x1000-0x1040]`. Untagged capabilities are
+/// suffixed with `(invalid)`, and sealed ones with their object type, e.g. `(sealed,otype=0x4)`.
+///
+/// Like `pointer_fmt_inner`, this isn't monomorphized over the pointee type.
+#[cfg(not(bootstrap))]
+fn capability_fmt_inner(ptr: *const (), f: &mut Formatter<'_>) -> Result {
+    use crate::intrinsics;
+
+    // The architectural permission bits, and the letters CheriBSD uses for them.
+    #[cfg(target_arch = "morello+c64")]
+    const PERMISSIONS: &[(usize, char)] = &[
+        (1 << 17, 'r'),
+        (1 << 16, 'w'),
+        (1 << 15, 'x'),
+        (1 << 14, 'R'),
+        (1 << 13, 'W'),
+        (1 << 1, 'E'),
+    ];
+    #[cfg(not(target_arch = "morello+c64"))]
+    const PERMISSIONS: &[(usize, char)] =
+        &[(1 << 2, 'r'), (1 << 3, 'w'), (1 << 1, 'x'), (1 << 4, 'R'), (1 << 5, 'W')];
+
+    let base = intrinsics::cheri_cap_base_get(ptr);
+    // The top of a capability may lie one past the end of the address space.
+    let top = base as u128 + intrinsics::cheri_cap_length_get(ptr) as u128;
+    let perms = intrinsics::cheri_cap_perms_get(ptr);
+
+    write!(f, "{:#x} [", ptr.addr())?;
+    for &(bit, letter) in PERMISSIONS {
+        if perms & bit != 0 {
+            f.write_char(letter)?;
+        }
+    }
+    write!(f, ",{:#x}-{:#x}]", base, top)?;
+
+    if !intrinsics::cheri_cap_tag_get(ptr) {
+        f.write_str(" (invalid)")?;
+    }
+    if intrinsics::cheri_cap_sealed_get(ptr) {
+        write!(f, " (sealed,otype={:#x})", intrinsics::cheri_cap_type_get(ptr))?;
     }
+    Ok(())
 }
 
 /// Since the formatting will be identical for all pointer types, use a non-monomorphized
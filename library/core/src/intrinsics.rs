@@ -2117,6 +2117,98 @@ pub unsafe fn drop_in_place<T: ?Sized>(to_drop: *mut T) {
     /// The intrinsic will return the alignment stored in that vtable.
     pub fn vtable_align(ptr: *const ()) -> usize;
 
+    /// Returns the lower bound of the capability `ptr`.
+    ///
+    /// On targets where pointers are not capabilities, this returns `0`.
+    ///
+    /// Note that, unlike most intrinsics, this is safe to call;
+    /// it does not require an `unsafe` block.
+    #[cfg(not(bootstrap))]
+    #[rustc_safe_intrinsic]
+    pub fn cheri_cap_base_get<T>(ptr: *const T) -> usize;
+
+    /// Returns the length of the bounds of the capability `ptr`.
+    ///
+    /// On targets where pointers are not capabilities, this returns `usize::MAX`.
+    ///
+    /// Note that, unlike most intrinsics, this is safe to call;
+    /// it does not require an `unsafe` block.
+    #[cfg(not(bootstrap))]
+    #[rustc_safe_intrinsic]
+    pub fn cheri_cap_length_get<T>(ptr: *const T) -> usize;
+
+    /// Returns the architectural permission bits of the capability `ptr`.
+    ///
+    /// On targets where pointers are not capabilities, every bit is set.
+    ///
+    /// Note that, unlike most intrinsics, this is safe to call;
+    /// it does not require an `unsafe` block.
+    #[cfg(not(bootstrap))]
+    #[rustc_safe_intrinsic]
+    pub fn cheri_cap_perms_get<T>(ptr: *const T) -> usize;
+
+    /// Returns whether the capability `ptr` is sealed.
+    ///
+    /// On targets where pointers are not capabilities, this returns `false`.
+    ///
+    /// Note that, unlike most intrinsics, this is safe to call;
+    /// it does not require an `unsafe` block.
+    #[cfg(not(bootstrap))]
+    #[rustc_safe_intrinsic]
+    pub fn cheri_cap_sealed_get<T>(ptr: *const T) -> bool;
+
+    /// Returns the validity tag of the capability `ptr`.
+    ///
+    /// On targets where pointers are not capabilities, this returns whether `ptr` is non-null.
+    ///
+    /// Note that, unlike most intrinsics, this is safe to call;
+    /// it does not require an `unsafe` block.
+    #[cfg(not(bootstrap))]
+    #[rustc_safe_intrinsic]
+    pub fn cheri_cap_tag_get<T>(ptr: *const T) -> bool;
+
+    /// Returns the object type the capability `ptr` is sealed with.
+    ///
+    /// On targets where pointers are not capabilities, this returns `0`.
+    ///
+    /// Note that, unlike most intrinsics, this is safe to call;
+    /// it does not require an `unsafe` block.
+    #[cfg(not(bootstrap))]
+    #[rustc_safe_intrinsic]
+    pub fn cheri_cap_type_get<T>(ptr: *const T) -> isize;
+
+    /// Returns whether the capabilities `a` and `b` are identical, including their bounds,
+    /// permissions, object type and validity tag.
+    ///
+    /// On targets where pointers are not capabilities, this compares the addresses.
+    ///
+    /// Note that, unlike most intrinsics, this is safe to call;
+    /// it does not require an `unsafe` block.
+    #[cfg(not(bootstrap))]
+    #[rustc_safe_intrinsic]
+    pub fn cheri_cap_equal_exact<T>(a: *const T, b: *const T) -> bool;
+
+    /// Returns the default data capability, which authorizes loads and stores through integer
+    /// addresses.
+    ///
+    /// On targets where pointers are not capabilities, this returns a null pointer.
+    ///
+    /// Note that, unlike most intrinsics, this is safe to call;
+    /// it does not require an `unsafe` block.
+    #[cfg(not(bootstrap))]
+    #[rustc_safe_intrinsic]
+    pub fn cheri_ddc_get() -> *mut ();
+
+    /// Returns the program counter capability of the calling code.
+    ///
+    /// On targets where pointers are not capabilities, this returns a null pointer.
+    ///
+    /// Note that, unlike most intrinsics, this is safe to call;
+    /// it does not require an `unsafe` block.
+    #[cfg(not(bootstrap))]
+    #[rustc_safe_intrinsic]
+    pub fn cheri_pcc_get() -> *const ();
+
     /// Selects which function to call depending on the context.
     ///
     /// If this function is evaluated at compile-time, then a call to this
@@ -169,6 +169,7 @@
 //
 // Language features:
 #![feature(abi_unadjusted)]
+#![cfg_attr(not(bootstrap), feature(abi_cheri_ccall))]
 #![feature(adt_const_params)]
 #![feature(allow_internal_unsafe)]
 #![feature(allow_internal_unstable)]
@@ -176,6 +177,7 @@
 #![feature(auto_traits)]
 #![feature(c_unwind)]
 #![feature(cfg_sanitize)]
+#![feature(cfg_target_abi)]
 #![feature(cfg_target_has_atomic)]
 #![feature(cfg_target_has_atomic_equal_alignment)]
 #![feature(const_fn_floating_point_arithmetic)]
@@ -200,6 +202,7 @@
 #![feature(macro_metavar_expr)]
 #![feature(min_specialization)]
 #![feature(must_not_suspend)]
+#![feature(naked_functions)]
 #![feature(negative_impls)]
 #![feature(never_type)]
 #![feature(no_core)]
@@ -228,6 +231,7 @@
 #![feature(f16c_target_feature)]
 #![feature(hexagon_target_feature)]
 #![feature(mips_target_feature)]
+#![cfg_attr(not(bootstrap), feature(morello_target_feature))]
 #![feature(powerpc_target_feature)]
 #![feature(riscv_target_feature)]
 #![feature(rtm_target_feature)]
@@ -417,6 +421,27 @@ pub mod arch {
     pub macro global_asm("assembly template", $(operands,)* $(options($(option),*))?) {
         /* compiler built-in */
     }
+
+    #[cfg(not(bootstrap))]
+    #[cfg(all(
+        any(
+            target_arch = "morello+c64",
+            all(target_arch = "riscv32", target_pointer_type_width = "64"),
+            all(target_arch = "riscv64", target_pointer_type_width = "128"),
+        ),
+        not(target_abi = "cheriot"),
+    ))]
+    #[unstable(feature = "cheri_sealed_pair", issue = "none")]
+    pub mod cheri;
+
+    /// Platform-specific intrinsics for Morello.
+    #[cfg(not(bootstrap))]
+    #[cfg(all(target_arch = "morello+c64", target_feature = "morello"))]
+    #[unstable(feature = "stdarch_morello", issue = "none")]
+    pub mod aarch64 {
+        #[unstable(feature = "stdarch_morello", issue = "none")]
+        pub mod morello;
+    }
 }
 
 // Pull in the `core_simd` crate directly into libcore. The contents of
@@ -833,6 +833,20 @@ pub const fn guaranteed_ne(self, other: *const T) -> Option<bool>
         }
     }
 
+    /// Returns whether `self` and `other` are exactly the same pointer, including any capability
+    /// metadata.
+    ///
+    /// On CHERI targets `==` only compares addresses; this also compares bounds, permissions,
+    /// object type and validity tag. On other targets it is the same as `==`.
+    ///
+    /// See [`ptr::eq_exact`](eq_exact) for details.
+    #[unstable(feature = "ptr_eq_exact", issue = "none")]
+    #[inline]
+    #[must_use]
+    pub fn cap_eq_exact(self, other: *const T) -> bool {
+        eq_exact(self, other)
+    }
+
     /// Calculates the offset from a pointer (convenience for `.offset(count as isize)`).
     ///
     /// `count` is in units of T; e.g., a `count` of 3 represents a pointer
@@ -1488,7 +1502,8 @@ pub const fn as_ptr(self) -> *const T {
     }
 }
 
-// Equality for pointers
+// Equality for pointers. On CHERI targets this, like the comparison and `Hash` impls, only looks
+// at the address (and the metadata of wide pointers); see `ptr::eq_exact` for the full capability.
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<T: ?Sized> PartialEq for *const T {
     #[inline]
@@ -310,7 +310,8 @@
 //!   more complex than this rapidly enters "extremely platform-specific" territory as
 //!   certain things may or may not be allowed based on specific supported operations.
 //!   For instance, ARM explicitly supports high-bit tagging, and so CHERI on ARM inherits
-//!   that and should support it.
+//!   that and should support it. [`TaggedPtr`] packages up low-bit tagging in a form that is
+//!   correct everywhere, CHERI included.
 //!
 //! ## Pointer-usize-pointer roundtrips and 'exposed' provenance
 //!
@@ -405,6 +406,10 @@
 #[unstable(feature = "ptr_internals", issue = "none")]
 pub use unique::Unique;
 
+mod tagged_ptr;
+#[unstable(feature = "tagged_ptr", issue = "none")]
+pub use tagged_ptr::TaggedPtr;
+
 mod const_ptr;
 mod mut_ptr;
 
@@ -610,6 +615,19 @@ pub const fn invalid_mut<T>(addr: usize) -> *mut T {
 /// since it is generally not possible to actually *compute* which provenance the returned
 /// pointer has to pick up.
 ///
+/// CHERI targets are such platforms: a pointer is a capability whose validity tag cannot be
+/// forged from an integer. What this function returns there is chosen when the calling crate is
+/// compiled, with `-Z cheri-int-to-ptr`:
+///
+/// * `null-derived` (the default): the pointer is derived from the null capability. It has the
+///   requested address and can be compared, hashed and formatted, but any access through it
+///   faults.
+/// * `ddc-derived`: the pointer is derived from the default data capability (DDC) and is as
+///   usable as the DDC itself. This is mostly useful for hybrid and bare-metal code, as the DDC
+///   is null in purecap processes. An address of zero still yields a null pointer.
+/// * `error`: every use of this function, or of an `as` cast from an integer to a pointer, is
+///   rejected at compile time.
+///
 /// This API and its claimed semantics are part of the Strict Provenance experiment, see the
 /// [module documentation][crate::ptr] for details.
 #[must_use]
@@ -645,7 +663,7 @@ pub fn from_exposed_addr<T>(addr: usize) -> *const T
 /// On most platforms this will produce a value with the same bytes as the address. Platforms
 /// which need to store additional information in a pointer may not support this operation,
 /// since it is generally not possible to actually *compute* which provenance the returned
-/// pointer has to pick up.
+/// pointer has to pick up. See [`from_exposed_addr`] for the behavior on CHERI targets.
 ///
 /// This API and its claimed semantics are part of the Strict Provenance experiment, see the
 /// [module documentation][crate::ptr] for details.
@@ -1766,6 +1784,11 @@ unsafe fn mod_inv(x: usize, m: usize) -> usize {
 /// multiple codegen units), and pointers to values of *different* underlying type can compare equal
 /// (since identical vtables can be deduplicated within a codegen unit).
 ///
+/// On CHERI targets, where pointers are capabilities, only the addresses are compared: two
+/// pointers with the same address but different bounds or permissions are equal. The same holds
+/// for the `PartialEq`, `PartialOrd`, `Ord` and `Hash` implementations of raw pointers. Use
+/// [`eq_exact`] to also compare the rest of the capability.
+///
 /// # Examples
 ///
 /// ```
@@ -1798,12 +1821,51 @@ pub fn eq<T: ?Sized>(a: *const T, b: *const T) -> bool {
     a == b
 }
 
+/// Compares raw pointers for exact equality, including any capability metadata.
+///
+/// On CHERI targets this returns `true` only if both pointers are identical capabilities: same
+/// address, bounds, permissions, object type and validity tag. [`eq`] and the `==` operator only
+/// compare addresses, so a pointer and a copy of it with narrowed bounds compare equal with
+/// those but not with `eq_exact`.
+///
+/// On other targets this is the same as [`eq`].
+///
+/// As with [`eq`], wide pointers also have their metadata compared.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(ptr_eq_exact)]
+/// use std::ptr;
+///
+/// let a = [1, 2, 3];
+/// assert!(ptr::eq_exact(&a[..2], &a[..2]));
+/// assert!(!ptr::eq_exact(&a[..2], &a[..3]));
+/// ```
+#[unstable(feature = "ptr_eq_exact", issue = "none")]
+#[inline]
+#[must_use]
+pub fn eq_exact<T: ?Sized>(a: *const T, b: *const T) -> bool {
+    #[cfg(not(bootstrap))]
+    {
+        crate::intrinsics::cheri_cap_equal_exact(a.cast::<()>(), b.cast::<()>())
+            && metadata(a) == metadata(b)
+    }
+    #[cfg(bootstrap)]
+    {
+        a == b
+    }
+}
+
 /// Hash a raw pointer.
 ///
 /// This can be used to hash a `&T` reference (which coerces to `*const T` implicitly)
 /// by its address rather than the value it points to
 /// (which is what the `Hash for &T` implementation does).
 ///
+/// Only the address (and the metadata of wide pointers) is hashed, which keeps this consistent
+/// with [`eq`] on CHERI targets.
+///
 /// # Examples
 ///
 /// ```
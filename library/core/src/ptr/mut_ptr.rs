@@ -755,6 +755,20 @@ pub const fn guaranteed_ne(self, other: *mut T) -> Option<bool>
         (self as *const T).guaranteed_ne(other as _)
     }
 
+    /// Returns whether `self` and `other` are exactly the same pointer, including any capability
+    /// metadata.
+    ///
+    /// On CHERI targets `==` only compares addresses; this also compares bounds, permissions,
+    /// object type and validity tag. On other targets it is the same as `==`.
+    ///
+    /// See [`ptr::eq_exact`](eq_exact) for details.
+    #[unstable(feature = "ptr_eq_exact", issue = "none")]
+    #[inline]
+    #[must_use]
+    pub fn cap_eq_exact(self, other: *mut T) -> bool {
+        eq_exact(self, other)
+    }
+
     /// Calculates the distance between two pointers. The returned value is in
     /// units of T: the distance in bytes divided by `mem::size_of::<T>()`.
     ///
@@ -1931,7 +1945,8 @@ pub const fn as_mut_ptr(self) -> *mut T {
     }
 }
 
-// Equality for pointers
+// Equality for pointers. On CHERI targets this, like the comparison and `Hash` impls, only looks
+// at the address (and the metadata of wide pointers); see `ptr::eq_exact` for the full capability.
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<T: ?Sized> PartialEq for *mut T {
     #[inline(always)]
@@ -0,0 +1,148 @@
+use crate::fmt;
+use crate::hash;
+use crate::mem;
+
+/// A `*mut T` that stores a small tag in the low bits of its address.
+///
+/// The alignment of `T` guarantees that the low bits of the address of a valid `*mut T` are
+/// zero, so `BITS` of them can carry a tag of the user's choosing instead. The tag is stored
+/// and removed with [`map_addr`], so the pointer keeps its provenance throughout: on CHERI the
+/// capability keeps its bounds and its validity tag, as the tagged address stays within the
+/// pointee (or just past it, for zero-sized types).
+///
+/// `BITS` must not exceed the number of low bits that the alignment of `T` leaves zero, which is
+/// checked at compile time. In particular, this type never stores a tag in the high bits of the
+/// address: capability targets forbid that, as changing them moves the address far out of the
+/// capability's bounds and clears its validity tag.
+///
+/// [`map_addr`]: pointer::map_addr
+///
+/// # Examples
+///
+/// ```
+/// #![feature(tagged_ptr)]
+/// use std::ptr::TaggedPtr;
+///
+/// let mut value = 7u32;
+/// let mut tagged = TaggedPtr::<u32, 2>::new(&mut value, 0b10);
+/// assert_eq!(tagged.tag(), 0b10);
+/// tagged.set_tag(0b01);
+/// assert_eq!(unsafe { *tagged.ptr() }, 7);
+/// ```
+///
+/// Asking for more bits than the alignment provides doesn't compile:
+///
+/// ```compile_fail
+/// #![feature(tagged_ptr)]
+/// use std::ptr::TaggedPtr;
+///
+/// let mut value = 7u16;
+/// let tagged = TaggedPtr::<u16, 2>::new(&mut value, 0);
+/// ```
+#[unstable(feature = "tagged_ptr", issue = "none")]
+pub struct TaggedPtr<T, const BITS: u32> {
+    pointer: *mut T,
+}
+
+impl<T, const BITS: u32> TaggedPtr<T, BITS> {
+    const BITS_FIT: () = assert!(
+        BITS < usize::BITS && mem::align_of::<T>() >= 1 << BITS,
+        "`TaggedPtr` can only use the low address bits that the alignment of `T` leaves zero",
+    );
+
+    /// The mask of the address bits that hold the tag.
+    #[unstable(feature = "tagged_ptr", issue = "none")]
+    pub const TAG_MASK: usize = (1 << BITS) - 1;
+
+    /// Creates a tagged pointer from `ptr` and `tag`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `tag` doesn't fit in `BITS` bits. In debug builds, also panics if `ptr` isn't
+    /// aligned for `T`.
+    #[unstable(feature = "tagged_ptr", issue = "none")]
+    #[inline]
+    pub fn new(ptr: *mut T, tag: usize) -> Self {
+        let () = Self::BITS_FIT;
+        debug_assert!(ptr.addr() & Self::TAG_MASK == 0, "`TaggedPtr::new` needs aligned pointers");
+        assert!(tag <= Self::TAG_MASK, "the tag doesn't fit in the bits of a `TaggedPtr`");
+        TaggedPtr { pointer: ptr.map_addr(|addr| addr | tag) }
+    }
+
+    /// Returns the pointer, without the tag.
+    #[unstable(feature = "tagged_ptr", issue = "none")]
+    #[inline]
+    #[must_use]
+    pub fn ptr(self) -> *mut T {
+        self.pointer.map_addr(|addr| addr & !Self::TAG_MASK)
+    }
+
+    /// Returns the tag.
+    #[unstable(feature = "tagged_ptr", issue = "none")]
+    #[inline]
+    #[must_use]
+    pub fn tag(self) -> usize {
+        self.pointer.addr() & Self::TAG_MASK
+    }
+
+    /// Replaces the tag.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `tag` doesn't fit in `BITS` bits.
+    #[unstable(feature = "tagged_ptr", issue = "none")]
+    #[inline]
+    pub fn set_tag(&mut self, tag: usize) {
+        *self = self.with_tag(tag);
+    }
+
+    /// Returns this pointer with the tag replaced.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `tag` doesn't fit in `BITS` bits.
+    #[unstable(feature = "tagged_ptr", issue = "none")]
+    #[inline]
+    #[must_use]
+    pub fn with_tag(self, tag: usize) -> Self {
+        assert!(tag <= Self::TAG_MASK, "the tag doesn't fit in the bits of a `TaggedPtr`");
+        TaggedPtr { pointer: self.pointer.map_addr(|addr| (addr & !Self::TAG_MASK) | tag) }
+    }
+}
+
+#[unstable(feature = "tagged_ptr", issue = "none")]
+impl<T, const BITS: u32> Clone for TaggedPtr<T, BITS> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+#[unstable(feature = "tagged_ptr", issue = "none")]
+impl<T, const BITS: u32> Copy for TaggedPtr<T, BITS> {}
+
+#[unstable(feature = "tagged_ptr", issue = "none")]
+impl<T, const BITS: u32> PartialEq for TaggedPtr<T, BITS> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.pointer == other.pointer
+    }
+}
+
+#[unstable(feature = "tagged_ptr", issue = "none")]
+impl<T, const BITS: u32> Eq for TaggedPtr<T, BITS> {}
+
+#[unstable(feature = "tagged_ptr", issue = "none")]
+impl<T, const BITS: u32> hash::Hash for TaggedPtr<T, BITS> {
+    #[inline]
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.pointer.hash(state)
+    }
+}
+
+#[unstable(feature = "tagged_ptr", issue = "none")]
+impl<T, const BITS: u32> fmt::Debug for TaggedPtr<T, BITS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TaggedPtr").field("ptr", &self.ptr()).field("tag", &self.tag()).finish()
+    }
+}
@@ -0,0 +1,67 @@
+use core::ffi::{c_intptr, c_uintptr};
+use core::mem;
+
+#[test]
+fn c_uintptr_layout() {
+    assert_eq!(mem::size_of::<c_uintptr>(), mem::size_of::<*const ()>());
+    assert_eq!(mem::align_of::<c_uintptr>(), mem::align_of::<*const ()>());
+    assert_eq!(mem::size_of::<c_intptr>(), mem::size_of::<*const ()>());
+    assert_eq!(mem::align_of::<c_intptr>(), mem::align_of::<*const ()>());
+}
+
+#[test]
+fn c_uintptr_roundtrip_keeps_provenance() {
+    let mut array = [1u32, 2, 3, 4];
+    let ptr = array.as_mut_ptr();
+
+    let mut value = c_uintptr::from(ptr);
+    value += 2 * mem::size_of::<u32>();
+    let ptr: *mut u32 = value.into();
+    unsafe {
+        assert_eq!(*ptr, 3);
+        *ptr = 5;
+    }
+
+    // Low-bit tagging through bitwise operations.
+    let tagged = c_uintptr::from(ptr) | 1;
+    assert_eq!(tagged & 1, c_uintptr::from(1usize));
+    let untagged: *const u32 = (tagged & !1usize).into();
+    assert_eq!(unsafe { *untagged }, 5);
+}
+
+#[test]
+fn c_uintptr_arithmetic() {
+    let value = c_uintptr::from_addr(0x1000);
+    assert_eq!((value + 0x10).addr(), 0x1010);
+    assert_eq!((value - 1).addr(), 0xfff);
+    assert_eq!((value * 2usize).addr(), 0x2000);
+    assert_eq!((value / 0x100usize).addr(), 0x10);
+    assert_eq!((value % 0x300usize).addr(), 0x100);
+    assert_eq!((value >> 4).addr(), 0x100);
+    assert_eq!((value << 4).addr(), 0x10000);
+    assert_eq!((value ^ value).addr(), 0);
+    assert_eq!(c_uintptr::from_addr(0) - 1, c_uintptr::from_addr(usize::MAX));
+    assert_eq!(c_uintptr::default(), c_uintptr::from_addr(0));
+}
+
+#[test]
+fn c_intptr_is_signed() {
+    let value = c_intptr::from_addr(-8);
+    assert_eq!(value.addr(), -8);
+    assert_eq!((value / 2isize).addr(), -4);
+    assert_eq!((value >> 1).addr(), -4);
+    assert!(value < c_intptr::from_addr(0));
+    assert!(c_uintptr::from_addr(usize::MAX) > c_uintptr::from_addr(0));
+}
+
+#[test]
+fn c_uintptr_fmt() {
+    let value = c_uintptr::from_addr(255);
+    assert_eq!(format!("{value}"), "255");
+    assert_eq!(format!("{value:?}"), "255");
+    assert_eq!(format!("{value:#x}"), "0xff");
+
+    let hex = format!("{:X}", c_intptr::from_addr(-1));
+    assert_eq!(hex.len(), mem::size_of::<isize>() * 2);
+    assert!(hex.chars().all(|c| c == 'F'));
+}
@@ -3,6 +3,7 @@
 #![feature(array_methods)]
 #![feature(array_windows)]
 #![feature(bigint_helper_methods)]
+#![feature(c_intptr)]
 #![feature(cell_update)]
 #![feature(const_assume)]
 #![feature(const_align_of_val_raw)]
@@ -83,6 +84,8 @@
 #![feature(pointer_byte_offsets)]
 #![feature(portable_simd)]
 #![feature(ptr_metadata)]
+#![feature(ptr_eq_exact)]
+#![feature(tagged_ptr)]
 #![feature(once_cell)]
 #![feature(option_result_contains)]
 #![feature(unsized_tuple_coercion)]
@@ -121,6 +124,7 @@
 mod cmp;
 mod const_ptr;
 mod convert;
+mod ffi;
 mod fmt;
 mod future;
 mod hash;
@@ -252,6 +252,48 @@ fn test_ptr_subtraction() {
     }
 }
 
+#[test]
+fn test_ptr_eq_exact() {
+    let xs = [0u8; 4];
+    let a = xs.as_ptr();
+    assert!(ptr::eq_exact(a, a));
+    assert!(a.cap_eq_exact(a.wrapping_add(1).wrapping_sub(1)));
+    assert!(!ptr::eq_exact(a, a.wrapping_add(1)));
+    assert!(!ptr::eq_exact(&xs[..2], &xs[..3]));
+}
+
+#[test]
+fn test_tagged_ptr() {
+    // `u64` is only 4-byte aligned on some 32-bit targets, which leaves room for two tag bits.
+    #[repr(align(8))]
+    #[derive(Debug, PartialEq)]
+    struct Aligned(u64);
+
+    let mut x = Aligned(5);
+    let p: *mut Aligned = &mut x;
+    let mut tagged = TaggedPtr::<Aligned, 3>::new(p, 0b101);
+    assert_eq!(tagged.tag(), 0b101);
+    // The pointer comes back with its provenance, and on CHERI its bounds and tag, intact.
+    assert!(ptr::eq_exact(tagged.ptr(), p));
+    assert_eq!(unsafe { &*tagged.ptr() }, &Aligned(5));
+
+    tagged.set_tag(0b010);
+    assert_eq!(tagged.tag(), 0b010);
+    assert!(ptr::eq_exact(tagged.ptr(), p));
+    unsafe { (*tagged.ptr()).0 = 6 };
+    assert_eq!(x, Aligned(6));
+
+    assert_eq!(tagged.with_tag(0), TaggedPtr::new(p, 0));
+    assert_ne!(tagged, TaggedPtr::new(p, 0));
+}
+
+#[test]
+#[should_panic]
+fn test_tagged_ptr_tag_too_wide() {
+    let mut x = 5u32;
+    let _ = TaggedPtr::<u32, 2>::new(&mut x, 0b100);
+}
+
 #[test]
 fn test_set_memory() {
     let mut xs = [0u8; 20];
@@ -61,8 +61,13 @@
     /// xor
     pub(crate) fn simd_xor<T>(x: T, y: T) -> T;
 
-    /// getelementptr (without inbounds)
-    pub(crate) fn simd_arith_offset<T, U>(ptrs: T, offsets: U) -> T;
+    /// ptrtoint, or llvm.cheri.cap.address.get per lane for capabilities
+    /// like `pointer::addr`, doesn't expose the provenance of the pointers
+    pub(crate) fn simd_addr<T, U>(ptrs: T) -> U;
+
+    /// getelementptr by the change in address, or llvm.cheri.cap.address.set per lane for capabilities
+    /// like `pointer::with_addr`, every lane keeps the provenance (and tag) of its pointer
+    pub(crate) fn simd_with_addr<T, U>(ptrs: T, addrs: U) -> T;
 
     /// fptoui/fptosi/uitofp/sitofp
     /// casting floats to integers is truncating, so it is safe to convert values like e.g. 1.5
@@ -20,9 +20,24 @@ pub fn splat(ptr: *const T) -> Self {
 
     #[inline]
     #[must_use]
-    pub fn wrapping_add(self, addend: Simd<usize, LANES>) -> Self {
+    pub fn addr(self) -> Simd<usize, LANES> {
+        // Safety: this intrinsic doesn't have a precondition
+        unsafe { intrinsics::simd_addr(self) }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn with_addr(self, addr: Simd<usize, LANES>) -> Self {
         // Safety: this intrinsic doesn't have a precondition
-        unsafe { intrinsics::simd_arith_offset(self, addend) }
+        unsafe { intrinsics::simd_with_addr(self, addr) }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wrapping_add(self, addend: Simd<usize, LANES>) -> Self {
+        // Go through the address so that every lane keeps its provenance, which capabilities
+        // need to stay valid.
+        self.with_addr(self.addr() + addend * Simd::splat(core::mem::size_of::<T>()))
     }
 }
 
@@ -44,8 +59,23 @@ pub fn splat(ptr: *mut T) -> Self {
 
     #[inline]
     #[must_use]
-    pub fn wrapping_add(self, addend: Simd<usize, LANES>) -> Self {
+    pub fn addr(self) -> Simd<usize, LANES> {
+        // Safety: this intrinsic doesn't have a precondition
+        unsafe { intrinsics::simd_addr(self) }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn with_addr(self, addr: Simd<usize, LANES>) -> Self {
         // Safety: this intrinsic doesn't have a precondition
-        unsafe { intrinsics::simd_arith_offset(self, addend) }
+        unsafe { intrinsics::simd_with_addr(self, addr) }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wrapping_add(self, addend: Simd<usize, LANES>) -> Self {
+        // Go through the address so that every lane keeps its provenance, which capabilities
+        // need to stay valid.
+        self.with_addr(self.addr() + addend * Simd::splat(core::mem::size_of::<T>()))
     }
 }
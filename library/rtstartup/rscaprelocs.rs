@@ -0,0 +1,170 @@
+// rscaprelocs.o is the startup object of freestanding CHERI pure-capability images, linked in
+// with `-Z cheri-self-init-caprelocs`.
+//
+// Every pointer in initialized data of a purecap image is a capability, which can't be stored in
+// the image itself: the linker emits an entry in the `__cap_relocs` section for each of them
+// instead, and something has to derive the capabilities before any code loads them. On hosted
+// targets that is the runtime linker or the C startup code. Freestanding images start running
+// with nothing but the root capabilities that the boot loader leaves in DDC and PCC, so this
+// object becomes the image's entry point, `__rust_cheri_start`. It derives three roots:
+//
+// * data, from DDC without the execute and seal permissions,
+// * read-only data, which additionally can't store anything, and
+// * code, from PCC without the store and seal permissions,
+//
+// then walks `__cap_relocs` and stores a capability to each target, derived from the root of its
+// kind and bounded to the size of the object. Function pointers are sealed as sentries. Finally
+// it branches to the program's own `_start`, which sets up the stack and everything else as
+// before. The relocation loop therefore only uses registers, and it leaves the argument registers
+// untouched so that whatever the boot loader passed in them reaches `_start`.
+//
+// Each `__cap_relocs` entry is five address-sized words, as emitted by lld:
+//
+// * the address of the capability to initialize,
+// * the address of the object it points to, or 0 for a null capability,
+// * the offset of the pointer into the object,
+// * the size of the object, or 0 if it is unknown and the capability keeps the root's bounds, and
+// * flags: the top bit marks a function pointer and the one below it read-only data.
+
+#![feature(no_core)]
+#![feature(lang_items)]
+#![feature(rustc_attrs)]
+#![crate_type = "rlib"]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+
+#[rustc_builtin_macro]
+macro_rules! global_asm {
+    () => {};
+}
+
+// Permissions are cleared with `clrperm`, which takes the mask of permissions to remove:
+// execute (bit 15) and seal (bit 11) for data, additionally store (bit 16), store capability
+// (bit 13) and store local capability (bit 12) for read-only data, and store, store capability
+// and seal for code.
+#[cfg(target_arch = "morello+c64")]
+global_asm!(
+    ".text",
+    ".globl __rust_cheri_start",
+    ".type __rust_cheri_start, %function",
+    "__rust_cheri_start:",
+    "    mrs     c23, DDC",
+    "    mov     x9, #0x8800",
+    "    clrperm c20, c23, x9",
+    "    movz    x9, #0xb800",
+    "    movk    x9, #0x1, lsl #16",
+    "    clrperm c21, c23, x9",
+    "    adr     c22, #0",
+    "    movz    x9, #0x2800",
+    "    movk    x9, #0x1, lsl #16",
+    "    clrperm c22, c22, x9",
+    // The table is read through the data root, as PCC may not grant loads.
+    "    adrp    c9, __stop___cap_relocs",
+    "    add     c9, c9, :lo12:__stop___cap_relocs",
+    "    gcvalue x10, c9",
+    "    adrp    c9, __start___cap_relocs",
+    "    add     c9, c9, :lo12:__start___cap_relocs",
+    "    gcvalue x11, c9",
+    "    scvalue c9, c20, x11",
+    "1:",
+    "    gcvalue x11, c9",
+    "    cmp     x11, x10",
+    "    b.hs    5f",
+    "    ldp     x12, x13, [c9, #0]",
+    "    ldp     x14, x15, [c9, #16]",
+    "    ldr     x11, [c9, #32]",
+    "    add     c9, c9, #40",
+    "    scvalue c16, c20, x12",
+    "    cbz     x13, 4f",
+    "    tbnz    x11, #63, 2f",
+    "    tbnz    x11, #62, 3f",
+    "    scvalue c17, c20, x13",
+    "    b       6f",
+    "2:",
+    "    scvalue c17, c22, x13",
+    "    b       6f",
+    "3:",
+    "    scvalue c17, c21, x13",
+    "6:",
+    "    cbz     x15, 7f",
+    "    scbnds  c17, c17, x15",
+    "7:",
+    "    add     c17, c17, x14",
+    "    tbz     x11, #63, 8f",
+    "    seal    c17, c17, rb",
+    "8:",
+    "    str     c17, [c16]",
+    "    b       1b",
+    "4:",
+    "    str     czr, [c16]",
+    "    b       1b",
+    "5:",
+    "    b       _start",
+    ".size __rust_cheri_start, . - __rust_cheri_start",
+);
+
+// Permissions are restricted with `candperm`, which takes the mask of permissions to keep: all but
+// execute (bit 1) and seal (bit 7) for data, additionally without store (bit 3), store capability
+// (bit 5) and store local capability (bit 6) for read-only data, and all but store, store
+// capability and seal for code.
+#[cfg(target_arch = "riscv32")]
+global_asm!(
+    ".text",
+    ".globl __rust_cheri_start",
+    ".type __rust_cheri_start, @function",
+    "__rust_cheri_start:",
+    "    cspecialr cs1, ddc",
+    "    li      t0, ~0x82",
+    "    candperm cs2, cs1, t0",
+    "    li      t0, ~0xea",
+    "    candperm cs3, cs1, t0",
+    "    auipcc  cs4, 0",
+    "    li      t0, ~0xa8",
+    "    candperm cs4, cs4, t0",
+    // The table is read through the data root, as PCC may not grant loads.
+    "    cllc    ct1, __stop___cap_relocs",
+    "    cgetaddr t1, ct1",
+    "    cllc    ct0, __start___cap_relocs",
+    "    cgetaddr t0, ct0",
+    "    csetaddr ct0, cs2, t0",
+    "1:",
+    "    cgetaddr t2, ct0",
+    "    bgeu    t2, t1, 5f",
+    "    clw     t2, 0(ct0)",
+    "    clw     t3, 4(ct0)",
+    "    clw     t4, 8(ct0)",
+    "    clw     t5, 12(ct0)",
+    "    clw     t6, 16(ct0)",
+    "    cincoffset ct0, ct0, 20",
+    "    csetaddr cs5, cs2, t2",
+    "    beqz    t3, 4f",
+    "    bltz    t6, 2f",
+    "    slli    t2, t6, 1",
+    "    bltz    t2, 3f",
+    "    csetaddr cs6, cs2, t3",
+    "    j       6f",
+    "2:",
+    "    csetaddr cs6, cs4, t3",
+    "    j       6f",
+    "3:",
+    "    csetaddr cs6, cs3, t3",
+    "6:",
+    "    beqz    t5, 7f",
+    "    csetbounds cs6, cs6, t5",
+    "7:",
+    "    cincoffset cs6, cs6, t4",
+    "    bgez    t6, 8f",
+    "    csealentry cs6, cs6",
+    "8:",
+    "    csc     cs6, 0(cs5)",
+    "    j       1b",
+    "4:",
+    "    csc     cnull, 0(cs5)",
+    "    j       1b",
+    "5:",
+    "    cllc    ct0, _start",
+    "    cjr     ct0",
+    ".size __rust_cheri_start, . - __rust_cheri_start",
+);
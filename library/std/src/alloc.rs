@@ -329,6 +329,72 @@ pub fn take_alloc_error_hook() -> fn(Layout) {
     if hook.is_null() { default_alloc_error_hook } else { unsafe { mem::transmute(hook) } }
 }
 
+/// Puts the [`System`] allocator into revocation mode, or takes it out of it with `None`.
+///
+/// In revocation mode, memory freed through `System` is quarantined instead of being reused right
+/// away. Once the quarantine holds more than `quarantine_size` bytes, every capability to the
+/// quarantined memory is revoked, and only then is it handed back to the operating system's
+/// allocator. A use after free can then never reach a new allocation, even from `unsafe` code.
+/// A larger quarantine makes the revocation passes rarer, at the cost of memory.
+///
+/// On CheriBSD the kernel revokes capabilities wherever they are stored, which requires the
+/// process to run with revocation enabled; this returns an error of kind
+/// [`Unsupported`](crate::io::ErrorKind::Unsupported) otherwise. Other targets can't find every
+/// copy of a pointer, and return the same error.
+///
+/// Taking `System` out of revocation mode revokes and releases everything in the quarantine.
+///
+/// This is only available on targets where pointers are capabilities.
+#[cfg(all(
+    unix,
+    any(
+        target_arch = "morello+c64",
+        all(target_arch = "riscv32", target_pointer_type_width = "64"),
+        all(target_arch = "riscv64", target_pointer_type_width = "128")
+    )
+))]
+#[unstable(feature = "cheri_revocation", issue = "none")]
+pub fn set_cheri_quarantine_size(quarantine_size: Option<usize>) -> crate::io::Result<()> {
+    crate::sys::revoke::set_quarantine_size(quarantine_size)
+}
+
+/// Revokes every capability to memory in the quarantine of the [`System`] allocator and releases
+/// it, however little there is.
+///
+/// This does nothing unless `System` is in revocation mode, see [`set_cheri_quarantine_size`].
+#[cfg(all(
+    unix,
+    any(
+        target_arch = "morello+c64",
+        all(target_arch = "riscv32", target_pointer_type_width = "64"),
+        all(target_arch = "riscv64", target_pointer_type_width = "128")
+    )
+))]
+#[unstable(feature = "cheri_revocation", issue = "none")]
+pub fn cheri_revoke_now() {
+    crate::sys::revoke::revoke_now()
+}
+
+/// Registers the memory that revocation sweeps on CHERI targets without kernel support for it.
+///
+/// A pointer in `heap` to memory that is revoked is replaced with a null pointer. On CheriBSD the
+/// kernel revokes capabilities everywhere, and `heap` is unused. Elsewhere, registering a heap is
+/// what makes revocation mode available. This only exists to test the revocation mode of
+/// [`System`].
+#[cfg(all(
+    unix,
+    any(
+        target_arch = "morello+c64",
+        all(target_arch = "riscv32", target_pointer_type_width = "64"),
+        all(target_arch = "riscv64", target_pointer_type_width = "128")
+    )
+))]
+#[unstable(feature = "cheri_revocation", issue = "none")]
+#[doc(hidden)]
+pub fn set_cheri_revocation_test_heap(heap: &'static [AtomicPtr<u8>]) {
+    crate::sys::revoke::set_test_heap(heap)
+}
+
 fn default_alloc_error_hook(layout: Layout) {
     extern "Rust" {
         // This symbol is emitted by rustc next to __rust_alloc_error_handler.
@@ -95,7 +95,7 @@
 use crate::fmt;
 use crate::sync::atomic::{AtomicUsize, Ordering::Relaxed};
 use crate::sync::Once;
-use crate::sys_common::backtrace::{lock, output_filename};
+use crate::sys_common::backtrace::{code_address, lock, output_filename, resolve_frames};
 use crate::vec::Vec;
 
 /// A captured OS thread stack backtrace.
@@ -295,7 +295,7 @@ pub fn capture() -> Backtrace {
         if !Backtrace::enabled() {
             return Backtrace { inner: Inner::Disabled };
         }
-        Backtrace::create(Backtrace::capture as usize)
+        Backtrace::create(Backtrace::capture as *const c_void)
     }
 
     /// Forcibly captures a full backtrace, regardless of environment variable
@@ -311,7 +311,7 @@ pub fn capture() -> Backtrace {
     #[stable(feature = "backtrace", since = "1.65.0")]
     #[inline(never)] // want to make sure there's a frame here to remove
     pub fn force_capture() -> Backtrace {
-        Backtrace::create(Backtrace::force_capture as usize)
+        Backtrace::create(Backtrace::force_capture as *const c_void)
     }
 
     /// Forcibly captures a disabled backtrace, regardless of environment
@@ -324,7 +324,8 @@ pub const fn disabled() -> Backtrace {
 
     // Capture a backtrace which start just before the function addressed by
     // `ip`
-    fn create(ip: usize) -> Backtrace {
+    fn create(ip: *const c_void) -> Backtrace {
+        let ip = code_address(ip);
         let _lock = lock();
         let mut frames = Vec::new();
         let mut actual_start = None;
@@ -334,7 +335,7 @@ fn create(ip: usize) -> Backtrace {
                     frame: RawFrame::Actual(frame.clone()),
                     symbols: Vec::new(),
                 });
-                if frame.symbol_address().addr() == ip && actual_start.is_none() {
+                if code_address(frame.symbol_address()) == ip && actual_start.is_none() {
                     actual_start = Some(frames.len());
                 }
                 true
@@ -469,26 +470,27 @@ fn resolve(&mut self) {
         // requirement of the `backtrace` crate, and then actually resolve
         // everything.
         let _lock = lock();
-        for frame in self.frames.iter_mut() {
-            let symbols = &mut frame.symbols;
-            let frame = match &frame.frame {
-                RawFrame::Actual(frame) => frame,
+        let frames: Vec<_> = self
+            .frames
+            .iter()
+            .map(|frame| match &frame.frame {
+                RawFrame::Actual(frame) => frame.clone(),
                 #[cfg(test)]
                 RawFrame::Fake => unimplemented!(),
-            };
-            unsafe {
-                backtrace_rs::resolve_frame_unsynchronized(frame, |symbol| {
-                    symbols.push(BacktraceSymbol {
-                        name: symbol.name().map(|m| m.as_bytes().to_vec()),
-                        filename: symbol.filename_raw().map(|b| match b {
-                            BytesOrWideString::Bytes(b) => BytesOrWide::Bytes(b.to_owned()),
-                            BytesOrWideString::Wide(b) => BytesOrWide::Wide(b.to_owned()),
-                        }),
-                        lineno: symbol.lineno(),
-                        colno: symbol.colno(),
-                    });
+            })
+            .collect();
+        unsafe {
+            resolve_frames(frames.iter(), |i, symbol| {
+                self.frames[i].symbols.push(BacktraceSymbol {
+                    name: symbol.name.map(|m| m.to_vec()),
+                    filename: symbol.filename.map(|b| match b {
+                        BytesOrWideString::Bytes(b) => BytesOrWide::Bytes(b.to_owned()),
+                        BytesOrWideString::Wide(b) => BytesOrWide::Wide(b.to_owned()),
+                    }),
+                    lineno: symbol.lineno,
+                    colno: symbol.colno,
                 });
-            }
+            });
         }
     }
 }
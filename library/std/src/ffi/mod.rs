@@ -163,6 +163,9 @@
 #[stable(feature = "core_c_void", since = "1.30.0")]
 pub use core::ffi::c_void;
 
+#[unstable(feature = "c_intptr", issue = "none")]
+pub use core::ffi::{c_intptr, c_uintptr};
+
 #[unstable(
     feature = "c_variadic",
     reason = "the `c_variadic` feature has not been properly tested on \
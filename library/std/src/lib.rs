@@ -332,6 +332,7 @@
 // Only for re-exporting:
 #![feature(assert_matches)]
 #![feature(async_iterator)]
+#![feature(c_intptr)]
 #![feature(c_variadic)]
 #![feature(cfg_accessible)]
 #![feature(cfg_eval)]
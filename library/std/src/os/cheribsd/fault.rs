@@ -0,0 +1,187 @@
+//! Capability faults.
+//!
+//! CheriBSD delivers a capability violation to the faulting thread as [`SIGPROT`], with an
+//! `si_code` that says which check failed. Unless the program installs a handler of its own,
+//! the standard library describes the fault on standard error before the signal kills the
+//! process, or passes it to the hook set with [`set_hook`].
+
+use crate::ffi::c_int;
+use crate::fmt;
+use crate::mem;
+use crate::ptr;
+use crate::sync::atomic::{AtomicPtr, Ordering};
+
+#[cfg(test)]
+mod tests;
+
+/// The signal CheriBSD raises on a capability fault.
+pub const SIGPROT: c_int = 34;
+
+/// `si_code` of a capability bounds fault.
+pub const PROT_CHERI_BOUNDS: c_int = 1;
+/// `si_code` of a capability tag fault.
+pub const PROT_CHERI_TAG: c_int = 2;
+/// `si_code` of a capability sealed fault.
+pub const PROT_CHERI_SEALED: c_int = 3;
+/// `si_code` of a capability object type mismatch.
+pub const PROT_CHERI_TYPE: c_int = 4;
+/// `si_code` of a capability permission fault.
+pub const PROT_CHERI_PERM: c_int = 5;
+/// `si_code` of a store of a local capability through a capability without the permission.
+pub const PROT_CHERI_STORELOCAL: c_int = 6;
+/// `si_code` of a failed domain crossing.
+pub const PROT_CHERI_CINVOKE: c_int = 7;
+/// `si_code` of bounds that could not be represented exactly.
+pub const PROT_CHERI_IMPRECISE: c_int = 8;
+/// `si_code` of a jump to a PCC with an unaligned base.
+pub const PROT_CHERI_UNALIGNED_BASE: c_int = 9;
+/// `si_code` of an access to a system register without the permission.
+pub const PROT_CHERI_SYSREG: c_int = 10;
+
+/// The check that a capability fault failed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CapabilityFault {
+    /// The access was outside of the bounds of the capability.
+    Bounds,
+    /// The capability was not valid.
+    Tag,
+    /// The capability was sealed.
+    Sealed,
+    /// The object types of a sealed code and data capability did not match.
+    Type,
+    /// The capability lacked a permission the access needed.
+    Permission,
+    /// A local capability was stored through a capability without the permission to do so.
+    StoreLocal,
+    /// Entering another protection domain failed.
+    Invoke,
+    /// The requested bounds could not be represented exactly.
+    Imprecise,
+    /// Control was transferred to a PCC with an unaligned base.
+    UnalignedBase,
+    /// A system register was accessed without the permission to do so.
+    SystemRegister,
+}
+
+impl CapabilityFault {
+    /// Decodes the `si_code` of a [`SIGPROT`].
+    ///
+    /// Returns `None` if `code` is not one of the `PROT_CHERI_*` values.
+    pub fn from_si_code(code: c_int) -> Option<CapabilityFault> {
+        Some(match code {
+            PROT_CHERI_BOUNDS => CapabilityFault::Bounds,
+            PROT_CHERI_TAG => CapabilityFault::Tag,
+            PROT_CHERI_SEALED => CapabilityFault::Sealed,
+            PROT_CHERI_TYPE => CapabilityFault::Type,
+            PROT_CHERI_PERM => CapabilityFault::Permission,
+            PROT_CHERI_STORELOCAL => CapabilityFault::StoreLocal,
+            PROT_CHERI_CINVOKE => CapabilityFault::Invoke,
+            PROT_CHERI_IMPRECISE => CapabilityFault::Imprecise,
+            PROT_CHERI_UNALIGNED_BASE => CapabilityFault::UnalignedBase,
+            PROT_CHERI_SYSREG => CapabilityFault::SystemRegister,
+            _ => return None,
+        })
+    }
+
+    /// Returns the `si_code` that CheriBSD reports this fault with.
+    pub fn si_code(self) -> c_int {
+        match self {
+            CapabilityFault::Bounds => PROT_CHERI_BOUNDS,
+            CapabilityFault::Tag => PROT_CHERI_TAG,
+            CapabilityFault::Sealed => PROT_CHERI_SEALED,
+            CapabilityFault::Type => PROT_CHERI_TYPE,
+            CapabilityFault::Permission => PROT_CHERI_PERM,
+            CapabilityFault::StoreLocal => PROT_CHERI_STORELOCAL,
+            CapabilityFault::Invoke => PROT_CHERI_CINVOKE,
+            CapabilityFault::Imprecise => PROT_CHERI_IMPRECISE,
+            CapabilityFault::UnalignedBase => PROT_CHERI_UNALIGNED_BASE,
+            CapabilityFault::SystemRegister => PROT_CHERI_SYSREG,
+        }
+    }
+}
+
+impl fmt::Display for CapabilityFault {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            CapabilityFault::Bounds => "capability bounds violation",
+            CapabilityFault::Tag => "capability tag violation",
+            CapabilityFault::Sealed => "sealed capability violation",
+            CapabilityFault::Type => "capability type mismatch",
+            CapabilityFault::Permission => "capability permission violation",
+            CapabilityFault::StoreLocal => "local capability store violation",
+            CapabilityFault::Invoke => "capability invocation fault",
+            CapabilityFault::Imprecise => "capability representability violation",
+            CapabilityFault::UnalignedBase => "PCC base alignment violation",
+            CapabilityFault::SystemRegister => "system register access violation",
+        })
+    }
+}
+
+/// A capability fault, as passed to the hook set with [`set_hook`].
+///
+/// Its `Display` implementation gives the message the standard library prints, for example
+/// `capability bounds violation at 0x1234 accessing 0x4010 [rwRW,0x4000-0x4010]`.
+#[derive(Clone, Copy, Debug)]
+pub struct FaultInfo {
+    kind: CapabilityFault,
+    pc: *const (),
+    capability: *const (),
+}
+
+impl FaultInfo {
+    pub(crate) fn new(kind: CapabilityFault, pc: *const (), capability: *const ()) -> FaultInfo {
+        FaultInfo { kind, pc, capability }
+    }
+
+    /// Returns which check failed.
+    pub fn kind(&self) -> CapabilityFault {
+        self.kind
+    }
+
+    /// Returns the program counter capability of the faulting instruction.
+    pub fn pc(&self) -> *const () {
+        self.pc
+    }
+
+    /// Returns the capability the faulting instruction accessed memory or jumped through.
+    pub fn capability(&self) -> *const () {
+        self.capability
+    }
+}
+
+impl fmt::Display for FaultInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at {:p} accessing {:#p}", self.kind, self.pc, self.capability)
+    }
+}
+
+static HOOK: AtomicPtr<()> = AtomicPtr::new(ptr::null_mut());
+
+/// Registers a hook that is called instead of printing the default message when a thread
+/// causes a capability fault.
+///
+/// The hook runs inside the signal handler, on the faulting thread, so it must only do what is
+/// async-signal-safe. Once it returns, the signal is delivered again with the default action and
+/// the process terminates.
+///
+/// The hook is only called if the standard library's handler for [`SIGPROT`] is installed, which
+/// it is unless the program had already installed one of its own when it started.
+pub fn set_hook(hook: fn(&FaultInfo)) {
+    HOOK.store(hook as *mut (), Ordering::Release);
+}
+
+/// Unregisters the current hook, returning it.
+///
+/// Afterwards the default message is printed again.
+pub fn take_hook() -> Option<fn(&FaultInfo)> {
+    let hook = HOOK.swap(ptr::null_mut(), Ordering::AcqRel);
+    // SAFETY: non-null values are only ever stored by `set_hook`, from a `fn(&FaultInfo)`.
+    (!hook.is_null()).then(|| unsafe { mem::transmute::<*mut (), fn(&FaultInfo)>(hook) })
+}
+
+pub(crate) fn hook() -> Option<fn(&FaultInfo)> {
+    let hook = HOOK.load(Ordering::Acquire);
+    // SAFETY: see `take_hook`.
+    (!hook.is_null()).then(|| unsafe { mem::transmute::<*mut (), fn(&FaultInfo)>(hook) })
+}
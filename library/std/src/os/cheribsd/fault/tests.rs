@@ -0,0 +1,36 @@
+use super::*;
+
+const ALL: [CapabilityFault; 10] = [
+    CapabilityFault::Bounds,
+    CapabilityFault::Tag,
+    CapabilityFault::Sealed,
+    CapabilityFault::Type,
+    CapabilityFault::Permission,
+    CapabilityFault::StoreLocal,
+    CapabilityFault::Invoke,
+    CapabilityFault::Imprecise,
+    CapabilityFault::UnalignedBase,
+    CapabilityFault::SystemRegister,
+];
+
+#[test]
+fn si_code_round_trip() {
+    for fault in ALL {
+        assert_eq!(CapabilityFault::from_si_code(fault.si_code()), Some(fault));
+    }
+}
+
+#[test]
+fn si_codes_are_distinct() {
+    let mut codes: Vec<_> = ALL.iter().map(|fault| fault.si_code()).collect();
+    codes.sort();
+    codes.dedup();
+    assert_eq!(codes.len(), ALL.len());
+}
+
+#[test]
+fn unknown_si_code() {
+    assert_eq!(CapabilityFault::from_si_code(0), None);
+    assert_eq!(CapabilityFault::from_si_code(PROT_CHERI_SYSREG + 1), None);
+    assert_eq!(CapabilityFault::from_si_code(-1), None);
+}
@@ -0,0 +1,18 @@
+//! CheriBSD extensions to the protection flags of `mmap` and `mprotect`, from `<sys/mman.h>`.
+
+use crate::ffi::c_int;
+
+/// The pages may hold capabilities: tags survive loads and stores through the mapping.
+pub const PROT_CAP: c_int = 0x08;
+
+/// The pages may not hold capabilities: loads clear the tag and storing a tagged capability
+/// faults.
+pub const PROT_NO_CAP: c_int = 0x10;
+
+/// Limits the permissions that later `mprotect` calls may grant the mapping to `prot`.
+///
+/// The result is or-ed into the protection passed to `mmap`, in the same way as the `PROT_MAX`
+/// macro in C.
+pub const fn prot_max(prot: c_int) -> c_int {
+    prot << 16
+}
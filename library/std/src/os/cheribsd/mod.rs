@@ -0,0 +1,26 @@
+//! CheriBSD-specific definitions.
+//!
+//! CheriBSD is FreeBSD extended for CHERI, so everything in [`crate::os::freebsd`] applies to it
+//! as well. This module adds what is specific to running with capabilities.
+
+#![unstable(feature = "cheribsd_ext", issue = "none")]
+
+pub mod fault;
+pub mod mman;
+
+/// Returns the default data capability (DDC).
+///
+/// Pure-capability code never dereferences through the DDC, but it is still what the kernel and
+/// legacy interfaces use to authorize integer addresses.
+#[inline]
+pub fn cheri_getdefault() -> *mut () {
+    crate::intrinsics::cheri_ddc_get()
+}
+
+/// Returns the program counter capability (PCC) of the caller.
+///
+/// Its bounds and permissions are those that the caller's code runs with.
+#[inline]
+pub fn cheri_getpcc() -> *const () {
+    crate::intrinsics::cheri_pcc_get()
+}
@@ -109,6 +109,15 @@ pub mod windows {}
 // Others.
 #[cfg(target_os = "android")]
 pub mod android;
+#[cfg(not(bootstrap))]
+#[cfg(all(
+    target_os = "freebsd",
+    any(
+        target_arch = "morello+c64",
+        all(target_arch = "riscv64", target_pointer_type_width = "128")
+    )
+))]
+pub mod cheribsd;
 #[cfg(target_os = "dragonfly")]
 pub mod dragonfly;
 #[cfg(target_os = "emscripten")]
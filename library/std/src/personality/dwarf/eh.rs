@@ -158,14 +158,15 @@ unsafe fn read_encoded_pointer(
         return Err(());
     }
 
-    // DW_EH_PE_aligned implies it's an absolute pointer value
+    // DW_EH_PE_aligned implies it's an absolute pointer value. Those are stored as pointers, so
+    // on CHERI pure-capability targets they are capabilities, twice the size of their address.
     if encoding == DW_EH_PE_aligned {
-        reader.ptr = round_up(reader.ptr, mem::size_of::<uintptr_t>())?;
-        return Ok(reader.read::<uintptr_t>());
+        reader.ptr = round_up(reader.ptr, mem::size_of::<*const u8>())?;
+        return Ok(reader.read::<*const u8>().addr());
     }
 
     let mut result = match encoding & 0x0F {
-        DW_EH_PE_absptr => reader.read::<uintptr_t>(),
+        DW_EH_PE_absptr => reader.read::<*const u8>().addr(),
         DW_EH_PE_uleb128 => reader.read_uleb128() as uintptr_t,
         DW_EH_PE_udata2 => reader.read::<u16>() as uintptr_t,
         DW_EH_PE_udata4 => reader.read::<u32>() as uintptr_t,
@@ -193,7 +194,9 @@ unsafe fn read_encoded_pointer(
     } as *const u8).addr()) as uintptr_t;
 
     if encoding & DW_EH_PE_indirect != 0 {
-        result = *(result as *const uintptr_t);
+        // An integer can't be dereferenced on CHERI, so borrow the LSDA's capability to read
+        // through. Anything outside of its bounds traps instead of being read unchecked.
+        result = (*reader.ptr.with_addr(result).cast::<*const u8>()).addr();
     }
 
     Ok(result)
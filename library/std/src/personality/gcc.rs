@@ -55,9 +55,11 @@
 #[cfg(any(target_arch = "arm", target_arch = "aarch64"))]
 const UNWIND_DATA_REG: (i32, i32) = (0, 1); // R0, R1 / X0, X1
 
+// Unlike on CHERI-RISC-V, Morello's capability registers have DWARF numbers of their own. Setting
+// X0/X1 would only write the address and leave the exception pointer untagged.
 #[cfg(not(bootstrap))]
 #[cfg(target_arch = "morello+c64")]
-const UNWIND_DATA_REG: (i32, i32) = (0, 1); // R0, R1 / X0, X1
+const UNWIND_DATA_REG: (i32, i32) = (198, 199); // C0, C1
 
 #[cfg(target_arch = "m68k")]
 const UNWIND_DATA_REG: (i32, i32) = (0, 1); // D0, D1
@@ -77,6 +79,8 @@
 #[cfg(target_arch = "hexagon")]
 const UNWIND_DATA_REG: (i32, i32) = (0, 1); // R0, R1
 
+// CHERI-RISC-V capability registers share the DWARF numbers of the integer registers they extend,
+// so this also holds for purecap targets, where the exception pointer is in c10.
 #[cfg(any(target_arch = "riscv64", target_arch = "riscv32"))]
 const UNWIND_DATA_REG: (i32, i32) = (10, 11); // x10, x11
 
@@ -211,10 +215,10 @@ fn __gnu_unwind_frame(
                         uw::_Unwind_SetGR(
                             context,
                             UNWIND_DATA_REG.0,
-                            exception_object as uintptr_t,
+                            exception_object as uw::_Unwind_Ptr,
                         );
-                        uw::_Unwind_SetGR(context, UNWIND_DATA_REG.1, 0 as uintptr_t);
-                        uw::_Unwind_SetIP(context, lpad);
+                        uw::_Unwind_SetGR(context, UNWIND_DATA_REG.1, 0 as uw::_Unwind_Ptr);
+                        uw::_Unwind_SetIP(context, landing_pad(context, lpad));
                         uw::_URC_INSTALL_CONTEXT
                     }
                     EHAction::Terminate => uw::_URC_FATAL_PHASE2_ERROR,
@@ -265,19 +269,38 @@ fn __gnu_unwind_frame(
     }
 }
 
+// The LSDA only gives us the address of a landing pad. On CHERI pure-capability targets libunwind
+// installs it as the new PCC, so it has to be a capability, which is derived from the one for the
+// start of the function. That is unsealed and its bounds cover the landing pad as well.
+#[cfg(any(
+    target_arch = "morello+c64",
+    all(target_arch = "riscv64", target_pointer_type_width = "128")
+))]
+unsafe fn landing_pad(context: *mut uw::_Unwind_Context, lpad: uintptr_t) -> uw::_Unwind_Ptr {
+    uw::_Unwind_GetRegionStart(context).with_addr(lpad)
+}
+
+#[cfg(not(any(
+    target_arch = "morello+c64",
+    all(target_arch = "riscv64", target_pointer_type_width = "128")
+)))]
+unsafe fn landing_pad(_context: *mut uw::_Unwind_Context, lpad: uintptr_t) -> uw::_Unwind_Ptr {
+    lpad
+}
+
 unsafe fn find_eh_action(context: *mut uw::_Unwind_Context) -> Result<EHAction, ()> {
     let lsda = uw::_Unwind_GetLanguageSpecificData(context) as *const u8;
     let mut ip_before_instr: c_int = 0;
-    let ip = uw::_Unwind_GetIPInfo(context, &mut ip_before_instr);
+    let ip = uw::_Unwind_GetIPInfo(context, &mut ip_before_instr) as uintptr_t;
     let eh_context = EHContext {
         // The return address points 1 byte past the call instruction,
         // which could be in the next IP range in LSDA range table.
         //
         // `ip = -1` has special meaning, so use wrapping sub to allow for that
         ip: if ip_before_instr != 0 { ip } else { ip.wrapping_sub(1) },
-        func_start: uw::_Unwind_GetRegionStart(context),
-        get_text_start: &|| uw::_Unwind_GetTextRelBase(context),
-        get_data_start: &|| uw::_Unwind_GetDataRelBase(context),
+        func_start: uw::_Unwind_GetRegionStart(context) as uintptr_t,
+        get_text_start: &|| uw::_Unwind_GetTextRelBase(context) as uintptr_t,
+        get_data_start: &|| uw::_Unwind_GetDataRelBase(context) as uintptr_t,
     };
     eh::find_eh_action(lsda, &eh_context)
 }
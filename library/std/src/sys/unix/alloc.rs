@@ -2,6 +2,28 @@
 use crate::ptr;
 use crate::sys::common::alloc::{realloc_fallback, MIN_ALIGN};
 
+cfg_if::cfg_if! {
+    if #[cfg(any(
+        target_arch = "morello+c64",
+        all(target_arch = "riscv32", target_pointer_type_width = "64"),
+        all(target_arch = "riscv64", target_pointer_type_width = "128")
+    ))] {
+        use crate::sys::revoke;
+    } else {
+        // Only capability targets have a revocation mode, so `System` never quarantines here.
+        mod revoke {
+            #[inline(always)]
+            pub fn enabled() -> bool {
+                false
+            }
+
+            pub unsafe fn quarantine(_ptr: *mut u8, _size: usize) {
+                unreachable!()
+            }
+        }
+    }
+}
+
 #[stable(feature = "alloc_system_type", since = "1.28.0")]
 unsafe impl GlobalAlloc for System {
     #[inline]
@@ -38,13 +60,18 @@ unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
     }
 
     #[inline]
-    unsafe fn dealloc(&self, ptr: *mut u8, _layout: Layout) {
-        libc::free(ptr as *mut libc::c_void)
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        if revoke::enabled() {
+            revoke::quarantine(ptr, layout.size())
+        } else {
+            libc::free(ptr as *mut libc::c_void)
+        }
     }
 
     #[inline]
     unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
-        if layout.align() <= MIN_ALIGN && layout.align() <= new_size {
+        // `realloc` would free the old block behind the quarantine's back.
+        if layout.align() <= MIN_ALIGN && layout.align() <= new_size && !revoke::enabled() {
             libc::realloc(ptr as *mut libc::c_void, new_size) as *mut u8
         } else {
             realloc_fallback(self, ptr, layout, new_size)
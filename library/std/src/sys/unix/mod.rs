@@ -35,8 +35,22 @@
 pub mod pipe;
 pub mod process;
 pub mod rand;
+#[cfg(any(
+    target_arch = "morello+c64",
+    all(target_arch = "riscv32", target_pointer_type_width = "64"),
+    all(target_arch = "riscv64", target_pointer_type_width = "128")
+))]
+pub mod revoke;
 pub mod stack_overflow;
 pub mod stdio;
+#[cfg(all(
+    feature = "backtrace",
+    any(
+        target_arch = "morello+c64",
+        all(target_arch = "riscv64", target_pointer_type_width = "128")
+    )
+))]
+pub mod symbolize;
 pub mod thread;
 pub mod thread_local_dtor;
 pub mod thread_local_key;
@@ -0,0 +1,216 @@
+//! Revocation mode of the `System` allocator.
+//!
+//! In revocation mode `dealloc` doesn't hand memory back to libc right away. Freed blocks are
+//! kept in a quarantine instead, and once it holds more than the configured number of bytes,
+//! every capability to a quarantined block is revoked before the blocks can be reused. A
+//! dangling pointer then never reaches whatever reuses its memory, even from `unsafe` code.
+//!
+//! On CheriBSD, libc's allocator does the revocation itself when the process runs with it
+//! enabled: blocks released from the quarantine are painted in the kernel's revocation bitmap,
+//! and `malloc_revoke` has the kernel clear the tag of every capability to them, wherever it is
+//! stored. On other capability targets nothing could find every copy of a pointer, so revocation
+//! mode is unsupported there unless a test heap is registered with `set_test_heap`, which is then
+//! swept in software. That is only meant for testing the quarantine, and protects nothing else.
+//!
+//! Targets where pointers aren't capabilities have no revocation mode at all.
+
+use crate::io;
+use crate::mem;
+use crate::ptr;
+use crate::sync::atomic::{AtomicBool, AtomicPtr, AtomicUsize, Ordering};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static QUARANTINE_SIZE: AtomicUsize = AtomicUsize::new(0);
+
+// Everything below is only touched with the lock held. It is a spin lock rather than a `Mutex`
+// because it is taken in `dealloc`, and the `Mutex` of some platforms allocates.
+static LOCKED: AtomicBool = AtomicBool::new(false);
+static mut QUARANTINE: Quarantine = Quarantine::new();
+static mut TEST_HEAP: &[AtomicPtr<u8>] = &[];
+
+struct Lock;
+
+impl Lock {
+    fn acquire() -> Lock {
+        while LOCKED
+            .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            crate::hint::spin_loop();
+        }
+        Lock
+    }
+}
+
+impl Drop for Lock {
+    fn drop(&mut self) {
+        LOCKED.store(false, Ordering::Release);
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Block {
+    ptr: *mut u8,
+    size: usize,
+}
+
+// The blocks are stored in memory from libc directly, which `System` wouldn't be able to hand out
+// while we are in the middle of freeing something.
+struct Quarantine {
+    blocks: *mut Block,
+    len: usize,
+    capacity: usize,
+    bytes: usize,
+}
+
+impl Quarantine {
+    const fn new() -> Quarantine {
+        Quarantine { blocks: ptr::null_mut(), len: 0, capacity: 0, bytes: 0 }
+    }
+
+    // Returns `false` if there was no memory to grow the quarantine.
+    unsafe fn push(&mut self, block: Block) -> bool {
+        if self.len == self.capacity {
+            let capacity = (self.capacity * 2).max(64);
+            let blocks = libc::realloc(self.blocks.cast(), capacity * mem::size_of::<Block>());
+            if blocks.is_null() {
+                return false;
+            }
+            self.blocks = blocks.cast();
+            self.capacity = capacity;
+        }
+        self.blocks.add(self.len).write(block);
+        self.len += 1;
+        self.bytes += block.size;
+        true
+    }
+
+    unsafe fn flush(&mut self) {
+        if self.len > 0 {
+            imp::release(crate::slice::from_raw_parts(self.blocks, self.len));
+            self.len = 0;
+            self.bytes = 0;
+        }
+    }
+}
+
+/// Whether `System` currently quarantines freed memory.
+#[inline]
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+pub fn set_quarantine_size(size: Option<usize>) -> io::Result<()> {
+    match size {
+        Some(size) => {
+            if !imp::supported() {
+                return Err(io::const_io_error!(
+                    io::ErrorKind::Unsupported,
+                    "capability revocation is not available to this process",
+                ));
+            }
+            QUARANTINE_SIZE.store(size, Ordering::Relaxed);
+            ENABLED.store(true, Ordering::Relaxed);
+        }
+        None => {
+            // Anything that races with this sees that revocation mode is off once it holds the
+            // lock, and releases its block itself.
+            ENABLED.store(false, Ordering::Relaxed);
+            revoke_now();
+        }
+    }
+    Ok(())
+}
+
+pub fn revoke_now() {
+    let _lock = Lock::acquire();
+    unsafe { QUARANTINE.flush() }
+}
+
+pub fn set_test_heap(heap: &'static [AtomicPtr<u8>]) {
+    let _lock = Lock::acquire();
+    unsafe { TEST_HEAP = heap }
+}
+
+/// Puts a block that `System` would otherwise free into the quarantine.
+pub unsafe fn quarantine(ptr: *mut u8, size: usize) {
+    let _lock = Lock::acquire();
+    let block = Block { ptr, size };
+    if !QUARANTINE.push(block) {
+        // Without room to keep it, the block has to be revoked right away.
+        QUARANTINE.flush();
+        imp::release(&[block]);
+    } else if !enabled() || QUARANTINE.bytes > QUARANTINE_SIZE.load(Ordering::Relaxed) {
+        QUARANTINE.flush();
+    }
+}
+
+#[cfg(all(
+    not(bootstrap),
+    target_os = "freebsd",
+    any(
+        target_arch = "morello+c64",
+        all(target_arch = "riscv64", target_pointer_type_width = "128")
+    )
+))]
+mod imp {
+    use super::Block;
+
+    // From CheriBSD's <malloc_np.h>.
+    extern "C" {
+        fn malloc_is_revoking() -> bool;
+        fn malloc_revoke();
+    }
+
+    pub fn supported() -> bool {
+        unsafe { malloc_is_revoking() }
+    }
+
+    // Revokes all capabilities to `blocks` and frees them.
+    pub unsafe fn release(blocks: &[Block]) {
+        // libc quarantines the blocks in turn, and only reuses them after a revocation pass, which
+        // we run right away.
+        for block in blocks {
+            libc::free(block.ptr.cast());
+        }
+        malloc_revoke();
+    }
+}
+
+#[cfg(not(all(
+    not(bootstrap),
+    target_os = "freebsd",
+    any(
+        target_arch = "morello+c64",
+        all(target_arch = "riscv64", target_pointer_type_width = "128")
+    )
+)))]
+mod imp {
+    use super::{Block, TEST_HEAP};
+    use crate::ptr;
+    use crate::sync::atomic::Ordering;
+
+    // Revocation only reaches the test heap, so without one nothing would ever be revoked.
+    pub fn supported() -> bool {
+        let _lock = super::Lock::acquire();
+        unsafe { !TEST_HEAP.is_empty() }
+    }
+
+    // Revokes the pointers to `blocks` in the test heap and frees them.
+    pub unsafe fn release(blocks: &[Block]) {
+        // Without tags to clear, a revoked pointer is replaced with null.
+        for slot in TEST_HEAP {
+            let addr = slot.load(Ordering::Relaxed).addr();
+            let is_quarantined = |block: &Block| {
+                let start = block.ptr.addr();
+                start <= addr && addr < start + block.size.max(1)
+            };
+            if blocks.iter().any(is_quarantined) {
+                slot.store(ptr::null_mut(), Ordering::Relaxed);
+            }
+        }
+        for block in blocks {
+            libc::free(block.ptr.cast());
+        }
+    }
+}
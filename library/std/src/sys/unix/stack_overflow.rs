@@ -47,11 +47,6 @@ mod imp {
     use libc::MAP_FAILED;
     use libc::{mmap, munmap};
     use libc::{sigaction, SA_ONSTACK, SA_SIGINFO, SIGBUS, SIG_DFL};
-    #[cfg(bootstrap)]
-    use libc::sighandler_t;
-    #[cfg(not(bootstrap))]
-    #[cfg(not(target_arch = "morello+c64"))]
-    use libc::sighandler_t;
     use libc::{sigaltstack, SIGSTKSZ, SS_DISABLE};
     use libc::{MAP_ANON, MAP_PRIVATE, PROT_NONE, PROT_READ, PROT_WRITE, SIGSEGV};
 
@@ -61,6 +56,107 @@ mod imp {
     use crate::sys::unix::os::page_size;
     use crate::sys_common::thread_info;
 
+    cfg_if! {
+        if #[cfg(all(
+            not(bootstrap),
+            any(
+                target_arch = "morello+c64",
+                all(target_arch = "riscv64", target_pointer_type_width = "128")
+            )
+        ))] {
+            // In purecap, `sigaction` keeps the handler in a union of the handler pointer and
+            // the `SIG_DFL`/`SIG_IGN` constants, rather than as an integer.
+            unsafe fn is_default(action: &sigaction) -> bool {
+                action.sa_u.sa_handler.sah_id == SIG_DFL
+            }
+
+            unsafe fn set_default(action: &mut sigaction) {
+                action.sa_u.sa_handler.sah_id = SIG_DFL;
+            }
+
+            unsafe fn set_signal_handler(action: &mut sigaction) {
+                action.sa_u.sa_sigaction = signal_handler;
+            }
+
+            cfg_if! {
+                if #[cfg(target_os = "freebsd")] {
+                    // CheriBSD reports capability faults with a signal of their own, which we
+                    // handle as well so that they don't go by as a bare signal death.
+                    const SIGNALS: &[libc::c_int] =
+                        &[SIGSEGV, SIGBUS, crate::os::cheribsd::fault::SIGPROT];
+
+                    // Describes a capability fault, or hands it to the hook set with
+                    // `std::os::cheribsd::fault::set_hook`. Returns `false` if `signum` is not a
+                    // capability fault.
+                    unsafe fn report_capability_fault(
+                        signum: libc::c_int,
+                        info: *mut libc::siginfo_t,
+                        context: *mut libc::c_void,
+                    ) -> bool {
+                        use crate::os::cheribsd::fault::{self, CapabilityFault, FaultInfo};
+
+                        if signum != fault::SIGPROT {
+                            return false;
+                        }
+                        let Some(kind) = CapabilityFault::from_si_code((*info).si_code) else {
+                            return true;
+                        };
+                        let context = &*context.cast::<libc::ucontext_t>();
+                        #[cfg(target_arch = "morello+c64")]
+                        let pc = context.uc_mcontext.mc_capregs.cap_elr as *const ();
+                        #[cfg(target_arch = "riscv64")]
+                        let pc = context.uc_mcontext.mc_capregs.cp_sepcc as *const ();
+                        let fault = FaultInfo::new(kind, pc, (*info).si_addr() as *const ());
+
+                        match fault::hook() {
+                            Some(hook) => hook(&fault),
+                            None => rtprintpanic!(
+                                "\nthread '{}' caused a {}\n",
+                                thread::current().name().unwrap_or("<unknown>"),
+                                fault
+                            ),
+                        }
+                        true
+                    }
+                } else {
+                    const SIGNALS: &[libc::c_int] = &[SIGSEGV, SIGBUS];
+
+                    unsafe fn report_capability_fault(
+                        _signum: libc::c_int,
+                        _info: *mut libc::siginfo_t,
+                        _context: *mut libc::c_void,
+                    ) -> bool {
+                        false
+                    }
+                }
+            }
+        } else {
+            use libc::sighandler_t;
+
+            unsafe fn is_default(action: &sigaction) -> bool {
+                action.sa_sigaction == SIG_DFL
+            }
+
+            unsafe fn set_default(action: &mut sigaction) {
+                action.sa_sigaction = SIG_DFL;
+            }
+
+            unsafe fn set_signal_handler(action: &mut sigaction) {
+                action.sa_sigaction = signal_handler as sighandler_t;
+            }
+
+            const SIGNALS: &[libc::c_int] = &[SIGSEGV, SIGBUS];
+
+            unsafe fn report_capability_fault(
+                _signum: libc::c_int,
+                _info: *mut libc::siginfo_t,
+                _context: *mut libc::c_void,
+            ) -> bool {
+                false
+            }
+        }
+    }
+
     // Signal handler for the SIGSEGV and SIGBUS handlers. We've got guard pages
     // (unmapped pages) at the end of every thread's stack, so if a thread ends
     // up running into the guard page it'll trigger this handler. We want to
@@ -85,8 +181,15 @@ mod imp {
     unsafe extern "C" fn signal_handler(
         signum: libc::c_int,
         info: *mut libc::siginfo_t,
-        _data: *mut libc::c_void,
+        data: *mut libc::c_void,
     ) {
+        // A capability fault is never a stack overflow, even if the faulting capability
+        // points into the guard page.
+        if report_capability_fault(signum, info, data) {
+            unregister(signum);
+            return;
+        }
+
         let guard = thread_info::stack_guard().unwrap_or(0..0);
         let addr = (*info).si_addr() as usize;
 
@@ -99,62 +202,32 @@ mod imp {
             );
             rtabort!("stack overflow");
         } else {
-            // Unregister ourselves by reverting back to the default behavior.
-            let mut action: sigaction = mem::zeroed();
-            cfg_if! {
-                if #[cfg(not(bootstrap))] {
-                    cfg_if! {
-                        if #[cfg(target_arch = "morello+c64")] {
-                            action.sa_u.sa_handler.sah_id = SIG_DFL;
-                        } else {
-                            action.sa_sigaction = SIG_DFL;
-                        }
-                    }
-                } else {
-                    action.sa_sigaction = SIG_DFL;
-                }
-            }
-            sigaction(signum, &action, ptr::null_mut());
+            unregister(signum);
 
             // See comment above for why this function returns.
         }
     }
 
+    // Unregisters `signal_handler` for `signum` by reverting back to the default behavior.
+    unsafe fn unregister(signum: libc::c_int) {
+        let mut action: sigaction = mem::zeroed();
+        set_default(&mut action);
+        sigaction(signum, &action, ptr::null_mut());
+    }
+
     static MAIN_ALTSTACK: AtomicPtr<libc::c_void> = AtomicPtr::new(ptr::null_mut());
     static NEED_ALTSTACK: AtomicBool = AtomicBool::new(false);
 
     pub unsafe fn init() {
         let mut action: sigaction = mem::zeroed();
-        for &signal in &[SIGSEGV, SIGBUS] {
+        for &signal in SIGNALS {
             sigaction(signal, ptr::null_mut(), &mut action);
             // Configure our signal handler if one is not already set.
-            cfg_if! {
-                if #[cfg(not(bootstrap))] {
-                    cfg_if! {
-                        if #[cfg(target_arch = "morello+c64")] {
-                            if action.sa_u.sa_handler.sah_id == SIG_DFL {
-                                action.sa_flags = SA_SIGINFO | SA_ONSTACK;
-                                action.sa_u.sa_sigaction = signal_handler;
-                                sigaction(signal, &action, ptr::null_mut());
-                                NEED_ALTSTACK.store(true, Ordering::Relaxed);
-                            }
-                        } else {
-                            if action.sa_sigaction == SIG_DFL {
-                                action.sa_flags = SA_SIGINFO | SA_ONSTACK;
-                                action.sa_sigaction = signal_handler as sighandler_t;
-                                sigaction(signal, &action, ptr::null_mut());
-                                NEED_ALTSTACK.store(true, Ordering::Relaxed);
-                            }
-                        }
-                    }
-                } else {
-                    if action.sa_sigaction == SIG_DFL {
-                        action.sa_flags = SA_SIGINFO | SA_ONSTACK;
-                        action.sa_sigaction = signal_handler as sighandler_t;
-                        sigaction(signal, &action, ptr::null_mut());
-                        NEED_ALTSTACK.store(true, Ordering::Relaxed);
-                    }
-                }
+            if is_default(&action) {
+                action.sa_flags = SA_SIGINFO | SA_ONSTACK;
+                set_signal_handler(&mut action);
+                sigaction(signal, &action, ptr::null_mut());
+                NEED_ALTSTACK.store(true, Ordering::Relaxed);
             }
         }
 
@@ -0,0 +1,100 @@
+//! Symbolization of backtraces on CHERI pure-capability targets.
+//!
+//! The instruction pointers of frames are capabilities derived from PCC, so only their addresses
+//! can be looked up. Executables and shared objects are position independent: the dynamic linker
+//! maps them at some base address and fixes up the capabilities they contain with relative
+//! relocations, but their DWARF and symbol tables keep describing link-time addresses. Each
+//! address is therefore first mapped back to the object it belongs to, and then moved by the base
+//! of that object before looking it up in the object's debuginfo, falling back to its symbol
+//! table for objects without debuginfo.
+
+use crate::env;
+use crate::ffi::{c_int, c_void, CStr, OsStr};
+use crate::fs;
+use crate::os::unix::ffi::OsStrExt;
+use crate::path::PathBuf;
+use crate::slice;
+
+mod elf;
+
+use self::elf::Object;
+pub use self::elf::Symbol;
+
+/// Resolves the instruction pointers of a backtrace, returning the symbols of each frame in
+/// order.
+pub fn resolve(ips: &[*mut c_void]) -> Vec<Vec<Symbol>> {
+    let addrs: Vec<usize> = ips.iter().map(|ip| ip.addr()).collect();
+    let mut symbols: Vec<Vec<Symbol>> = ips.iter().map(|_| Vec::new()).collect();
+
+    // Every object is parsed at most once, for all the frames that fall into it.
+    for library in libraries() {
+        let mut hits = addrs.iter().enumerate().filter(|&(_, &addr)| library.contains(addr));
+        let Some(first) = hits.next() else { continue };
+        let Ok(data) = fs::read(&library.path) else { continue };
+        let Some(object) = Object::parse(&data) else { continue };
+        let cx = object.context();
+        for (i, &addr) in [first].into_iter().chain(hits) {
+            object.resolve(cx.as_ref(), addr, library.bias, &mut symbols[i]);
+        }
+    }
+    symbols
+}
+
+/// An object loaded into the process.
+struct Library {
+    path: PathBuf,
+    /// Difference between the run-time and link-time addresses of the object.
+    bias: usize,
+    /// Link-time address ranges of the loaded segments.
+    segments: Vec<(usize, usize)>,
+}
+
+impl Library {
+    fn contains(&self, addr: usize) -> bool {
+        let svma = addr.wrapping_sub(self.bias);
+        self.segments.iter().any(|&(start, len)| svma.wrapping_sub(start) < len)
+    }
+}
+
+fn libraries() -> Vec<Library> {
+    let mut libraries: Vec<Library> = Vec::new();
+    unsafe {
+        libc::dl_iterate_phdr(Some(callback), &mut libraries as *mut Vec<Library> as *mut c_void);
+    }
+    libraries
+}
+
+unsafe extern "C" fn callback(
+    info: *mut libc::dl_phdr_info,
+    _size: libc::size_t,
+    data: *mut c_void,
+) -> c_int {
+    let libraries = unsafe { &mut *(data as *mut Vec<Library>) };
+    let info = unsafe { &*info };
+    let name = if info.dlpi_name.is_null() {
+        &[][..]
+    } else {
+        unsafe { CStr::from_ptr(info.dlpi_name) }.to_bytes()
+    };
+    // The executable itself is reported without a name.
+    let path = if name.is_empty() {
+        match env::current_exe() {
+            Ok(path) => path,
+            Err(_) => return 0,
+        }
+    } else {
+        PathBuf::from(OsStr::from_bytes(name))
+    };
+    let phdrs = if info.dlpi_phdr.is_null() {
+        &[][..]
+    } else {
+        unsafe { slice::from_raw_parts(info.dlpi_phdr, info.dlpi_phnum as usize) }
+    };
+    let segments = phdrs
+        .iter()
+        .filter(|phdr| phdr.p_type == libc::PT_LOAD)
+        .map(|phdr| (phdr.p_vaddr as usize, phdr.p_memsz as usize))
+        .collect();
+    libraries.push(Library { path, bias: info.dlpi_addr as usize, segments });
+    0
+}
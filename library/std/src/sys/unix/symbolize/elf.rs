@@ -0,0 +1,123 @@
+//! Resolution of code addresses in a single ELF object.
+//!
+//! This only depends on the object and its debuginfo, not on the process it was loaded into, so
+//! that it can be tested on any host against objects built for a capability target.
+
+use addr2line::gimli;
+use object::elf::{EM_AARCH64, SHF_COMPRESSED, SHT_DYNSYM, SHT_SYMTAB, STT_FUNC};
+use object::read::elf::{FileHeader, SectionHeader, SectionTable, Sym, SymbolTable};
+use object::NativeEndian;
+
+type Elf = object::elf::FileHeader64<NativeEndian>;
+pub type Context<'data> = addr2line::Context<gimli::EndianSlice<'data, gimli::NativeEndian>>;
+
+/// A function that an instruction pointer resolved to. Inlined functions get a symbol each.
+pub struct Symbol {
+    pub name: Option<Vec<u8>>,
+    pub filename: Option<Vec<u8>>,
+    pub lineno: Option<u32>,
+    pub colno: Option<u32>,
+}
+
+/// A parsed ELF file.
+pub struct Object<'data> {
+    data: &'data [u8],
+    endian: NativeEndian,
+    sections: SectionTable<'data, Elf>,
+    symbols: SymbolTable<'data, Elf>,
+    /// Clears the bit of code addresses that selects the instruction set rather than a byte.
+    code_mask: u64,
+}
+
+impl<'data> Object<'data> {
+    pub fn parse(data: &'data [u8]) -> Option<Object<'data>> {
+        let elf = Elf::parse(data).ok()?;
+        let endian = elf.endian().ok()?;
+        let sections = elf.sections(endian, data).ok()?;
+        let mut symbols = sections.symbols(endian, data, SHT_SYMTAB).ok()?;
+        if symbols.is_empty() {
+            symbols = sections.symbols(endian, data, SHT_DYNSYM).ok()?;
+        }
+        // On Morello, branches into C64 code set the lowest bit of the address, and so do the
+        // symbols of C64 functions. Instructions are aligned, so the bit is never part of the
+        // address of one.
+        let code_mask = if elf.e_machine(endian) == EM_AARCH64 { !1 } else { !0 };
+        Some(Object { data, endian, sections, symbols, code_mask })
+    }
+
+    fn section(&self, name: &str) -> Option<&'data [u8]> {
+        let (_, section) = self.sections.section_by_name(self.endian, name.as_bytes())?;
+        // We don't decompress debuginfo, so compressed sections are treated as missing.
+        if section.sh_flags(self.endian) & u64::from(SHF_COMPRESSED) != 0 {
+            return None;
+        }
+        section.data(self.endian, self.data).ok()
+    }
+
+    pub fn context(&self) -> Option<Context<'data>> {
+        let dwarf = gimli::Dwarf::load(|id| -> Result<_, ()> {
+            let data = self.section(id.name()).unwrap_or(&[]);
+            Ok(gimli::EndianSlice::new(data, gimli::NativeEndian::default()))
+        })
+        .ok()?;
+        Context::from_dwarf(dwarf).ok()
+    }
+
+    /// Resolves the address of the instruction pointer of a frame, pushing the functions it is in
+    /// to `symbols`. `bias` is the difference between the run-time and link-time addresses of the
+    /// object.
+    pub fn resolve(
+        &self,
+        cx: Option<&Context<'data>>,
+        ip: usize,
+        bias: usize,
+        symbols: &mut Vec<Symbol>,
+    ) {
+        let addr = ip as u64 & self.code_mask;
+        // Instruction pointers are return addresses, which may already belong to the next line or
+        // even the next function. Look up the call instruction instead.
+        let addr = if addr == 0 { 0 } else { addr - 1 };
+        let svma = addr.wrapping_sub(bias as u64);
+
+        if let Some(mut frames) = cx.and_then(|cx| cx.find_frames(svma).ok()) {
+            while let Ok(Some(frame)) = frames.next() {
+                let (filename, lineno, colno) = match frame.location {
+                    Some(loc) => {
+                        (loc.file.map(|file| file.as_bytes().to_vec()), loc.line, loc.column)
+                    }
+                    None => (None, None, None),
+                };
+                symbols.push(Symbol {
+                    name: frame.function.map(|function| function.name.slice().to_vec()),
+                    filename,
+                    lineno,
+                    colno,
+                });
+            }
+        }
+
+        // The debuginfo may not cover the function, or not name it.
+        if symbols.last().map_or(true, |symbol| symbol.name.is_none()) {
+            if let Some(name) = self.symbol_name(svma) {
+                match symbols.last_mut() {
+                    Some(symbol) => symbol.name = Some(name.to_vec()),
+                    None => symbols.push(Symbol {
+                        name: Some(name.to_vec()),
+                        filename: None,
+                        lineno: None,
+                        colno: None,
+                    }),
+                }
+            }
+        }
+    }
+
+    /// Returns the name of the function in the symbol table that contains `svma`.
+    fn symbol_name(&self, svma: u64) -> Option<&'data [u8]> {
+        let sym = self.symbols.symbols().iter().find(|sym| {
+            let value = sym.st_value(self.endian) & self.code_mask;
+            sym.st_type() == STT_FUNC && svma.wrapping_sub(value) < sym.st_size(self.endian)
+        })?;
+        sym.name(self.endian, self.symbols.strings()).ok()
+    }
+}
@@ -867,7 +867,10 @@ pub unsafe fn current() -> Option<Guard> {
                     #[cfg(bootstrap)]
                     panic!("there is no guard page");
                     #[cfg(not(bootstrap))]
-                    if !cfg!(target_arch = "morello+c64") {
+                    if !cfg!(any(
+                        target_arch = "morello+c64",
+                        all(target_arch = "riscv64", target_pointer_type_width = "128")
+                    )) {
                         panic!("there is no guard page");
                     }
                 }
@@ -3,6 +3,7 @@
 /// Common code for printing the backtrace in the same way across the different
 /// supported platforms.
 use crate::env;
+use crate::ffi::c_void;
 use crate::fmt;
 use crate::io;
 use crate::io::prelude::*;
@@ -58,56 +59,175 @@ unsafe fn _print_fmt(fmt: &mut fmt::Formatter<'_>, print_fmt: PrintFmt) -> fmt::
     writeln!(fmt, "stack backtrace:")?;
     let mut bt_fmt = BacktraceFmt::new(fmt, print_fmt, &mut print_path);
     bt_fmt.add_context()?;
-    let mut idx = 0;
-    let mut res = Ok(());
-    // Start immediately if we're not using a short backtrace.
-    let mut start = print_fmt != PrintFmt::Short;
-    backtrace_rs::trace_unsynchronized(|frame| {
-        if print_fmt == PrintFmt::Short && idx > MAX_NB_FRAMES {
-            return false;
+    print_frames(&mut bt_fmt, print_fmt)?;
+    bt_fmt.finish()?;
+    if print_fmt == PrintFmt::Short {
+        writeln!(
+            fmt,
+            "note: Some details are omitted, \
+             run with `RUST_BACKTRACE=full` for a verbose backtrace."
+        )?;
+    }
+    Ok(())
+}
+
+/// A function that a frame resolved to, as passed to the callback of `resolve_frames`.
+pub struct ResolvedSymbol<'a> {
+    pub name: Option<&'a [u8]>,
+    pub filename: Option<BytesOrWideString<'a>>,
+    pub lineno: Option<u32>,
+    pub colno: Option<u32>,
+}
+
+cfg_if::cfg_if! {
+    if #[cfg(all(
+        feature = "backtrace",
+        any(
+            target_arch = "morello+c64",
+            all(target_arch = "riscv64", target_pointer_type_width = "128")
+        )
+    ))] {
+        // On purecap targets we symbolize frames ourselves, see `sys::unix::symbolize`. This is
+        // done for all frames at once, so that every object is only parsed once.
+        /// Resolves the symbols of `frames`, calling `f` with the index of the frame that each
+        /// symbol belongs to.
+        pub unsafe fn resolve_frames<'a>(
+            frames: impl Iterator<Item = &'a backtrace_rs::Frame>,
+            mut f: impl FnMut(usize, ResolvedSymbol<'_>),
+        ) {
+            let ips: Vec<_> = frames.map(|frame| frame.ip()).collect();
+            for (i, symbols) in crate::sys::symbolize::resolve(&ips).iter().enumerate() {
+                for symbol in symbols {
+                    f(
+                        i,
+                        ResolvedSymbol {
+                            name: symbol.name.as_deref(),
+                            filename: symbol.filename.as_deref().map(BytesOrWideString::Bytes),
+                            lineno: symbol.lineno,
+                            colno: symbol.colno,
+                        },
+                    );
+                }
+            }
         }
 
-        let mut hit = false;
-        let mut stop = false;
-        backtrace_rs::resolve_frame_unsynchronized(frame, |symbol| {
-            hit = true;
-            if print_fmt == PrintFmt::Short {
-                if let Some(sym) = symbol.name().and_then(|s| s.as_str()) {
-                    if start && sym.contains("__rust_begin_short_backtrace") {
-                        stop = true;
-                        return;
+        unsafe fn print_frames(
+            bt_fmt: &mut BacktraceFmt<'_, '_>,
+            print_fmt: PrintFmt,
+        ) -> fmt::Result {
+            let mut ips = Vec::new();
+            backtrace_rs::trace_unsynchronized(|frame| {
+                ips.push(frame.ip());
+                print_fmt != PrintFmt::Short || ips.len() <= MAX_NB_FRAMES
+            });
+
+            // Start immediately if we're not using a short backtrace.
+            let mut start = print_fmt != PrintFmt::Short;
+            for (&ip, symbols) in ips.iter().zip(crate::sys::symbolize::resolve(&ips)) {
+                let mut stop = false;
+                for symbol in &symbols {
+                    if print_fmt == PrintFmt::Short {
+                        let name = symbol.name.as_deref().map(crate::str::from_utf8);
+                        if let Some(Ok(sym)) = name {
+                            if start && sym.contains("__rust_begin_short_backtrace") {
+                                stop = true;
+                                continue;
+                            }
+                            if sym.contains("__rust_end_short_backtrace") {
+                                start = true;
+                                continue;
+                            }
+                        }
                     }
-                    if sym.contains("__rust_end_short_backtrace") {
-                        start = true;
-                        return;
+
+                    if start {
+                        bt_fmt.frame().print_raw_with_column(
+                            ip,
+                            symbol.name.as_deref().map(backtrace_rs::SymbolName::new),
+                            symbol.filename.as_deref().map(BytesOrWideString::Bytes),
+                            symbol.lineno,
+                            symbol.colno,
+                        )?;
                     }
                 }
+                if stop {
+                    break;
+                }
+                if symbols.is_empty() && start {
+                    bt_fmt.frame().print_raw(ip, None, None, None)?;
+                }
             }
-
-            if start {
-                res = bt_fmt.frame().symbol(frame, symbol);
-            }
-        });
-        if stop {
-            return false;
+            Ok(())
         }
-        if !hit && start {
-            res = bt_fmt.frame().print_raw(frame.ip(), None, None, None);
+    } else {
+        /// Resolves the symbols of `frames`, calling `f` with the index of the frame that each
+        /// symbol belongs to.
+        pub unsafe fn resolve_frames<'a>(
+            frames: impl Iterator<Item = &'a backtrace_rs::Frame>,
+            mut f: impl FnMut(usize, ResolvedSymbol<'_>),
+        ) {
+            for (i, frame) in frames.enumerate() {
+                backtrace_rs::resolve_frame_unsynchronized(frame, |symbol| {
+                    f(
+                        i,
+                        ResolvedSymbol {
+                            name: symbol.name().map(|m| m.as_bytes()),
+                            filename: symbol.filename_raw(),
+                            lineno: symbol.lineno(),
+                            colno: symbol.colno(),
+                        },
+                    );
+                });
+            }
         }
 
-        idx += 1;
-        res.is_ok()
-    });
-    res?;
-    bt_fmt.finish()?;
-    if print_fmt == PrintFmt::Short {
-        writeln!(
-            fmt,
-            "note: Some details are omitted, \
-             run with `RUST_BACKTRACE=full` for a verbose backtrace."
-        )?;
+        unsafe fn print_frames(
+            bt_fmt: &mut BacktraceFmt<'_, '_>,
+            print_fmt: PrintFmt,
+        ) -> fmt::Result {
+            let mut idx = 0;
+            let mut res = Ok(());
+            // Start immediately if we're not using a short backtrace.
+            let mut start = print_fmt != PrintFmt::Short;
+            backtrace_rs::trace_unsynchronized(|frame| {
+                if print_fmt == PrintFmt::Short && idx > MAX_NB_FRAMES {
+                    return false;
+                }
+
+                let mut hit = false;
+                let mut stop = false;
+                backtrace_rs::resolve_frame_unsynchronized(frame, |symbol| {
+                    hit = true;
+                    if print_fmt == PrintFmt::Short {
+                        if let Some(sym) = symbol.name().and_then(|s| s.as_str()) {
+                            if start && sym.contains("__rust_begin_short_backtrace") {
+                                stop = true;
+                                return;
+                            }
+                            if sym.contains("__rust_end_short_backtrace") {
+                                start = true;
+                                return;
+                            }
+                        }
+                    }
+
+                    if start {
+                        res = bt_fmt.frame().symbol(frame, symbol);
+                    }
+                });
+                if stop {
+                    return false;
+                }
+                if !hit && start {
+                    res = bt_fmt.frame().print_raw(frame.ip(), None, None, None);
+                }
+
+                idx += 1;
+                res.is_ok()
+            });
+            res
+        }
     }
-    Ok(())
 }
 
 /// Fixed frame used to clean the backtrace with `RUST_BACKTRACE=1`. Note that
@@ -142,6 +262,18 @@ pub fn __rust_end_short_backtrace<F, T>(f: F) -> T
     result
 }
 
+/// Returns the address of the instruction that a code pointer or a frame's instruction pointer
+/// points to.
+///
+/// On purecap targets these are capabilities, of which only the address identifies the
+/// instruction. On Morello, branches and calls in the C64 instruction set also set the lowest bit
+/// of the address, which function pointers and return addresses then carry but the starts of
+/// functions reported by the unwinder don't.
+pub fn code_address(ip: *const c_void) -> usize {
+    let addr = ip.addr();
+    if cfg!(target_arch = "morello+c64") { addr & !1 } else { addr }
+}
+
 /// Prints the filename of the backtrace frame.
 ///
 /// See also `output`.
@@ -0,0 +1,96 @@
+#![cfg(panic = "unwind")]
+
+use std::cell::Cell;
+use std::panic::{self, AssertUnwindSafe};
+
+struct SetOnDrop<'a>(&'a Cell<bool>);
+
+impl Drop for SetOnDrop<'_> {
+    fn drop(&mut self) {
+        self.0.set(true);
+    }
+}
+
+#[inline(never)]
+fn recurse_then_panic(depth: usize, dropped: &Cell<usize>) {
+    struct CountOnDrop<'a>(&'a Cell<usize>);
+
+    impl Drop for CountOnDrop<'_> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    let _guard = CountOnDrop(dropped);
+    if depth == 0 {
+        panic!("bottom");
+    }
+    recurse_then_panic(depth - 1, dropped);
+}
+
+#[test]
+fn catch_panic() {
+    let result = panic::catch_unwind(|| -> i32 { panic!("oops") });
+    let payload = result.unwrap_err();
+    assert_eq!(payload.downcast_ref::<&str>(), Some(&"oops"));
+}
+
+#[test]
+fn no_panic() {
+    assert_eq!(panic::catch_unwind(|| 42).unwrap(), 42);
+}
+
+#[test]
+fn cleanups_run() {
+    let dropped = Cell::new(false);
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        let _guard = SetOnDrop(&dropped);
+        panic!("cleanup");
+    }));
+    assert!(result.is_err());
+    assert!(dropped.get());
+}
+
+#[test]
+fn cleanups_run_in_every_frame() {
+    let dropped = Cell::new(0);
+    let result = panic::catch_unwind(AssertUnwindSafe(|| recurse_then_panic(16, &dropped)));
+    assert!(result.is_err());
+    assert_eq!(dropped.get(), 17);
+}
+
+#[test]
+fn pointers_survive_unwinding() {
+    // Landing pads read locals that live across the call that panicked. On CHERI these are
+    // capabilities, which have to come out of unwinding still tagged.
+    let boxed = Box::new(7u64);
+    let ptr: *const u64 = &*boxed;
+    let dropped = Cell::new(false);
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        let _guard = SetOnDrop(&dropped);
+        assert_eq!(unsafe { *ptr }, 7);
+        panic!("after read");
+    }));
+    assert!(result.is_err());
+    assert!(dropped.get());
+    assert_eq!(unsafe { *ptr }, 7);
+    assert_eq!(*boxed, 7);
+}
+
+#[test]
+fn nested() {
+    let outer = panic::catch_unwind(|| {
+        let inner = panic::catch_unwind(|| panic!("inner"));
+        assert!(inner.is_err());
+        panic!("outer");
+    });
+    let payload = outer.unwrap_err();
+    assert_eq!(payload.downcast_ref::<&str>(), Some(&"outer"));
+}
+
+#[test]
+fn resume_unwind_payload() {
+    let result = panic::catch_unwind(|| panic::resume_unwind(Box::new(String::from("payload"))));
+    let payload = result.unwrap_err();
+    assert_eq!(payload.downcast_ref::<String>().map(String::as_str), Some("payload"));
+}
@@ -0,0 +1,62 @@
+// Only capability targets have a revocation mode. On CheriBSD the kernel does the revoking instead
+// of sweeping the test heap, which needs a process started with revocation enabled.
+#![cfg(all(
+    unix,
+    any(
+        target_arch = "morello+c64",
+        all(target_arch = "riscv32", target_pointer_type_width = "64"),
+        all(target_arch = "riscv64", target_pointer_type_width = "128")
+    ),
+    not(target_os = "freebsd")
+))]
+#![feature(allocator_api, cheri_revocation)]
+
+use std::alloc::{self, Allocator, Layout, System};
+use std::io;
+use std::ptr::{self, NonNull};
+use std::sync::atomic::{AtomicPtr, Ordering};
+
+static HEAP: [AtomicPtr<u8>; 2] =
+    [AtomicPtr::new(ptr::null_mut()), AtomicPtr::new(ptr::null_mut())];
+
+fn allocate(slot: &AtomicPtr<u8>, layout: Layout) {
+    slot.store(System.allocate(layout).unwrap().as_mut_ptr(), Ordering::Relaxed);
+}
+
+unsafe fn deallocate(slot: &AtomicPtr<u8>, layout: Layout) {
+    System.deallocate(NonNull::new(slot.load(Ordering::Relaxed)).unwrap(), layout);
+}
+
+// Everything is in one test, as revocation mode applies to the whole process.
+#[test]
+fn revocation() {
+    // Without a kernel that revokes, there is nothing to revoke until a test heap is registered.
+    let err = alloc::set_cheri_quarantine_size(Some(64)).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::Unsupported);
+
+    alloc::set_cheri_revocation_test_heap(&HEAP);
+    let layout = Layout::from_size_align(128, 16).unwrap();
+
+    // Freed memory stays in the quarantine until it is revoked explicitly.
+    alloc::set_cheri_quarantine_size(Some(usize::MAX)).unwrap();
+    allocate(&HEAP[0], layout);
+    allocate(&HEAP[1], layout);
+    let live = HEAP[1].load(Ordering::Relaxed);
+    unsafe { deallocate(&HEAP[0], layout) };
+    assert!(!HEAP[0].load(Ordering::Relaxed).is_null());
+    alloc::cheri_revoke_now();
+    assert!(HEAP[0].load(Ordering::Relaxed).is_null());
+    assert_eq!(HEAP[1].load(Ordering::Relaxed), live);
+
+    // Freeing more than the quarantine holds revokes right away.
+    alloc::set_cheri_quarantine_size(Some(64)).unwrap();
+    unsafe { deallocate(&HEAP[1], layout) };
+    assert!(HEAP[1].load(Ordering::Relaxed).is_null());
+
+    // Outside of revocation mode, memory is freed without being revoked.
+    alloc::set_cheri_quarantine_size(None).unwrap();
+    allocate(&HEAP[0], layout);
+    unsafe { deallocate(&HEAP[0], layout) };
+    assert!(!HEAP[0].load(Ordering::Relaxed).is_null());
+    HEAP[0].store(ptr::null_mut(), Ordering::Relaxed);
+}
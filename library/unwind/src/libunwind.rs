@@ -20,7 +20,19 @@ pub enum _Unwind_Reason_Code {
 
 pub type _Unwind_Exception_Class = u64;
 pub type _Unwind_Word = uintptr_t;
+#[cfg(not(any(
+    target_arch = "morello+c64",
+    all(target_arch = "riscv64", target_pointer_type_width = "128")
+)))]
 pub type _Unwind_Ptr = uintptr_t;
+// libunwind's `uintptr_t` is a capability on CHERI pure-capability targets, while
+// `libc::uintptr_t` only holds its address. Code addresses and register values have to keep
+// their tags on the way in and out of libunwind, so they are passed as pointers here.
+#[cfg(any(
+    target_arch = "morello+c64",
+    all(target_arch = "riscv64", target_pointer_type_width = "128")
+))]
+pub type _Unwind_Ptr = *mut c_void;
 pub type _Unwind_Trace_Fn =
     extern "C" fn(ctx: *mut _Unwind_Context, arg: *mut c_void) -> _Unwind_Reason_Code;
 
@@ -39,9 +51,11 @@ pub enum _Unwind_Reason_Code {
 #[cfg(all(target_arch = "aarch64", target_pointer_width = "64"))]
 pub const unwinder_private_data_size: usize = 2;
 
+// As on CHERI-RISC-V below, libunwind's two `uintptr_t`s are capabilities.
 #[cfg(not(bootstrap))]
 #[cfg(all(target_arch = "morello+c64", target_pointer_width = "64"))]
-pub const unwinder_private_data_size: usize = 2;
+pub const unwinder_private_data_size: usize =
+    2 * core::mem::size_of::<*const c_void>() / core::mem::size_of::<_Unwind_Word>();
 
 #[cfg(all(target_arch = "aarch64", target_pointer_width = "32"))]
 pub const unwinder_private_data_size: usize = 5;
@@ -64,8 +78,11 @@ pub enum _Unwind_Reason_Code {
 #[cfg(any(target_arch = "sparc", target_arch = "sparc64"))]
 pub const unwinder_private_data_size: usize = 2;
 
+// libunwind keeps two `uintptr_t`s here, which are capabilities on CHERI-RISC-V purecap targets
+// and so wider than `_Unwind_Word`.
 #[cfg(any(target_arch = "riscv64", target_arch = "riscv32"))]
-pub const unwinder_private_data_size: usize = 2;
+pub const unwinder_private_data_size: usize =
+    2 * core::mem::size_of::<*const c_void>() / core::mem::size_of::<_Unwind_Word>();
 
 #[cfg(target_os = "emscripten")]
 pub const unwinder_private_data_size: usize = 20;
@@ -127,12 +144,12 @@ pub enum _Unwind_Action {
         link(name = "unwind", kind = "static", modifiers = "-bundle")
     )]
     extern "C" {
-        pub fn _Unwind_GetGR(ctx: *mut _Unwind_Context, reg_index: c_int) -> _Unwind_Word;
-        pub fn _Unwind_SetGR(ctx: *mut _Unwind_Context, reg_index: c_int, value: _Unwind_Word);
-        pub fn _Unwind_GetIP(ctx: *mut _Unwind_Context) -> _Unwind_Word;
-        pub fn _Unwind_SetIP(ctx: *mut _Unwind_Context, value: _Unwind_Word);
+        pub fn _Unwind_GetGR(ctx: *mut _Unwind_Context, reg_index: c_int) -> _Unwind_Ptr;
+        pub fn _Unwind_SetGR(ctx: *mut _Unwind_Context, reg_index: c_int, value: _Unwind_Ptr);
+        pub fn _Unwind_GetIP(ctx: *mut _Unwind_Context) -> _Unwind_Ptr;
+        pub fn _Unwind_SetIP(ctx: *mut _Unwind_Context, value: _Unwind_Ptr);
         pub fn _Unwind_GetIPInfo(ctx: *mut _Unwind_Context, ip_before_insn: *mut c_int)
-                                 -> _Unwind_Word;
+                                 -> _Unwind_Ptr;
         pub fn _Unwind_FindEnclosingFunction(pc: *mut c_void) -> *mut c_void;
     }
 
@@ -29,7 +29,6 @@
 use crate::{Build, CLang, DocTests, GitRepo, Mode};
 
 pub use crate::Compiler;
-use home::home_dir;
 // FIXME: replace with std::lazy after it gets stabilized and reaches beta
 use once_cell::sync::{Lazy, OnceCell};
 use xz2::bufread::XzDecoder;
@@ -1738,26 +1737,19 @@ pub fn cargo(
         if self.is_fuse_ld_lld(target) {
             rustflags.arg("-Clink-args=-fuse-ld=lld");
         }
-        if target == "morello-unknown-freebsd-purecap" {
-            rustflags.arg("-Clink-args=-march=morello+c64");
-            rustflags.arg("-Clink-args=-mabi=purecap");
-            let sysroot = match home_dir() {
-                Some(path) => path.as_path().join("cheri").join("output").join("rootfs-morello-purecap"),
-                None => Path::new("").to_path_buf(),
-            };
-            let sysroot = sysroot.into_os_string().into_string().unwrap();
-            rustflags.arg(&format!("-Clink-args=--sysroot={}", sysroot));
-        }
-        if target == "morello-unknown-linux-purecap" {
-            rustflags.arg("-Clink-args=-march=morello+c64");
-            rustflags.arg("-Clink-args=-mabi=purecap");
-            let sysroot = match home_dir() {
-                Some(path) => path.as_path().join("morello").join("musl"),
-                None => Path::new("").to_path_buf(),
-            };
-            let sysroot = sysroot.into_os_string().into_string().unwrap();
-            rustflags.arg(&format!("-Clink-args=--sysroot={}", sysroot));
-            rustflags.arg(&format!("-Clink-self-contained=no"));
+        // CHERI pure-capability targets need the same ABI and sysroot flags when clang is
+        // driving the link as when it compiles C code.
+        for flag in self.purecap_cflags(target) {
+            rustflags.arg(&format!("-Clink-arg={}", flag));
+        }
+        if target.contains("musl") && self.c_sysroot(target).is_some() {
+            // Use the crt objects and libc from the configured sysroot rather than ours.
+            rustflags.arg("-Clink-self-contained=no");
+        }
+        if let Some(t) = self.config.target_config.get(&target) {
+            for arg in &t.link_args {
+                rustflags.arg(&format!("-Clink-arg={}", arg));
+            }
         }
         self.lld_flags(target).for_each(|flag| {
             rustdocflags.arg(&flag);
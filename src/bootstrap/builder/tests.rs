@@ -555,6 +555,7 @@ fn test_with_no_doc_stage0() {
             force_rerun: false,
             compare_mode: None,
             rustfix_coverage: false,
+            cheri_qemu: None,
             pass: None,
             run: None,
         };
@@ -625,6 +626,7 @@ fn test_docs() {
             force_rerun: false,
             compare_mode: None,
             rustfix_coverage: false,
+            cheri_qemu: None,
             pass: None,
             run: None,
         };
@@ -27,7 +27,7 @@
 use std::{env, iter};
 
 use crate::config::{Target, TargetSelection};
-use crate::util::output;
+use crate::util::{exe, output};
 use crate::{Build, CLang, GitRepo};
 
 // The `cc` crate doesn't provide a way to obtain a path to the detected archiver,
@@ -109,6 +109,8 @@ pub fn find(build: &mut Build) {
         let compiler = cfg.get_compiler();
         let ar = if let ar @ Some(..) = config.and_then(|c| c.ar.clone()) {
             ar
+        } else if let Some(sdk) = build.cheri_sdk(target) {
+            Some(sdk.join("bin").join(exe("llvm-ar", build.build)))
         } else {
             cc2ar(compiler.path(), target)
         };
@@ -162,6 +164,13 @@ fn set_compiler(
     config: Option<&Target>,
     build: &Build,
 ) {
+    // A CHERI SDK ships a clang which knows how to generate capability code, so prefer it over
+    // whichever compiler happens to be on the `PATH`.
+    if let Some(sdk) = build.cheri_sdk(target) {
+        cfg.compiler(sdk.join("bin").join(exe(compiler.clang(), build.build)));
+        return;
+    }
+
     match &*target.triple {
         // When compiling for android we may have the NDK configured in the
         // config.toml in which case we look there. Otherwise the default
@@ -517,14 +517,25 @@ fn make_run(run: RunConfig<'_>) {
     ///
     /// These are primarily used on Windows right now for linking executables/dlls.
     /// They don't require any library support as they're just plain old object
-    /// files, so we just use the nightly snapshot compiler to always build them (as
-    /// no other compilers are guaranteed to be available).
+    /// files, so we use the nightly snapshot compiler to build them (as no other
+    /// compilers are guaranteed to be available).
+    ///
+    /// The exception is rscaprelocs.o, which `-Z cheri-self-init-caprelocs` links
+    /// into executables for freestanding CHERI targets whose spec sets
+    /// `self_init_caprelocs`. The snapshot compiler doesn't know these targets, so
+    /// this object is built with the compiler the standard library is built with.
     fn run(self, builder: &Builder<'_>) -> Vec<(PathBuf, DependencyType)> {
         let for_compiler = self.compiler;
         let target = self.target;
-        if !target.ends_with("windows-gnu") {
+        let is_cheri_freestanding =
+            builder.target_spec(target).map_or(false, |spec| spec.self_init_caprelocs);
+        let files: &[&str] = if target.ends_with("windows-gnu") {
+            &["rsbegin", "rsend"]
+        } else if is_cheri_freestanding {
+            &["rscaprelocs"]
+        } else {
             return vec![];
-        }
+        };
 
         let mut target_deps = vec![];
 
@@ -533,13 +544,18 @@ fn run(self, builder: &Builder<'_>) -> Vec<(PathBuf, DependencyType)> {
         let sysroot_dir = &builder.sysroot_libdir(for_compiler, target);
         t!(fs::create_dir_all(dst_dir));
 
-        for file in &["rsbegin", "rsend"] {
+        for file in files {
             let src_file = &src_dir.join(file.to_string() + ".rs");
             let dst_file = &dst_dir.join(file.to_string() + ".o");
             if !up_to_date(src_file, dst_file) {
-                let mut cmd = Command::new(&builder.initial_rustc);
+                let rustc = if is_cheri_freestanding {
+                    builder.rustc(for_compiler)
+                } else {
+                    builder.initial_rustc.clone()
+                };
+                let mut cmd = Command::new(&rustc);
                 cmd.env("RUSTC_BOOTSTRAP", "1");
-                if !builder.local_rebuild {
+                if rustc == builder.initial_rustc && !builder.local_rebuild {
                     // a local_rebuild compiler already has stage1 features
                     cmd.arg("--cfg").arg("bootstrap");
                 }
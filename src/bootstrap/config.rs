@@ -431,6 +431,9 @@ pub struct Target {
     pub musl_libdir: Option<PathBuf>,
     pub wasi_root: Option<PathBuf>,
     pub qemu_rootfs: Option<PathBuf>,
+    pub sysroot: Option<PathBuf>,
+    pub cheri_sdk: Option<PathBuf>,
+    pub link_args: Vec<String>,
     pub no_std: bool,
 }
 
@@ -771,6 +774,9 @@ struct TomlTarget {
         musl_libdir: Option<String> = "musl-libdir",
         wasi_root: Option<String> = "wasi-root",
         qemu_rootfs: Option<String> = "qemu-rootfs",
+        sysroot: Option<String> = "sysroot",
+        cheri_sdk: Option<String> = "cheri-sdk",
+        link_args: Option<Vec<String>> = "link-args",
         no_std: Option<bool> = "no-std",
     }
 }
@@ -1235,6 +1241,9 @@ pub fn parse(args: &[String]) -> Config {
                 target.musl_libdir = cfg.musl_libdir.map(PathBuf::from);
                 target.wasi_root = cfg.wasi_root.map(PathBuf::from);
                 target.qemu_rootfs = cfg.qemu_rootfs.map(PathBuf::from);
+                target.sysroot = cfg.sysroot.map(PathBuf::from);
+                target.cheri_sdk = cfg.cheri_sdk.map(PathBuf::from);
+                target.link_args = cfg.link_args.unwrap_or_default();
                 target.sanitizers = cfg.sanitizers;
                 target.profiler = cfg.profiler;
 
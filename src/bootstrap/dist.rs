@@ -520,6 +520,7 @@ fn run(self, builder: &Builder<'_>) {
             cp_debugger_script("natvis/libstd.natvis");
         } else {
             cp_debugger_script("rust_types.py");
+            cp_debugger_script("cheri_capability.py");
 
             // gdb debugger scripts
             builder.install(&builder.src.join("src/etc/rust-gdb"), &sysroot.join("bin"), 0o755);
@@ -3,7 +3,7 @@
 //! This module implements the command-line parsing of the build system which
 //! has various flags to configure how it's run.
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use getopts::Options;
 
@@ -124,6 +124,8 @@ pub enum Subcommand {
         fail_fast: bool,
         doc_tests: DocTests,
         rustfix_coverage: bool,
+        /// Disk image of a CheriBSD guest to boot in QEMU and run target tests in
+        cheri_qemu: Option<PathBuf>,
     },
     Bench {
         paths: Vec<PathBuf>,
@@ -318,6 +320,12 @@ pub fn parse(args: &[String]) -> Flags {
                     "enable this to generate a Rustfix coverage file, which is saved in \
                         `/<build_base>/rustfix_missing_coverage.txt`",
                 );
+                opts.optopt(
+                    "",
+                    "cheri-qemu",
+                    "boot this CheriBSD disk image in QEMU and run target tests inside it",
+                    "PATH",
+                );
             }
             Kind::Check => {
                 opts.optflag("", "all-targets", "Check all targets");
@@ -583,6 +591,7 @@ pub fn parse(args: &[String]) -> Flags {
                 rustc_args: matches.opt_strs("rustc-args"),
                 fail_fast: !matches.opt_present("no-fail-fast"),
                 rustfix_coverage: matches.opt_present("rustfix-coverage"),
+                cheri_qemu: matches.opt_str("cheri-qemu").map(PathBuf::from),
                 doc_tests: if matches.opt_present("doc") {
                     DocTests::Only
                 } else if matches.opt_present("no-doc") {
@@ -777,6 +786,13 @@ pub fn rustfix_coverage(&self) -> bool {
         }
     }
 
+    pub fn cheri_qemu(&self) -> Option<&Path> {
+        match *self {
+            Subcommand::Test { ref cheri_qemu, .. } => cheri_qemu.as_deref(),
+            _ => None,
+        }
+    }
+
     pub fn compare_mode(&self) -> Option<&str> {
         match *self {
             Subcommand::Test { ref compare_mode, .. } => compare_mode.as_ref().map(|s| &s[..]),
@@ -111,16 +111,17 @@
 use std::path::{Path, PathBuf};
 use std::process::Command;
 use std::str;
+use std::sync::Mutex;
 
 use config::Target;
 use filetime::FileTime;
-use home::home_dir;
 use once_cell::sync::OnceCell;
 
 use crate::builder::Kind;
 use crate::config::{LlvmLibunwind, TargetSelection};
 use crate::util::{
     check_run, exe, libdir, mtime, output, run, run_suppressed, try_run, try_run_suppressed, CiEnv,
+    TargetSpec,
 };
 
 mod bolt;
@@ -320,6 +321,7 @@ pub struct Build {
     prerelease_version: Cell<Option<u32>>,
     tool_artifacts:
         RefCell<HashMap<TargetSelection, HashMap<String, (&'static str, PathBuf, Vec<String>)>>>,
+    target_specs: RefCell<HashMap<TargetSelection, Option<TargetSpec>>>,
 
     #[cfg(feature = "build-metrics")]
     metrics: metrics::BuildMetrics,
@@ -518,6 +520,7 @@ pub fn new(mut config: Config) -> Build {
             delayed_failures: RefCell::new(Vec::new()),
             prerelease_version: Cell::new(None),
             tool_artifacts: Default::default(),
+            target_specs: Default::default(),
 
             #[cfg(feature = "build-metrics")]
             metrics: metrics::BuildMetrics::init(),
@@ -704,6 +707,9 @@ pub fn build(&mut self) {
             builder.execute_cli();
         }
 
+        // Shut down any emulators that were booted to run tests in.
+        EMULATORS.lock().unwrap().clear();
+
         // Check for postponed failures from `test --no-fail-fast`.
         let failures = self.delayed_failures.borrow();
         if failures.len() > 0 {
@@ -1078,24 +1084,7 @@ fn cflags(&self, target: TargetSelection, which: GitRepo, c: CLang) -> Vec<Strin
             base.push("-stdlib=libc++".into());
         }
 
-        println!("cflags requested for {:?}", &target.triple);
-        if &*target.triple == "morello-unknown-freebsd-purecap" {
-            let sysroot = match home_dir() {
-                Some(path) => path.as_path().join("cheri").join("output").join("rootfs-morello-purecap"),
-                None => Path::new("").to_path_buf(),
-            };
-            let sysroot = sysroot.into_os_string().into_string().unwrap();
-            base.push(format!("--sysroot={}", sysroot));
-        }
-        if &*target.triple == "morello-unknown-linux-purecap" {
-            base.push("--target=aarch64-unknown-linux-musl_purecap".into());
-            let sysroot = match home_dir() {
-                Some(path) => path.as_path().join("morello").join("musl"),
-                None => Path::new("").to_path_buf(),
-            };
-            let sysroot = sysroot.into_os_string().into_string().unwrap();
-            base.push(format!("--sysroot={}", sysroot));
-        }
+        base.extend(self.purecap_cflags(target));
 
         // Work around an apparently bad MinGW / GCC optimization,
         // See: https://lists.llvm.org/pipermail/cfe-dev/2016-December/051980.html
@@ -1212,6 +1201,72 @@ fn wasi_root(&self, target: TargetSelection) -> Option<&Path> {
         self.config.target_config.get(&target).and_then(|t| t.wasi_root.as_ref()).map(|p| &**p)
     }
 
+    /// Returns the C sysroot configured for this `target`, if defined
+    fn c_sysroot(&self, target: TargetSelection) -> Option<&Path> {
+        self.config.target_config.get(&target).and_then(|t| t.sysroot.as_ref()).map(|p| &**p)
+    }
+
+    /// Returns the CHERI LLVM toolchain configured for this `target`, if defined
+    fn cheri_sdk(&self, target: TargetSelection) -> Option<&Path> {
+        self.config.target_config.get(&target).and_then(|t| t.cheri_sdk.as_ref()).map(|p| &**p)
+    }
+
+    /// Returns the spec of `target`, or `None` if no compiler we have knows it yet.
+    ///
+    /// The snapshot compiler doesn't know targets added in this tree, so those are looked up with
+    /// the stage1 compiler once it has been built. Everything that compiles for a target runs
+    /// after that.
+    fn target_spec(&self, target: TargetSelection) -> Option<TargetSpec> {
+        if let Some(spec) = self.target_specs.borrow().get(&target) {
+            return spec.clone();
+        }
+        let stage1_bin = self.out.join(&*self.build.triple).join("stage1").join("bin");
+        let stage1_rustc = stage1_bin.join(exe("rustc", self.build));
+        let spec = TargetSpec::query(&self.initial_rustc, target).or_else(|| {
+            if stage1_rustc.exists() { TargetSpec::query(&stage1_rustc, target) } else { None }
+        });
+        // Don't remember that the spec is missing, the stage1 compiler may not be built yet.
+        if spec.is_some() {
+            self.target_specs.borrow_mut().insert(target, spec.clone());
+        }
+        spec
+    }
+
+    /// Returns `true` if `target` is a CHERI pure-capability target.
+    fn is_purecap(&self, target: TargetSelection) -> bool {
+        self.target_spec(target).map_or(false, |spec| spec.is_purecap())
+    }
+
+    /// Returns the extra C compiler flags a CHERI pure-capability `target` needs to select its
+    /// ABI and find its sysroot, or nothing for any other target.
+    fn purecap_cflags(&self, target: TargetSelection) -> Vec<String> {
+        let spec = match self.target_spec(target) {
+            Some(spec) if spec.is_purecap() => spec,
+            _ => return Vec::new(),
+        };
+        let mut flags = spec.purecap_cflags();
+        match self.c_sysroot(target) {
+            Some(sysroot) => flags.push(format!("--sysroot={}", sysroot.display())),
+            // Hosted targets can't fall back to the host's headers and libraries.
+            None if spec.os != "none" => panic!(
+                "when targeting {} the target.{}.sysroot option must be specified in config.toml",
+                target.triple, target.triple
+            ),
+            None => {}
+        }
+        flags
+    }
+
+    /// Returns the CheriBSD disk image passed with `--cheri-qemu`, if this `target` runs on
+    /// CheriBSD.
+    fn cheri_qemu(&self, target: TargetSelection) -> Option<&Path> {
+        if target.contains("freebsd") && self.is_purecap(target) {
+            self.config.cmd.cheri_qemu()
+        } else {
+            None
+        }
+    }
+
     /// Returns `true` if this is a no-std `target`, if defined
     fn no_std(&self, target: TargetSelection) -> Option<bool> {
         self.config.target_config.get(&target).map(|t| t.no_std)
@@ -1221,6 +1276,7 @@ fn no_std(&self, target: TargetSelection) -> Option<bool> {
     /// and `remote-test-server` binaries.
     fn remote_tested(&self, target: TargetSelection) -> bool {
         self.qemu_rootfs(target).is_some()
+            || self.cheri_qemu(target).is_some()
             || target.contains("android")
             || env::var_os("TEST_DEVICE_ADDR").is_some()
     }
@@ -1666,9 +1722,27 @@ fn chmod(path: &Path, perms: u32) {
 #[cfg(windows)]
 fn chmod(_path: &Path, _perms: u32) {}
 
+/// Shuts down an emulator that `remote-test-client` booted to run tests in when dropped.
+pub(crate) struct EmulatorGuard {
+    pub(crate) client: PathBuf,
+    pub(crate) tmpdir: PathBuf,
+}
+
+impl Drop for EmulatorGuard {
+    fn drop(&mut self) {
+        // This may run while exiting because of some other failure, which is the one to report.
+        let _ = Command::new(&self.client).arg("stop-emulator").arg(&self.tmpdir).status();
+    }
+}
+
+/// Emulators that are still running, shut down when bootstrap finishes or exits early.
+pub(crate) static EMULATORS: Mutex<Vec<EmulatorGuard>> = Mutex::new(Vec::new());
+
 /// If code is not 0 (successful exit status), exit status is 101 (rust's default error code.)
 /// If the test is running and code is an error code, it will cause a panic.
 fn detail_exit(code: i32) -> ! {
+    // Exiting skips destructors, so shut down emulators ourselves rather than leaving them running.
+    EMULATORS.lock().unwrap().clear();
     // if in test and code is an error code, panic with status code provided
     if cfg!(test) && code != 0 {
         panic!("status code: {}", code);
@@ -190,6 +190,29 @@ pub fn check(build: &mut Build) {
             }
         }
 
+        // Hosted CHERI pure-capability targets can't fall back to the host's headers and
+        // libraries, so make sure we know where theirs are. If the snapshot compiler doesn't know
+        // the target yet, `Build::purecap_cflags` checks this once the stage1 compiler does.
+        if build.is_purecap(*target) && build.no_std(*target) == Some(false) {
+            match build.c_sysroot(*target) {
+                Some(sysroot) => {
+                    if fs::metadata(sysroot).is_err() {
+                        panic!("couldn't find sysroot for {}: {}", target, sysroot.display());
+                    }
+                }
+                None => panic!(
+                    "when targeting {} the target.{}.sysroot option must be specified in \
+                            config.toml",
+                    target.triple, target.triple
+                ),
+            }
+        }
+        if let Some(sdk) = build.cheri_sdk(*target) {
+            if fs::metadata(sdk).is_err() {
+                panic!("couldn't find CHERI SDK for {}: {}", target, sdk.display());
+            }
+        }
+
         // Make sure musl-root is valid
         if target.contains("musl") {
             // If this is a native target (host is also musl) and no musl-root is given,
@@ -2334,7 +2334,25 @@ fn run(self, builder: &Builder<'_>) {
         if let Some(rootfs) = builder.qemu_rootfs(target) {
             cmd.arg(rootfs);
         }
+        if let Some(image) = builder.cheri_qemu(target) {
+            // CheriBSD for other architectures can't be booted from a disk image alone.
+            if !target.starts_with("morello") {
+                eprintln!("error: `--cheri-qemu` only supports Morello targets, not {}", target);
+                crate::detail_exit(1);
+            }
+            cmd.arg(image);
+            // CHERI QEMU is built as part of the SDK, prefer it over any QEMU on the `PATH`.
+            if let Some(sdk) = builder.cheri_sdk(target) {
+                let path = env::var_os("PATH").unwrap_or_default();
+                let paths = iter::once(sdk.join("bin")).chain(env::split_paths(&path));
+                cmd.env("PATH", t!(env::join_paths(paths)));
+            }
+        }
         builder.run(&mut cmd);
+        if builder.cheri_qemu(target).is_some() && !builder.config.dry_run {
+            let guard = crate::EmulatorGuard { client: tool.clone(), tmpdir: builder.tempdir() };
+            crate::EMULATORS.lock().unwrap().push(guard);
+        }
 
         // Push all our dylibs to the emulator
         for f in t!(builder.sysroot_libdir(compiler, target).read_dir()) {
@@ -11,6 +11,8 @@
 use std::str;
 use std::time::{Instant, SystemTime, UNIX_EPOCH};
 
+use serde::Deserialize;
+
 use crate::builder::Builder;
 use crate::config::{Config, TargetSelection};
 use crate::OnceCell;
@@ -309,6 +311,86 @@ pub fn use_host_linker(target: TargetSelection) -> bool {
         || target.contains("switch"))
 }
 
+/// The parts of a target spec that bootstrap needs, as printed by
+/// `rustc --print target-spec-json`.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct TargetSpec {
+    pub llvm_target: String,
+    pub arch: String,
+    pub target_pointer_width: String,
+    #[serde(default = "default_os")]
+    pub os: String,
+    #[serde(default)]
+    pub features: String,
+    #[serde(default)]
+    pub llvm_abiname: String,
+    #[serde(default)]
+    pub target_pointer_type_width: Option<u32>,
+    #[serde(default)]
+    pub self_init_caprelocs: bool,
+}
+
+fn default_os() -> String {
+    "none".to_owned()
+}
+
+impl TargetSpec {
+    /// Asks `rustc` for the spec of `target`, returning `None` if it doesn't know the target.
+    pub fn query(rustc: &Path, target: TargetSelection) -> Option<TargetSpec> {
+        let output = Command::new(rustc)
+            .env("RUSTC_BOOTSTRAP", "1")
+            .args(&["-Z", "unstable-options", "--print", "target-spec-json", "--target"])
+            .arg(target.rustc_target_arg())
+            .stderr(Stdio::null())
+            .output()
+            .ok()?;
+        if !output.status.success() {
+            return None;
+        }
+        Some(t!(serde_json::from_slice(&output.stdout)))
+    }
+
+    /// Returns `true` for CHERI pure-capability targets, where pointers are capabilities.
+    pub fn is_purecap(&self) -> bool {
+        self.target_pointer_type_width.is_some()
+    }
+
+    /// Flags telling clang to generate and link code for the C ABI of a CHERI pure-capability
+    /// target, or nothing for any other target.
+    pub fn purecap_cflags(&self) -> Vec<String> {
+        if !self.is_purecap() {
+            return Vec::new();
+        }
+        vec![
+            format!("--target={}", self.llvm_target),
+            format!("-march={}", self.march()),
+            format!("-mabi={}", self.llvm_abiname),
+        ]
+    }
+
+    /// The `-march` clang selects the target's instruction set with: the architecture itself for
+    /// Morello, and the ISA string spelled out by the target features for CHERI-RISC-V.
+    fn march(&self) -> String {
+        if !self.arch.starts_with("riscv") {
+            return self.arch.clone();
+        }
+        let enabled: Vec<&str> =
+            self.features.split(',').filter_map(|f| f.strip_prefix('+')).collect();
+        let base = if enabled.contains(&"e") { "e" } else { "i" };
+        let mut march = format!("rv{}{}", self.target_pointer_width, base);
+        for ext in ["m", "a", "f", "d", "c"] {
+            if enabled.contains(&ext) {
+                march.push_str(ext);
+            }
+        }
+        if enabled.contains(&"xcheri") {
+            march.push_str("xcheri");
+        }
+        march
+    }
+}
+
 pub fn is_valid_test_suite_arg<'a, P: AsRef<Path>>(
     path: &'a Path,
     suite_path: P,
@@ -0,0 +1,98 @@
+import re
+
+####################################################################################################
+# Helpers shared by the GDB and LLDB pretty-printers for CHERI targets.
+#
+# On CHERI purecap targets every pointer is a capability: an address plus bounds, permissions,
+# an object type and a validity tag held out of band. CHERI-aware debuggers print capabilities in
+# the same style as CheriBSD, e.g.
+#
+#     0x40a01000 [rwRW,0x40a01000-0x40a01040]
+#     0x40a01000 [rwRW,0x40a01000-0x40a01040] (invalid)
+#     0x40210f3c [rxR,0x40200000-0x40280000] (sentry)
+#
+# Rather than decoding the compressed bounds ourselves (the encoding differs between Morello and
+# CHERI-RISC-V, and the tag is not part of the in-memory bytes), we parse the debugger's own
+# rendering of the pointer. On targets without capabilities no metadata is printed, parsing fails
+# and the pretty-printers keep their usual output.
+####################################################################################################
+
+CAPABILITY_REGEX = re.compile(
+    r"(?P<address>0x[0-9a-fA-F]+)\s*"
+    r"\[(?P<perms>[^,\]]*),(?P<base>0x[0-9a-fA-F]+)-(?P<top>0x[0-9a-fA-F]+)\]"
+    r"(?P<attributes>(\s*\([^)]*\))*)"
+)
+CAPABILITY_ATTRIBUTE_REGEX = re.compile(r"\(([^)]*)\)")
+
+
+class Capability(object):
+    def __init__(self, address, perms, base, top, attributes):
+        # type: (int, str, int, int, list) -> Capability
+        self.address = address
+        self.perms = perms
+        self.base = base
+        self.top = top
+        self.attributes = attributes
+
+    @property
+    def tag(self):
+        # type: () -> bool
+        return "invalid" not in self.attributes
+
+    @property
+    def sealed(self):
+        # type: () -> bool
+        return any(attr.startswith("sealed") or attr == "sentry" for attr in self.attributes)
+
+    def remaining(self):
+        # type: () -> int
+        """Returns the number of bytes accessible from the address to the top of the bounds."""
+        if self.address < self.base or self.address > self.top:
+            return 0
+        return self.top - self.address
+
+    def covers(self, size):
+        # type: (int) -> bool
+        """Returns whether `size` bytes starting at the address are within the bounds."""
+        return self.base <= self.address and self.address + size <= self.top
+
+    def problems(self, size):
+        # type: (int) -> list
+        """Returns a list of reasons why `size` bytes can't be accessed through this capability."""
+        problems = []
+        if not self.tag:
+            problems.append("tag is clear")
+        if self.sealed:
+            problems.append("capability is sealed")
+        if not self.covers(size):
+            problems.append("bounds cover {} bytes but {} are required".format(
+                self.remaining(), size))
+        return problems
+
+    def metadata(self):
+        # type: () -> str
+        """Formats everything but the address, e.g. `[rwRW,0x1000-0x1040] (invalid)`."""
+        text = "[{},{:#x}-{:#x}]".format(self.perms, self.base, self.top)
+        for attr in self.attributes:
+            text += " ({})".format(attr)
+        return text
+
+    def __str__(self):
+        return "{:#x} {}".format(self.address, self.metadata())
+
+
+def parse_capability(text):
+    # type: (str) -> Capability
+    """Parses a capability printed by a CHERI-aware debugger, returning None if it has no bounds."""
+    match = CAPABILITY_REGEX.search(text)
+    if match is None:
+        return None
+
+    attributes = CAPABILITY_ATTRIBUTE_REGEX.findall(match.group("attributes"))
+    return Capability(
+        int(match.group("address"), 16),
+        match.group("perms"),
+        int(match.group("base"), 16),
+        int(match.group("top"), 16),
+        [attr.strip() for attr in attributes],
+    )
@@ -23,6 +23,8 @@ def classify_rust_type(type):
         return classify_struct(type.tag, type.fields())
     if type_class == gdb.TYPE_CODE_UNION:
         return classify_union(type.fields())
+    if type_class == gdb.TYPE_CODE_PTR:
+        return classify_pointer(type.name)
 
     return RustType.OTHER
 
@@ -92,4 +94,93 @@ def lookup(valobj):
     if rust_type == RustType.STD_NONZERO_NUMBER:
         return StdNonZeroNumberProvider(valobj)
 
+    if rust_type == RustType.STD_BOX:
+        # A `Box` is printed like any other pointer unless it carries capability metadata.
+        capability = capability_of(valobj)
+        if capability is not None:
+            return StdBoxProvider(valobj, capability)
+
+    return None
+
+
+# Returns the data pointer and the number of bytes a Rust value claims to own or borrow through
+# it, or None if the value isn't one of the pointer-like std types.
+def pointer_and_extent(valobj):
+    rust_type = classify_rust_type(valobj.type)
+
+    if rust_type == RustType.STD_STRING:
+        vec = valobj["vec"]
+        return unwrap_unique_or_non_null(vec["buf"]["ptr"]), int(vec["len"])
+    if rust_type == RustType.STD_VEC:
+        data_ptr = unwrap_unique_or_non_null(valobj["buf"]["ptr"])
+        return data_ptr, int(valobj["len"]) * data_ptr.type.target().sizeof
+    if rust_type == RustType.STD_STR:
+        return valobj["data_ptr"], int(valobj["length"])
+    if rust_type == RustType.STD_SLICE:
+        data_ptr = valobj["data_ptr"]
+        return data_ptr, int(valobj["length"]) * data_ptr.type.target().sizeof
+    if rust_type in (RustType.STD_RC, RustType.STD_ARC):
+        ptr = unwrap_unique_or_non_null(valobj["ptr"])
+        return ptr, ptr.type.target().sizeof
+    if rust_type == RustType.STD_BOX:
+        return valobj, valobj.type.target().sizeof
+
     return None
+
+
+class CheckCapabilityBoundsCommand(gdb.Command):
+    """Flag Rust values whose capability bounds are narrower than their length.
+
+Usage: rust-cheri-bounds [EXPRESSION]
+
+Checks `String`, `Vec`, `&str`, slices, `Box`, `Rc` and `Arc` values on CHERI targets, reporting
+any whose data capability is untagged, sealed, or doesn't cover the bytes implied by the value's
+Rust length. Without an expression, every local variable in the selected frame is checked."""
+
+    def __init__(self):
+        super(CheckCapabilityBoundsCommand, self).__init__("rust-cheri-bounds", gdb.COMMAND_DATA)
+
+    def invoke(self, argument, from_tty):
+        if argument:
+            values = [(argument, gdb.parse_and_eval(argument))]
+        else:
+            values = self.frame_locals(gdb.selected_frame())
+
+        checked = 0
+        flagged = 0
+        for name, valobj in values:
+            extent = pointer_and_extent(valobj)
+            if extent is None:
+                continue
+            data_ptr, size = extent
+            capability = capability_of(data_ptr)
+            if capability is None:
+                continue
+
+            checked += 1
+            # Empty values may legitimately hold a dangling, untagged pointer.
+            problems = capability.problems(size) if size > 0 else []
+            if problems:
+                flagged += 1
+                gdb.write("{}: {} ({})\n".format(name, capability, "; ".join(problems)))
+            elif argument:
+                gdb.write("{}: {} covers {} bytes\n".format(name, capability, size))
+
+        if checked == 0:
+            gdb.write("no capabilities to check\n")
+        elif not argument and flagged == 0:
+            gdb.write("{} value(s) checked, all within bounds\n".format(checked))
+
+    @staticmethod
+    def frame_locals(frame):
+        block = frame.block()
+        while block is not None and not block.is_global:
+            for symbol in block:
+                if symbol.is_variable or symbol.is_argument:
+                    yield symbol.print_name, symbol.value(frame)
+            if block.function is not None:
+                break
+            block = block.superblock
+
+
+CheckCapabilityBoundsCommand()
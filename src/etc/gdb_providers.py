@@ -2,6 +2,8 @@ from sys import version_info
 
 import gdb
 
+from cheri_capability import parse_capability
+
 if version_info[0] >= 3:
     xrange = range
 
@@ -18,6 +20,25 @@ def unwrap_unique_or_non_null(unique_or_nonnull):
     return ptr if ptr.type.code == gdb.TYPE_CODE_PTR else ptr[ptr.type.fields()[0]]
 
 
+def capability_of(ptr):
+    # On CHERI targets pointers are capabilities, which a CHERI-aware GDB prints together with
+    # their bounds, permissions and tag. Returns None for plain integer pointers.
+    try:
+        if hasattr(ptr, "format_string"):
+            text = ptr.format_string(raw=True, symbols=False)
+        else:
+            # BACKCOMPAT: gdb 9.1 added `Value.format_string`
+            text = str(ptr)
+    except gdb.error:
+        return None
+    return parse_capability(text)
+
+
+def with_capability(text, ptr):
+    capability = capability_of(ptr)
+    return text if capability is None else "{} {}".format(text, capability)
+
+
 class EnumProvider:
     def __init__(self, valobj):
         content = valobj[valobj.type.fields()[0]]
@@ -48,13 +69,18 @@ class StdStringProvider:
         vec = valobj["vec"]
         self.length = int(vec["len"])
         self.data_ptr = unwrap_unique_or_non_null(vec["buf"]["ptr"])
+        self.capability = capability_of(self.data_ptr)
 
     def to_string(self):
+        if self.capability is not None:
+            return '"{}" {}'.format(
+                self.data_ptr.string(encoding="utf-8", errors="replace", length=self.length),
+                self.capability,
+            )
         return self.data_ptr.lazy_string(encoding="utf-8", length=self.length)
 
-    @staticmethod
-    def display_hint():
-        return "string"
+    def display_hint(self):
+        return "string" if self.capability is None else None
 
 
 class StdOsStringProvider:
@@ -110,7 +136,7 @@ class StdSliceProvider:
         self.data_ptr = valobj["data_ptr"]
 
     def to_string(self):
-        return "{}(size={})".format(self.valobj.type, self.length)
+        return with_capability("{}(size={})".format(self.valobj.type, self.length), self.data_ptr)
 
     def children(self):
         return _enumerate_array_elements(
@@ -128,7 +154,7 @@ class StdVecProvider:
         self.data_ptr = unwrap_unique_or_non_null(valobj["buf"]["ptr"])
 
     def to_string(self):
-        return "Vec(size={})".format(self.length)
+        return with_capability("Vec(size={})".format(self.length), self.data_ptr)
 
     def children(self):
         return _enumerate_array_elements(
@@ -176,9 +202,10 @@ class StdRcProvider:
 
     def to_string(self):
         if self.is_atomic:
-            return "Arc(strong={}, weak={})".format(int(self.strong), int(self.weak))
+            text = "Arc(strong={}, weak={})".format(int(self.strong), int(self.weak))
         else:
-            return "Rc(strong={}, weak={})".format(int(self.strong), int(self.weak))
+            text = "Rc(strong={}, weak={})".format(int(self.strong), int(self.weak))
+        return with_capability(text, self.ptr)
 
     def children(self):
         yield "value", self.value
@@ -186,6 +213,19 @@ class StdRcProvider:
         yield "weak", self.weak
 
 
+class StdBoxProvider:
+    def __init__(self, valobj, capability):
+        self.valobj = valobj
+        self.capability = capability
+
+    def to_string(self):
+        return str(self.capability)
+
+    def children(self):
+        if self.capability.tag:
+            yield "*", self.valobj.dereference()
+
+
 class StdCellProvider:
     def __init__(self, valobj):
         self.value = valobj["value"]["value"]
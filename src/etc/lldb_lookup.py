@@ -1,7 +1,7 @@
 import lldb
 
 from lldb_providers import *
-from rust_types import RustType, classify_struct, classify_union
+from rust_types import RustType, classify_pointer, classify_struct, classify_union
 
 
 # BACKCOMPAT: rust 1.35
@@ -15,6 +15,8 @@ def classify_rust_type(type):
         return classify_struct(type.name, type.fields)
     if type_class == lldb.eTypeClassUnion:
         return classify_union(type.fields)
+    if type_class == lldb.eTypeClassPointer:
+        return classify_pointer(type.name)
 
     return RustType.OTHER
 
@@ -32,11 +34,11 @@ def summary_lookup(valobj, dict):
         return StdStrSummaryProvider(valobj, dict)
 
     if rust_type == RustType.STD_VEC:
-        return SizeSummaryProvider(valobj, dict)
+        return StdVecSummaryProvider(valobj, dict)
     if rust_type == RustType.STD_VEC_DEQUE:
         return SizeSummaryProvider(valobj, dict)
     if rust_type == RustType.STD_SLICE:
-        return SizeSummaryProvider(valobj, dict)
+        return StdSliceSummaryProvider(valobj, dict)
 
     if rust_type == RustType.STD_HASH_MAP:
         return SizeSummaryProvider(valobj, dict)
@@ -58,6 +60,9 @@ def summary_lookup(valobj, dict):
     if rust_type == RustType.STD_NONZERO_NUMBER:
         return StdNonZeroNumberSummaryProvider(valobj, dict)
 
+    if rust_type == RustType.STD_BOX:
+        return StdBoxSummaryProvider(valobj, dict)
+
     return ""
 
 
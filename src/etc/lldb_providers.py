@@ -3,6 +3,8 @@ import sys
 from lldb import SBValue, SBData, SBError, eBasicTypeLong, eBasicTypeUnsignedLong, \
     eBasicTypeUnsignedChar
 
+from cheri_capability import parse_capability
+
 # from lldb.formatters import Logger
 
 ####################################################################################################
@@ -69,6 +71,19 @@ def unwrap_unique_or_non_null(unique_or_nonnull):
     return ptr if ptr.TypeIsPointerType() else ptr.GetChildAtIndex(0)
 
 
+def capability_of(ptr):
+    # type: (SBValue) -> Capability
+    # On CHERI targets pointers are capabilities, which a CHERI-aware LLDB prints together with
+    # their bounds, permissions and tag. Returns None for plain integer pointers.
+    return parse_capability(ptr.GetValue() or "")
+
+
+def with_capability(summary, ptr):
+    # type: (str, SBValue) -> str
+    capability = capability_of(ptr)
+    return summary if capability is None else "{} {}".format(summary, capability.metadata())
+
+
 class DefaultSynthteticProvider:
     def __init__(self, valobj, dict):
         # type: (SBValue, dict) -> DefaultSynthteticProvider
@@ -130,6 +145,25 @@ def SizeSummaryProvider(valobj, dict):
     return 'size=' + str(valobj.GetNumChildren())
 
 
+def StdVecSummaryProvider(valobj, dict):
+    # type: (SBValue, dict) -> str
+    buf = valobj.GetNonSyntheticValue().GetChildMemberWithName("buf")
+    data_ptr = unwrap_unique_or_non_null(buf.GetChildMemberWithName("ptr"))
+    return with_capability(SizeSummaryProvider(valobj, dict), data_ptr)
+
+
+def StdSliceSummaryProvider(valobj, dict):
+    # type: (SBValue, dict) -> str
+    data_ptr = valobj.GetNonSyntheticValue().GetChildMemberWithName("data_ptr")
+    return with_capability(SizeSummaryProvider(valobj, dict), data_ptr)
+
+
+def StdBoxSummaryProvider(valobj, dict):
+    # type: (SBValue, dict) -> str
+    capability = capability_of(valobj.GetNonSyntheticValue())
+    return "" if capability is None else capability.metadata()
+
+
 def vec_to_string(vec):
     length = vec.GetNumChildren()
     chars = [vec.GetChildAtIndex(i).GetValueAsUnsigned() for i in range(length)]
@@ -141,7 +175,9 @@ def StdStringSummaryProvider(valobj, dict):
     # logger = Logger.Logger()
     # logger >> "[StdStringSummaryProvider] for " + str(valobj.GetName())
     vec = valobj.GetChildAtIndex(0)
-    return '"%s"' % vec_to_string(vec)
+    buf = vec.GetNonSyntheticValue().GetChildMemberWithName("buf")
+    data_ptr = unwrap_unique_or_non_null(buf.GetChildMemberWithName("ptr"))
+    return with_capability('"%s"' % vec_to_string(vec), data_ptr)
 
 
 def StdOsStringSummaryProvider(valobj, dict):
@@ -160,17 +196,16 @@ def StdStrSummaryProvider(valobj, dict):
     # logger >> "[StdStrSummaryProvider] for " + str(valobj.GetName())
 
     length = valobj.GetChildMemberWithName("length").GetValueAsUnsigned()
-    if length == 0:
-        return '""'
-
     data_ptr = valobj.GetChildMemberWithName("data_ptr")
+    if length == 0:
+        return with_capability('""', data_ptr)
 
     start = data_ptr.GetValueAsUnsigned()
     error = SBError()
     process = data_ptr.GetProcess()
     data = process.ReadMemory(start, length, error)
     data = data.decode(encoding='UTF-8') if PY3 else data
-    return '"%s"' % data
+    return with_capability('"%s"' % data, data_ptr)
 
 
 class StructSyntheticProvider:
@@ -580,7 +615,8 @@ def StdRcSummaryProvider(valobj, dict):
     # type: (SBValue, dict) -> str
     strong = valobj.GetChildMemberWithName("strong").GetValueAsUnsigned()
     weak = valobj.GetChildMemberWithName("weak").GetValueAsUnsigned()
-    return "strong={}, weak={}".format(strong, weak)
+    ptr = unwrap_unique_or_non_null(valobj.GetNonSyntheticValue().GetChildMemberWithName("ptr"))
+    return with_capability("strong={}, weak={}".format(strong, weak), ptr)
 
 
 class StdRcSyntheticProvider:
@@ -32,6 +32,7 @@ class RustType(object):
     STD_REF_MUT = "StdRefMut"
     STD_REF_CELL = "StdRefCell"
     STD_NONZERO_NUMBER = "StdNonZeroNumber"
+    STD_BOX = "StdBox"
 
 
 STD_STRING_REGEX = re.compile(r"^(alloc::(\w+::)+)String$")
@@ -51,6 +52,7 @@ STD_REF_REGEX = re.compile(r"^(core::(\w+::)+)Ref<.+>$")
 STD_REF_MUT_REGEX = re.compile(r"^(core::(\w+::)+)RefMut<.+>$")
 STD_REF_CELL_REGEX = re.compile(r"^(core::(\w+::)+)RefCell<.+>$")
 STD_NONZERO_NUMBER_REGEX = re.compile(r"^core::num::([a-z_]+::)*NonZero.+$")
+STD_BOX_REGEX = re.compile(r"^(alloc::(\w+::)+)Box<.+>$")
 
 TUPLE_ITEM_REGEX = re.compile(r"__\d+$")
 
@@ -114,3 +116,10 @@ def classify_union(fields):
         return RustType.COMPRESSED_ENUM
     else:
         return RustType.REGULAR_UNION
+
+
+def classify_pointer(name):
+    if name is not None and STD_BOX_REGEX.match(name):
+        return RustType.STD_BOX
+
+    return RustType.OTHER
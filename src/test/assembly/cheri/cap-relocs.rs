@@ -0,0 +1,52 @@
+// Checks that statics initialized with pointers are emitted as capability initializers, which the
+// linker turns into `__cap_relocs` entries, rather than as plain integer addresses.
+
+// assembly-output: emit-asm
+// revisions: morello riscv32 riscv64
+// [morello]compile-flags: --target morello-unknown-linux-purecap
+// [morello]needs-llvm-components: aarch64
+// [riscv32]compile-flags: --target riscv32imcxcheri-unknown-none-purecap
+// [riscv32]needs-llvm-components: riscv
+// [riscv64]compile-flags: --target riscv64imafdcxcheri-unknown-freebsd-purecap
+// [riscv64]needs-llvm-components: riscv
+
+#![feature(no_core, lang_items)]
+#![no_std]
+#![no_core]
+#![crate_type = "lib"]
+
+#[lang = "sized"]
+trait Sized {}
+#[lang = "sync"]
+trait Sync {}
+
+impl Sync for u8 {}
+impl<T: ?Sized + Sync> Sync for &T {}
+impl<T: ?Sized> Sync for fn() -> T {}
+
+#[no_mangle]
+pub static BYTE: u8 = 42;
+
+// CHECK-LABEL: REF:
+// CHECK-NEXT: .chericap BYTE
+#[no_mangle]
+pub static REF: &u8 = &BYTE;
+
+// CHECK-LABEL: ARRAY:
+// CHECK-NEXT: .chericap BYTE
+// CHECK-NEXT: .chericap BYTE
+#[no_mangle]
+pub static ARRAY: [&u8; 2] = [&BYTE, &BYTE];
+
+// CHECK-LABEL: FN:
+// CHECK-NEXT: .chericap {{.*}}function
+#[no_mangle]
+pub static FN: fn() -> u8 = function;
+
+#[no_mangle]
+pub fn function() -> u8 {
+    42
+}
+
+// CHECK-NOT: .xword BYTE
+// CHECK-NOT: .word BYTE
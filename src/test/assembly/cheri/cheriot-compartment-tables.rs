@@ -0,0 +1,40 @@
+// Checks that CHERIoT compartment exports and the imports that are called end up in the
+// export and import tables that the CHERIoT linker resolves against each other.
+
+// assembly-output: emit-asm
+// compile-flags: --target riscv32cheriot-unknown-none -C codegen-units=1
+// needs-llvm-components: riscv
+
+#![feature(no_core, lang_items, cheriot_compartment)]
+#![no_core]
+#![crate_type = "rlib"]
+
+#[lang = "sized"]
+trait Sized {}
+#[lang = "copy"]
+trait Copy {}
+
+impl Copy for i32 {}
+
+extern "cheriot-cross-compartment" {
+    #[cheriot_compartment = "allocator"]
+    fn heap_quota() -> i32;
+}
+
+#[no_mangle]
+#[cheriot_compartment = "example"]
+pub extern "cheriot-cross-compartment" fn entry(x: i32) -> i32 {
+    x
+}
+
+// CHECK-LABEL: call_import:
+// CHECK: __import_allocator_heap_quota
+#[no_mangle]
+pub fn call_import() -> i32 {
+    unsafe { heap_quota() }
+}
+
+// CHECK-DAG: .section .compartment_imports
+// CHECK-DAG: __import_allocator_heap_quota:
+// CHECK-DAG: .section .compartment_exports
+// CHECK-DAG: __export_example_entry:
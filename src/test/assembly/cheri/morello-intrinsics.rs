@@ -0,0 +1,141 @@
+// Checks the instructions emitted for `core::arch::aarch64::morello`.
+//
+// Some instructions are wrapped in naked functions, which would be compiled into libcore rather
+// than this crate. The module is therefore included from source, so that the wrappers show up
+// here too; with a single codegen unit they come first, in the order they are defined in.
+
+// assembly-output: emit-asm
+// compile-flags: -O -C codegen-units=1
+// only-morello+c64
+
+#![feature(staged_api, stdarch_morello, naked_functions, abi_unadjusted, link_llvm_intrinsics)]
+#![unstable(feature = "stdarch_morello", issue = "none")]
+#![crate_type = "rlib"]
+
+mod arch {
+    pub use core::arch::asm;
+}
+
+#[path = "../../../../library/core/src/arch/aarch64/morello.rs"]
+mod morello;
+
+use morello::*;
+
+// CHECK-LABEL: {{.*}}ddc_set{{.*}}:
+// CHECK: msr DDC, c0
+// CHECK-NEXT: ret
+
+// CHECK-LABEL: {{.*}}rddc_get{{.*}}:
+// CHECK: mrs c0, RDDC_EL0
+// CHECK-NEXT: ret
+
+// CHECK-LABEL: {{.*}}chktgd{{.*}}:
+// CHECK: chktgd c0
+// CHECK-NEXT: cset w0, {{cs|hs}}
+// CHECK-NEXT: ret
+
+// CHECK-LABEL: cctlr_el0_read:
+// CHECK: mrs x{{[0-9]+}}, CCTLR_EL0
+#[no_mangle]
+pub fn cctlr_el0_read() -> u64 {
+    __cctlr_el0_read()
+}
+
+// CHECK-LABEL: cctlr_el0_write:
+// CHECK: msr CCTLR_EL0, x0
+// CHECK-NEXT: isb
+#[no_mangle]
+pub unsafe fn cctlr_el0_write(value: u64) {
+    __cctlr_el0_write(value)
+}
+
+// CHECK-LABEL: ddc_read:
+// CHECK: mrs c0, DDC
+#[no_mangle]
+pub fn ddc_read() -> *mut u8 {
+    __ddc_read()
+}
+
+// CHECK-LABEL: ddc_write:
+// CHECK: {{bl?}} {{.*}}ddc_set
+#[no_mangle]
+pub unsafe fn ddc_write(ddc: *mut u8) {
+    __ddc_write(ddc)
+}
+
+// CHECK-LABEL: rddc_read:
+// CHECK: {{bl?}} {{.*}}rddc_get
+#[no_mangle]
+pub fn rddc_read() -> *mut u8 {
+    __rddc_read()
+}
+
+// CHECK-LABEL: chktgd:
+// CHECK: {{bl?}} {{.*}}chktgd
+#[no_mangle]
+pub fn chktgd(cap: *const u8) -> bool {
+    __chktgd(cap)
+}
+
+// CHECK-LABEL: gcvalue:
+// CHECK: gcvalue x0, c0
+#[no_mangle]
+pub fn gcvalue(cap: *const u8) -> usize {
+    __gcvalue(cap)
+}
+
+// CHECK-LABEL: scbndse:
+// CHECK: scbndse c0, c0, x1
+#[no_mangle]
+pub fn scbndse(cap: *const u8, length: usize) -> *const u8 {
+    __scbndse(cap, length)
+}
+
+// CHECK-LABEL: clrperm:
+// CHECK: clrperm c0, c0, x1
+#[no_mangle]
+pub fn clrperm(cap: *const u8, perms: usize) -> *const u8 {
+    __clrperm(cap, perms)
+}
+
+// CHECK-LABEL: seal:
+// CHECK: seal c0, c0, c1
+#[no_mangle]
+pub fn seal(cap: *const u8, sealer: *const u8) -> *const u8 {
+    __seal(cap, sealer)
+}
+
+// CHECK-LABEL: build:
+// CHECK: build c0, c0, c1
+#[no_mangle]
+pub fn build(bits: *const u8, auth: *const u8) -> *const u8 {
+    __build(bits, auth)
+}
+
+// CHECK-LABEL: cvtd:
+// CHECK: cvtd c0, x0
+#[no_mangle]
+pub fn cvtd(addr: usize) -> *mut u8 {
+    __cvtd(addr)
+}
+
+// CHECK-LABEL: cvtd_to_addr:
+// CHECK: cvtd x0, c0
+#[no_mangle]
+pub fn cvtd_to_addr(cap: *const u8) -> usize {
+    __cvtd_to_addr(cap)
+}
+
+// CHECK-LABEL: cvtp:
+// CHECK: cvtp c0, x0
+#[no_mangle]
+pub fn cvtp(addr: usize) -> *const u8 {
+    __cvtp(addr)
+}
+
+// CHECK-LABEL: cvtp_to_addr:
+// CHECK: cvtp x0, c0
+#[no_mangle]
+pub fn cvtp_to_addr(cap: *const u8) -> usize {
+    __cvtp_to_addr(cap)
+}
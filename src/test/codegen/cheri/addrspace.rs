@@ -0,0 +1,47 @@
+// Checks that pointers, references and the places holding them live in the capability address
+// space on a pure-capability target.
+
+// compile-flags: -O --target morello-unknown-linux-purecap --crate-type=rlib
+// needs-llvm-components: aarch64
+
+#![feature(no_core, lang_items)]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+#[lang = "copy"]
+trait Copy {}
+#[lang = "sync"]
+trait Sync {}
+
+impl Copy for u8 {}
+impl<T: ?Sized> Copy for *const T {}
+impl Sync for u8 {}
+impl<T: ?Sized + Sync> Sync for &T {}
+
+pub static BYTE: u8 = 42;
+
+// CHECK: @REF = {{.*}}addrspace(200){{.*}} @BYTE
+#[no_mangle]
+pub static REF: &u8 = &BYTE;
+
+// CHECK-LABEL: define {{.*}}i8 addrspace(200)* @identity(i8 addrspace(200)* {{.*}}%p)
+#[no_mangle]
+pub fn identity(p: *const u8) -> *const u8 {
+    p
+}
+
+// CHECK-LABEL: @load
+// CHECK: load i8, i8 addrspace(200)* %x
+#[no_mangle]
+pub fn load(x: &u8) -> u8 {
+    *x
+}
+
+// CHECK-LABEL: @pair
+// CHECK: %[[FIRST:.+]] = load i8 addrspace(200)*, i8 addrspace(200)* addrspace(200)*
+// CHECK: ret i8 addrspace(200)* %[[FIRST]]
+#[no_mangle]
+pub fn pair(p: &[*const u8; 2]) -> *const u8 {
+    p[0]
+}
@@ -0,0 +1,32 @@
+// Checks that calls through a sealed pair use the domain-crossing convention, with the code
+// and data capabilities as the first two arguments. The convention is checked by the name LLVM
+// prints for it, like in `cheriot-compartment.rs`, which also pins the number `CallConv` passes.
+
+// revisions: morello riscv64
+// compile-flags: -O --crate-type=rlib
+// [morello] compile-flags: --target morello-unknown-none-purecap
+// [morello] needs-llvm-components: aarch64
+// [riscv64] compile-flags: --target riscv64imafdcxcheri-unknown-freebsd-purecap
+// [riscv64] needs-llvm-components: riscv
+
+#![feature(no_core, lang_items, abi_cheri_ccall)]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+#[lang = "copy"]
+trait Copy {}
+
+impl Copy for i32 {}
+
+// CHECK-LABEL: @enter(
+#[no_mangle]
+pub fn enter(
+    code: *const u8,
+    data: *mut u8,
+    arg: i32,
+    entry: extern "cheri-ccall" fn(*const u8, *mut u8, i32) -> i32,
+) -> i32 {
+    // CHECK: call chericcallcc i32 %entry(i8 addrspace(200)* {{.*}}%code, i8 addrspace(200)* {{.*}}%data, i32 {{.*}}%arg)
+    entry(code, data, arg)
+}
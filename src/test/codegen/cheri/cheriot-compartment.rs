@@ -0,0 +1,47 @@
+// Checks that CHERIoT compartment exports are entered with the callee convention and that
+// imports are called through the switcher, both tagged with their compartment. The calling
+// conventions are checked by the names LLVM prints for them, which also pins the numbers
+// `CallConv` passes for them.
+
+// compile-flags: --target riscv32cheriot-unknown-none --crate-type=rlib
+// needs-llvm-components: riscv
+
+#![feature(no_core, lang_items, cheriot_compartment)]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+#[lang = "copy"]
+trait Copy {}
+
+impl Copy for i32 {}
+
+extern "cheriot-cross-compartment" {
+    #[cheriot_compartment = "allocator"]
+    fn heap_quota() -> i32;
+}
+
+// CHECK: define chericcalleecc i32 @entry(i32 {{.*}}%x){{.*}} #[[EXPORT:[0-9]+]]
+#[no_mangle]
+#[cheriot_compartment = "example"]
+pub extern "cheriot-cross-compartment" fn entry(x: i32) -> i32 {
+    x
+}
+
+// CHECK-LABEL: @call_import
+#[no_mangle]
+pub fn call_import() -> i32 {
+    // CHECK: call chericcallcc i32 @heap_quota()
+    unsafe { heap_quota() }
+}
+
+// CHECK-LABEL: @call_export
+#[no_mangle]
+pub fn call_export(x: i32) -> i32 {
+    // CHECK: call chericcalleecc i32 @entry(i32 {{.*}}%x)
+    entry(x)
+}
+
+// CHECK: declare chericcallcc i32 @heap_quota(){{.*}} #[[IMPORT:[0-9]+]]
+// CHECK: attributes #[[EXPORT]] = {{.*}}"cheri-compartment"="example"
+// CHECK: attributes #[[IMPORT]] = {{.*}}"cheri-compartment"="allocator"
@@ -0,0 +1,42 @@
+// Checks that the data of a `dyn*` is a capability on CHERI targets: pointers are stored without
+// being reduced to their address, and integers become the address of a null-derived capability.
+
+// compile-flags: -O --target morello-unknown-none-purecap --crate-type=rlib
+// needs-llvm-components: aarch64
+
+#![feature(no_core, lang_items, dyn_star)]
+#![allow(incomplete_features)]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+#[lang = "copy"]
+trait Copy {}
+
+pub trait Trait {
+    fn method(&self);
+}
+
+impl Trait for &'static u8 {
+    fn method(&self) {}
+}
+
+impl Trait for usize {
+    fn method(&self) {}
+}
+
+// CHECK-LABEL: @from_ref
+#[no_mangle]
+pub fn from_ref(x: &'static u8) -> dyn* Trait {
+    // CHECK-NOT: ptrtoint
+    // CHECK-NOT: @llvm.cheri.cap.address.get
+    // CHECK: insertvalue { i8 addrspace(200)*, {{.*}} } {{.*}}, i8 addrspace(200)* %x, 0
+    x
+}
+
+// CHECK-LABEL: @from_usize
+#[no_mangle]
+pub fn from_usize(x: usize) -> dyn* Trait {
+    // CHECK: {{call i8 addrspace\(200\)\* @llvm.cheri.cap.address.set.*null, i64 %x|getelementptr i8, i8 addrspace\(200\)\* null, i64 %x}}
+    x
+}
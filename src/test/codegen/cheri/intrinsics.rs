@@ -0,0 +1,109 @@
+// Checks that capability queries and pointer-to-integer casts lower to the CHERI LLVM intrinsics
+// on a pure-capability target.
+
+// revisions: null ddc
+// compile-flags: -O --target morello-unknown-linux-purecap --crate-type=rlib
+// [ddc]compile-flags: -Z cheri-int-to-ptr=ddc-derived
+// needs-llvm-components: aarch64
+
+#![feature(no_core, lang_items, intrinsics)]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+#[lang = "copy"]
+trait Copy {}
+
+impl Copy for usize {}
+impl<T: ?Sized> Copy for *const T {}
+
+extern "rust-intrinsic" {
+    fn cheri_cap_base_get<T>(ptr: *const T) -> usize;
+    fn cheri_cap_length_get<T>(ptr: *const T) -> usize;
+    fn cheri_cap_perms_get<T>(ptr: *const T) -> usize;
+    fn cheri_cap_type_get<T>(ptr: *const T) -> isize;
+    fn cheri_cap_sealed_get<T>(ptr: *const T) -> bool;
+    fn cheri_cap_tag_get<T>(ptr: *const T) -> bool;
+    fn cheri_cap_equal_exact<T>(a: *const T, b: *const T) -> bool;
+    fn cheri_ddc_get() -> *mut ();
+    fn cheri_pcc_get() -> *const ();
+}
+
+// CHECK-LABEL: @base
+// CHECK: call i64 @llvm.cheri.cap.base.get{{(\.i64)?}}(i8 addrspace(200)*
+#[no_mangle]
+pub fn base(p: *const u8) -> usize {
+    unsafe { cheri_cap_base_get(p) }
+}
+
+// CHECK-LABEL: @length
+// CHECK: call i64 @llvm.cheri.cap.length.get{{(\.i64)?}}(i8 addrspace(200)*
+#[no_mangle]
+pub fn length(p: *const u8) -> usize {
+    unsafe { cheri_cap_length_get(p) }
+}
+
+// CHECK-LABEL: @perms
+// CHECK: call i64 @llvm.cheri.cap.perms.get{{(\.i64)?}}(i8 addrspace(200)*
+#[no_mangle]
+pub fn perms(p: *const u8) -> usize {
+    unsafe { cheri_cap_perms_get(p) }
+}
+
+// CHECK-LABEL: @otype
+// CHECK: call i64 @llvm.cheri.cap.type.get{{(\.i64)?}}(i8 addrspace(200)*
+#[no_mangle]
+pub fn otype(p: *const u8) -> isize {
+    unsafe { cheri_cap_type_get(p) }
+}
+
+// CHECK-LABEL: @sealed
+// CHECK: call i1 @llvm.cheri.cap.sealed.get(i8 addrspace(200)*
+#[no_mangle]
+pub fn sealed(p: *const u8) -> bool {
+    unsafe { cheri_cap_sealed_get(p) }
+}
+
+// CHECK-LABEL: @tag
+// CHECK: call i1 @llvm.cheri.cap.tag.get(i8 addrspace(200)*
+#[no_mangle]
+pub fn tag(p: *const u8) -> bool {
+    unsafe { cheri_cap_tag_get(p) }
+}
+
+// CHECK-LABEL: @equal_exact
+// CHECK: call i1 @llvm.cheri.cap.equal.exact(i8 addrspace(200)*
+#[no_mangle]
+pub fn equal_exact(a: *const u8, b: *const u8) -> bool {
+    unsafe { cheri_cap_equal_exact(a, b) }
+}
+
+// CHECK-LABEL: @ddc
+// CHECK: call i8 addrspace(200)* @llvm.cheri.ddc.get()
+#[no_mangle]
+pub fn ddc() -> *mut () {
+    unsafe { cheri_ddc_get() }
+}
+
+// CHECK-LABEL: @pcc
+// CHECK: call i8 addrspace(200)* @llvm.cheri.pcc.get()
+#[no_mangle]
+pub fn pcc() -> *const () {
+    unsafe { cheri_pcc_get() }
+}
+
+// CHECK-LABEL: @expose
+// CHECK: call i64 @llvm.cheri.cap.address.get{{(\.i64)?}}(i8 addrspace(200)*
+#[no_mangle]
+pub fn expose(p: *const u8) -> usize {
+    p as usize
+}
+
+// CHECK-LABEL: @from_exposed
+// null-NOT: llvm.cheri.ddc.get
+// ddc: call i8 addrspace(200)* @llvm.cheri.ddc.get()
+// ddc: call i8 addrspace(200)* @llvm.cheri.cap.address.set{{(\.i64)?}}(
+#[no_mangle]
+pub fn from_exposed(addr: usize) -> *const u8 {
+    addr as *const u8
+}
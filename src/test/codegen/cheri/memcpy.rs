@@ -0,0 +1,23 @@
+// Checks that copies of values containing capabilities keep capability alignment and are not
+// marked as free to drop tags, so that the copied pointers stay valid.
+
+// compile-flags: -O --target morello-unknown-linux-purecap --crate-type=rlib
+// needs-llvm-components: aarch64
+
+#![feature(no_core, lang_items)]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+#[lang = "copy"]
+trait Copy {}
+
+impl<T: ?Sized> Copy for *const T {}
+
+// CHECK-LABEL: @copy_ptrs
+// CHECK: call void @llvm.memcpy.p200{{.*}}(i8 addrspace(200)* align 16 {{.*}}, i8 addrspace(200)* align 16 {{.*}}, i64 128, i1 false)
+// CHECK-NOT: no-preserve-cheri-tags
+#[no_mangle]
+pub fn copy_ptrs(dst: &mut [*const u8; 8], src: &[*const u8; 8]) {
+    *dst = *src;
+}
@@ -0,0 +1,33 @@
+// Checks that `-Z cheri-vtables=restricted` bounds the vtable capability of a new trait object to
+// its vtable and leaves it only the load permissions.
+
+// compile-flags: -O --target morello-unknown-none-purecap --crate-type=rlib -Z cheri-vtables=restricted
+// needs-llvm-components: aarch64
+
+#![feature(no_core, lang_items)]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+#[lang = "unsize"]
+trait Unsize<T: ?Sized> {}
+#[lang = "coerce_unsized"]
+trait CoerceUnsized<T: ?Sized> {}
+impl<'a, 'b: 'a, T: ?Sized + Unsize<U>, U: ?Sized> CoerceUnsized<&'a U> for &'b T {}
+
+pub trait Trait {
+    fn method(&self);
+}
+
+impl Trait for u8 {
+    fn method(&self) {}
+}
+
+// CHECK-LABEL: @to_dyn
+#[no_mangle]
+pub fn to_dyn(x: &u8) -> &dyn Trait {
+    // The vtable holds the drop glue, size, alignment and `method`, each a 16-byte capability.
+    // CHECK: [[BOUNDED:%.*]] = {{.*}}call i8 addrspace(200)* @llvm.cheri.cap.bounds.set.exact{{(\.i64)?}}(i8 addrspace(200)* {{.*}}@vtable{{.*}}, i64 64)
+    // CHECK: call i8 addrspace(200)* @llvm.cheri.cap.perms.and{{(\.i64)?}}(i8 addrspace(200)* [[BOUNDED]], i64 147457)
+    x
+}
@@ -0,0 +1,45 @@
+// Checks that the addresses of vectors of pointers are read and replaced one lane at a time with
+// the CHERI LLVM intrinsics, so that the capabilities keep their tags.
+
+// compile-flags: -O --target morello-unknown-linux-purecap --crate-type=rlib
+// needs-llvm-components: aarch64
+
+#![feature(no_core, lang_items, platform_intrinsics, repr_simd)]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+#[lang = "copy"]
+trait Copy {}
+
+impl Copy for usize {}
+impl<T: ?Sized> Copy for *const T {}
+
+extern "platform-intrinsic" {
+    fn simd_addr<T, U>(x: T) -> U;
+    fn simd_with_addr<T, U>(x: T, y: U) -> T;
+}
+
+#[repr(simd)]
+pub struct Ptrs(*const u8, *const u8);
+
+#[repr(simd)]
+pub struct Addrs(usize, usize);
+
+// CHECK-LABEL: @addr
+// CHECK: call i64 @llvm.cheri.cap.address.get{{(\.i64)?}}(i8 addrspace(200)*
+// CHECK: call i64 @llvm.cheri.cap.address.get{{(\.i64)?}}(i8 addrspace(200)*
+// CHECK-NOT: ptrtoint
+#[no_mangle]
+pub fn addr(ptrs: Ptrs) -> Addrs {
+    unsafe { simd_addr(ptrs) }
+}
+
+// CHECK-LABEL: @with_addr
+// CHECK: call i8 addrspace(200)* @llvm.cheri.cap.address.set{{(\.i64)?}}(i8 addrspace(200)*
+// CHECK: call i8 addrspace(200)* @llvm.cheri.cap.address.set{{(\.i64)?}}(i8 addrspace(200)*
+// CHECK-NOT: inttoptr
+#[no_mangle]
+pub fn with_addr(ptrs: Ptrs, addrs: Addrs) -> Ptrs {
+    unsafe { simd_with_addr(ptrs, addrs) }
+}
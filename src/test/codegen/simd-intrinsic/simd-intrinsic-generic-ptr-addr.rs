@@ -0,0 +1,48 @@
+// compile-flags: -O
+// ignore-cheri
+
+#![crate_type = "lib"]
+
+#![feature(repr_simd, platform_intrinsics)]
+#![allow(non_camel_case_types)]
+
+// Checks that vectors of ordinary pointers are converted to and from addresses as a whole. Only
+// capabilities have to be converted one lane at a time.
+
+#[repr(simd)]
+#[derive(Copy, Clone)]
+pub struct ptrx4(*const u8, *const u8, *const u8, *const u8);
+
+#[repr(simd)]
+#[derive(Copy, Clone)]
+pub struct usizex4(usize, usize, usize, usize);
+
+extern "platform-intrinsic" {
+    fn simd_expose_addr<T, U>(x: T) -> U;
+    fn simd_addr<T, U>(x: T) -> U;
+    fn simd_from_exposed_addr<T, U>(x: T) -> U;
+}
+
+// CHECK-LABEL: @expose_addr
+#[no_mangle]
+pub unsafe fn expose_addr(ptrs: ptrx4) -> usizex4 {
+    // CHECK: ptrtoint <4 x i8*> %{{.*}} to <4 x i{{32|64}}>
+    // CHECK-NOT: extractelement
+    simd_expose_addr(ptrs)
+}
+
+// CHECK-LABEL: @addr
+#[no_mangle]
+pub unsafe fn addr(ptrs: ptrx4) -> usizex4 {
+    // CHECK: ptrtoint <4 x i8*> %{{.*}} to <4 x i{{32|64}}>
+    // CHECK-NOT: extractelement
+    simd_addr(ptrs)
+}
+
+// CHECK-LABEL: @from_exposed_addr
+#[no_mangle]
+pub unsafe fn from_exposed_addr(addrs: usizex4) -> ptrx4 {
+    // CHECK: inttoptr <4 x i{{32|64}}> %{{.*}} to <4 x i8*>
+    // CHECK-NOT: insertelement
+    simd_from_exposed_addr(addrs)
+}
@@ -0,0 +1,30 @@
+#![feature(no_core, lang_items)]
+#![crate_type = "rlib"]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+
+#[no_mangle]
+#[inline(never)]
+pub extern "C" fn capture() {}
+
+#[no_mangle]
+#[inline(never)]
+pub fn inner() {
+    capture()
+}
+
+#[no_mangle]
+#[inline(never)]
+pub fn middle() {
+    inner();
+    inner();
+}
+
+#[no_mangle]
+#[inline(never)]
+pub fn outer() {
+    middle();
+    middle();
+}
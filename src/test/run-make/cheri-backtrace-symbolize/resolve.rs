@@ -0,0 +1,66 @@
+// Resolves addresses in a Morello object with the symbolizer that std uses for backtraces on
+// CHERI targets, as if the object had been loaded at an offset and the addresses were C64 return
+// addresses.
+//
+// Usage: resolve <object> (debuginfo|symtab) <function>=<link-time address>...
+
+#![feature(rustc_private)]
+
+extern crate addr2line;
+extern crate object;
+
+#[path = "../../../../library/std/src/sys/unix/symbolize/elf.rs"]
+mod elf;
+
+use std::env;
+use std::fs;
+use std::str;
+
+const BIAS: usize = 0x4000_0000;
+
+/// Lines of the functions in `frames.rs`, from their signature to their closing brace.
+fn lines(function: &str) -> (u32, u32) {
+    match function {
+        "inner" => (14, 16),
+        "middle" => (20, 23),
+        "outer" => (27, 30),
+        _ => panic!("unknown function {}", function),
+    }
+}
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    let data = fs::read(&args[1]).unwrap();
+    let debuginfo = match &*args[2] {
+        "debuginfo" => true,
+        "symtab" => false,
+        mode => panic!("unknown mode {}", mode),
+    };
+    let object = elf::Object::parse(&data).expect("not an ELF object");
+    let cx = object.context();
+
+    for arg in &args[3..] {
+        let (function, pc) = arg.split_once('=').unwrap();
+        let pc = usize::from_str_radix(pc.trim_start_matches("0x"), 16).unwrap();
+        // Past the first instruction, with the C64 bit set as in a return address.
+        let ip = (pc + BIAS + 4) | 1;
+
+        let mut symbols = Vec::new();
+        object.resolve(cx.as_ref(), ip, BIAS, &mut symbols);
+        let symbol = symbols.last().unwrap_or_else(|| panic!("{} did not resolve", function));
+
+        let name = str::from_utf8(symbol.name.as_deref().unwrap()).unwrap();
+        assert_eq!(name, function);
+        if debuginfo {
+            let filename = str::from_utf8(symbol.filename.as_deref().unwrap()).unwrap();
+            assert!(filename.ends_with("frames.rs"), "{}", filename);
+            let line = symbol.lineno.unwrap();
+            let (first, last) = lines(function);
+            assert!(first <= line && line <= last, "{} resolved to line {}", function, line);
+            println!("{} {}:{}", name, filename, line);
+        } else {
+            assert!(symbol.filename.is_none() && symbol.lineno.is_none());
+            println!("{}", name);
+        }
+    }
+}
@@ -0,0 +1,30 @@
+// run-pass
+// compile-flags: -g -Cstrip=none
+// only-cheri-purecap
+
+// Checks that backtraces on purecap targets resolve their frames to functions and source
+// locations. Frames hold capabilities derived from PCC, and executables are position independent,
+// so both have to be turned into link-time addresses before the debuginfo is looked up.
+
+use std::backtrace::Backtrace;
+
+#[inline(never)]
+fn inner() -> Backtrace {
+    Backtrace::force_capture()
+}
+
+#[inline(never)]
+fn outer() -> Backtrace {
+    let backtrace = inner();
+    std::hint::black_box(());
+    backtrace
+}
+
+fn main() {
+    let backtrace = outer().to_string();
+    for name in ["inner", "outer", "main"] {
+        let name = format!("backtrace_symbolize::{name}");
+        assert!(backtrace.contains(&name), "{name} not found in:\n{backtrace}");
+    }
+    assert!(backtrace.contains(file!()), "{} not found in:\n{backtrace}", file!());
+}
@@ -0,0 +1,15 @@
+// run-pass
+// only-cheri-purecap
+// needs-capability-width: 128
+
+// On 128-bit capability targets pointers are twice as wide as the addresses they hold.
+
+use std::mem::{align_of, size_of};
+
+fn main() {
+    assert_eq!(size_of::<*const u8>(), 16);
+    assert_eq!(align_of::<*const u8>(), 16);
+    assert_eq!(size_of::<&str>(), 32);
+    assert_eq!(size_of::<usize>(), 8);
+    assert_eq!(size_of::<Option<&u8>>(), 16);
+}
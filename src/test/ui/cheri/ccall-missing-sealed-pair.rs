@@ -0,0 +1,16 @@
+// Checks that `extern "cheri-ccall"` functions have to take the sealed code and data
+// capabilities as their first two arguments.
+
+// build-fail
+// compile-flags: --target morello-unknown-none-purecap --crate-type=rlib
+// needs-llvm-components: aarch64
+
+#![feature(no_core, lang_items, abi_cheri_ccall)]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+
+#[no_mangle]
+extern "cheri-ccall" fn entry(_code: *const u8, _arg: i32) {}
+//~^ ERROR must take the sealed code and data capabilities as their first two arguments
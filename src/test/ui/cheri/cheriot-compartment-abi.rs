@@ -0,0 +1,18 @@
+// needs-llvm-components: riscv
+// compile-flags: --target=riscv32cheriot-unknown-none --crate-type=rlib
+#![no_core]
+#![feature(no_core, lang_items, cheriot_compartment)]
+#[lang="sized"]
+trait Sized { }
+
+// Compartment exports and imports must use the cross-compartment calling convention.
+
+#[cheriot_compartment = "example"]
+//~^ ERROR [E0791]
+pub extern "C" fn export() {}
+
+extern "C" {
+    #[cheriot_compartment = "other"]
+    //~^ ERROR [E0791]
+    pub fn import();
+}
@@ -0,0 +1,11 @@
+// needs-llvm-components: riscv
+// compile-flags: --target=riscv32imc-unknown-none-elf --crate-type=rlib
+#![no_core]
+#![feature(no_core, lang_items, cheriot_compartment)]
+#[lang="sized"]
+trait Sized { }
+
+// Compartments only exist on CHERIoT.
+
+#[cheriot_compartment = "example"] //~ ERROR [E0792]
+pub extern "C" fn export() {}
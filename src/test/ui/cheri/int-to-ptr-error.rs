@@ -0,0 +1,40 @@
+// Checks that `-Z cheri-int-to-ptr=error` rejects integer-to-pointer casts on purecap targets.
+
+// build-fail
+// compile-flags: --target morello-unknown-linux-purecap --crate-type=rlib
+// compile-flags: -Z cheri-int-to-ptr=error
+// needs-llvm-components: aarch64
+
+#![feature(no_core, lang_items, repr_simd, platform_intrinsics)]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+
+#[no_mangle]
+pub fn int_to_ptr(addr: usize) -> *const u8 {
+    addr as *const u8
+    //~^ ERROR casting an integer to a pointer is not allowed with `-Z cheri-int-to-ptr=error`
+}
+
+// Casts between pointers keep their provenance and are fine.
+#[no_mangle]
+pub fn ptr_to_ptr(ptr: *const u16) -> *const u8 {
+    ptr as *const u8
+}
+
+#[repr(simd)]
+pub struct UsizeX2(usize, usize);
+#[repr(simd)]
+pub struct PtrX2(*const u8, *const u8);
+
+extern "platform-intrinsic" {
+    fn simd_from_exposed_addr<T, U>(x: T) -> U;
+}
+
+// The lane-wise casts of `Simd::from_exposed_addr` are rejected too.
+#[no_mangle]
+pub unsafe fn simd_int_to_ptr(addrs: UsizeX2) -> PtrX2 {
+    simd_from_exposed_addr(addrs)
+    //~^ ERROR casting an integer to a pointer is not allowed with `-Z cheri-int-to-ptr=error`
+}
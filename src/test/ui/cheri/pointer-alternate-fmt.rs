@@ -0,0 +1,29 @@
+// run-pass
+// only-cheri-purecap
+
+// The alternate form of `{:p}` prints the metadata of capabilities in the style of CheriBSD's
+// `%#p`, while the plain form keeps printing only the address.
+
+#![feature(strict_provenance)]
+
+use std::ptr;
+
+fn main() {
+    let x = [0u32; 4];
+    let ptr = x.as_ptr();
+    let addr = ptr.addr();
+    assert_eq!(format!("{:p}", ptr), format!("{:#x}", addr));
+    // Stack objects get exact bounds, and may be loaded from and stored to.
+    assert_eq!(format!("{:#p}", ptr), format!("{:#x} [rwRW,{:#x}-{:#x}]", addr, addr, addr + 16));
+    // The bounds don't move with the address.
+    let second = ptr.wrapping_add(1);
+    assert_eq!(
+        format!("{:#p}", second),
+        format!("{:#x} [rwRW,{:#x}-{:#x}]", addr + 4, addr, addr + 16),
+    );
+
+    // The null capability has no permissions and no tag.
+    let null = format!("{:#p}", ptr::null::<u8>());
+    assert!(null.starts_with("0x0 [,0x0-"), "{null}");
+    assert!(null.ends_with(" (invalid)"), "{null}");
+}
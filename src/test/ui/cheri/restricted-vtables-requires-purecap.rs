@@ -0,0 +1,5 @@
+// compile-flags: -Z cheri-vtables=restricted
+// ignore-cheri-purecap
+// error-pattern: `-Z cheri-vtables=restricted` is only supported on CHERI pure-capability targets
+
+fn main() {}
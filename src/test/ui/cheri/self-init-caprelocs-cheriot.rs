@@ -0,0 +1,9 @@
+// CHERIoT has no DDC to derive capabilities from, so it can't initialize its own.
+
+// compile-flags: --target riscv32cheriot-unknown-none --crate-type=rlib
+// compile-flags: -Z cheri-self-init-caprelocs
+// needs-llvm-components: riscv
+// error-pattern: is only supported on freestanding CHERI pure-capability targets
+
+#![feature(no_core)]
+#![no_core]
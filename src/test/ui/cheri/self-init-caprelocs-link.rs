@@ -0,0 +1,19 @@
+// Checks that executables for a freestanding purecap target link against rscaprelocs.o from the
+// target's sysroot, whose `__rust_cheri_start` becomes their entry point.
+
+// build-pass
+// only-morello-unknown-none-purecap
+// compile-flags: -Z cheri-self-init-caprelocs -C panic=abort
+
+#![no_std]
+#![no_main]
+
+#[panic_handler]
+fn panic(_: &core::panic::PanicInfo<'_>) -> ! {
+    loop {}
+}
+
+#[no_mangle]
+pub extern "C" fn _start() -> ! {
+    loop {}
+}
@@ -0,0 +1,4 @@
+// compile-flags: -Z cheri-self-init-caprelocs
+// error-pattern: is only supported on freestanding CHERI pure-capability targets
+
+fn main() {}
@@ -0,0 +1,18 @@
+// needs-llvm-components: aarch64
+// compile-flags: --target=morello-unknown-none-purecap --crate-type=rlib
+#![no_core]
+#![feature(no_core, lang_items)]
+#[lang="sized"]
+trait Sized { }
+
+// Test that the cheri-ccall ABI cannot be used when the abi_cheri_ccall
+// feature gate is not used.
+
+extern "cheri-ccall" fn f(_code: *const u8, _data: *mut u8) {}
+//~^ ERROR cheri-ccall ABI is experimental
+
+type TA = extern "cheri-ccall" fn(*const u8, *mut u8);
+//~^ ERROR cheri-ccall ABI is experimental
+
+extern "cheri-ccall" {}
+//~^ ERROR cheri-ccall ABI is experimental
@@ -0,0 +1,21 @@
+// needs-llvm-components: riscv
+// compile-flags: --target=riscv32cheriot-unknown-none --crate-type=rlib
+#![no_core]
+#![feature(no_core, lang_items)]
+#[lang="sized"]
+trait Sized { }
+
+// Test that CHERIoT compartment exports and imports cannot be used when the
+// cheriot_compartment feature gate is not used.
+
+#[cheriot_compartment = "example"]
+//~^ ERROR the `#[cheriot_compartment]` attribute is an experimental feature
+extern "cheriot-cross-compartment" fn export() {}
+//~^ ERROR cheriot-cross-compartment ABI is experimental
+
+extern "cheriot-cross-compartment" {
+//~^ ERROR cheriot-cross-compartment ABI is experimental
+    #[cheriot_compartment = "other"]
+    //~^ ERROR the `#[cheriot_compartment]` attribute is an experimental feature
+    fn import();
+}
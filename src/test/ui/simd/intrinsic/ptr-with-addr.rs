@@ -0,0 +1,31 @@
+// run-pass
+
+#![feature(repr_simd, platform_intrinsics)]
+
+extern "platform-intrinsic" {
+    fn simd_addr<T, U>(x: T) -> U;
+    fn simd_with_addr<T, U>(x: T, y: U) -> T;
+}
+
+#[derive(Copy, Clone)]
+#[repr(simd)]
+struct V<T>([T; 2]);
+
+fn main() {
+    unsafe {
+        let mut foo = [1u32, 2, 3, 4];
+        let ptr = foo.as_mut_ptr();
+
+        let ptrs = V::<*mut u32>([ptr, ptr.wrapping_add(1)]);
+
+        let addrs: V<usize> = simd_addr(ptrs);
+        assert!(addrs.0 == [ptr as usize, ptr as usize + 4]);
+
+        // The new pointers keep the provenance of the old ones, so they can be dereferenced.
+        let moved: V<*mut u32> = simd_with_addr(ptrs, V([addrs.0[0] + 8, addrs.0[1] + 8]));
+        assert!(moved.0 == [ptr.wrapping_add(2), ptr.wrapping_add(3)]);
+        *moved.0[0] += 10;
+        assert_eq!(*moved.0[1], 4);
+        assert_eq!(foo, [1, 2, 13, 4]);
+    }
+}
@@ -28,6 +28,7 @@
 // gate-test-ermsb_target_feature
 // gate-test-bpf_target_feature
 // gate-test-aarch64_ver_target_feature
+// gate-test-morello_target_feature
 
 #[target_feature(enable = "avx512bw")]
 //~^ ERROR: currently unstable
@@ -180,6 +180,7 @@ fn get_clap_config() -> ArgMatches {
                             PossibleValue::new("pedantic"),
                             PossibleValue::new("restriction"),
                             PossibleValue::new("cargo"),
+                            PossibleValue::new("cheri"),
                             PossibleValue::new("nursery"),
                             PossibleValue::new("internal"),
                             PossibleValue::new("internal_warn"),
@@ -0,0 +1,55 @@
+use clippy_utils::consts::{constant, Constant};
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::get_parent_expr;
+use rustc_hir::def::Res;
+use rustc_hir::{Closure, Expr, ExprKind, Node, QPath};
+use rustc_lint::LateContext;
+
+use super::utils::address_of;
+use super::HIGH_BIT_POINTER_TAG;
+
+/// The number of address bits that 64-bit targets use for virtual addresses. Capabilities are only
+/// representable with addresses near their bounds, so any bit above these takes a tagged address
+/// out of them.
+const ADDRESS_BITS: u32 = 48;
+
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, left: &'tcx Expr<'_>, right: &'tcx Expr<'_>) {
+    for (address, tag) in [(left, right), (right, left)] {
+        if let Some((Constant::Int(tag), _)) = constant(cx, cx.typeck_results(), tag)
+            && tag >> ADDRESS_BITS != 0
+            && (address_of(cx, address).is_some() || is_map_addr_param(cx, address))
+        {
+            span_lint_and_help(
+                cx,
+                HIGH_BIT_POINTER_TAG,
+                expr.span,
+                "storing a tag in the high bits of a pointer's address",
+                None,
+                "the pointer becomes invalid on CHERI targets; store the tag in the low bits that the \
+                pointee's alignment leaves zero with `pointer::map_addr`, or next to the pointer",
+            );
+            return;
+        }
+    }
+}
+
+/// Returns `true` if `expr` is the parameter of a closure passed to `pointer::map_addr`, which is
+/// the address of the pointer.
+fn is_map_addr_param(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    let ExprKind::Path(QPath::Resolved(None, path)) = expr.kind else { return false };
+    let Res::Local(local_id) = path.res else { return false };
+    cx.tcx.hir().parent_iter(expr.hir_id).any(|(_, node)| {
+        if let Node::Expr(closure) = node
+            && let ExprKind::Closure(&Closure { body, .. }) = closure.kind
+            && let [param] = cx.tcx.hir().body(body).params
+            && param.pat.hir_id == local_id
+            && let Some(parent) = get_parent_expr(cx, closure)
+            && let ExprKind::MethodCall(method, receiver, [_], _) = parent.kind
+            && method.ident.as_str() == "map_addr"
+        {
+            cx.typeck_results().expr_ty(receiver).is_unsafe_ptr()
+        } else {
+            false
+        }
+    })
+}
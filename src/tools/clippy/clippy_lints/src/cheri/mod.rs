@@ -0,0 +1,214 @@
+mod high_bit_pointer_tag;
+mod pointer_copy_through_bytes;
+mod pointer_in_usize_field;
+mod ptr_usize_ptr_roundtrip;
+mod usize_pointer_size_assumption;
+mod utils;
+
+use clippy_utils::macros::{find_assert_eq_args, root_macro_call_first_node};
+use rustc_hir::{BinOpKind, Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for comparisons of `size_of::<usize>()` with the size of a pointer.
+    ///
+    /// ### Why is this bad?
+    /// Such comparisons usually guard code that stores pointers in integers. On CHERI targets a
+    /// pointer is a capability, twice the size of `usize`: `usize` only holds its address, so the
+    /// assumption fails there, and code that only asserts it stops compiling or panics.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # use std::mem::size_of;
+    /// assert_eq!(size_of::<usize>(), size_of::<*const u8>());
+    /// ```
+    ///
+    /// Use instead:
+    /// ```rust
+    /// // Keep pointers in pointer types, and use `pointer::addr` where only the address is needed.
+    /// let x = 0u8;
+    /// let ptr: *const u8 = &x;
+    /// ```
+    #[clippy::version = "1.66.0"]
+    pub USIZE_POINTER_SIZE_ASSUMPTION,
+    cheri,
+    "comparing the size of `usize` with the size of a pointer"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for pointers stored in integer fields, by struct expressions or assignments whose
+    /// value is a pointer cast to an integer or the address of a pointer.
+    ///
+    /// ### Why is this bad?
+    /// An integer only holds the address of a pointer. On CHERI targets the pointer's capability,
+    /// its bounds and permissions, are lost when it is stored, and pointers made from the field
+    /// later can't be dereferenced.
+    ///
+    /// ### Example
+    /// ```rust
+    /// struct Node {
+    ///     next: usize,
+    /// }
+    ///
+    /// let x = 0u8;
+    /// let node = Node { next: &x as *const u8 as usize };
+    /// ```
+    ///
+    /// Use instead:
+    /// ```rust
+    /// struct Node {
+    ///     next: *const u8,
+    /// }
+    ///
+    /// let x = 0u8;
+    /// let node = Node { next: &x };
+    /// ```
+    #[clippy::version = "1.66.0"]
+    pub POINTER_IN_USIZE_FIELD,
+    cheri,
+    "storing a pointer in an integer field"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for pointers that are cast to an integer and back to a pointer, possibly with
+    /// arithmetic on the integer in between.
+    ///
+    /// ### Why is this bad?
+    /// The pointer made from the integer has no provenance. On CHERI targets it is an invalid
+    /// capability that traps when dereferenced, so the round trip only works with the strict
+    /// provenance APIs, which keep the original pointer's capability.
+    ///
+    /// ### Example
+    /// ```rust
+    /// let x = [0u8; 4];
+    /// let ptr = x.as_ptr();
+    /// let second = (ptr as usize + 1) as *const u8;
+    /// ```
+    ///
+    /// Use instead:
+    /// ```rust
+    /// # #![feature(strict_provenance)]
+    /// let x = [0u8; 4];
+    /// let ptr = x.as_ptr();
+    /// let second = ptr.map_addr(|addr| addr + 1);
+    /// ```
+    #[clippy::version = "1.66.0"]
+    pub PTR_USIZE_PTR_ROUNDTRIP,
+    cheri,
+    "casting a pointer to an integer and back"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for tags stored in the high bits of the address of a pointer, as in
+    /// `ptr as usize | (1 << 63)`.
+    ///
+    /// ### Why is this bad?
+    /// On CHERI targets, moving the address of a capability far outside its bounds clears the
+    /// capability's validity tag, so the pointer can never be dereferenced again, even after the
+    /// high bits are cleared. Other targets with address tagging or larger address spaces use
+    /// those bits as well.
+    ///
+    /// ### Example
+    /// ```rust
+    /// let x = 0u64;
+    /// let tagged = &x as *const u64 as usize | (1 << 63);
+    /// ```
+    ///
+    /// Use instead:
+    /// ```rust
+    /// # #![feature(strict_provenance)]
+    /// // Use the low bits that the alignment of `u64` leaves zero.
+    /// let x = 0u64;
+    /// let tagged = (&x as *const u64).map_addr(|addr| addr | 1);
+    /// ```
+    #[clippy::version = "1.66.0"]
+    pub HIGH_BIT_POINTER_TAG,
+    cheri,
+    "storing a tag in the high bits of a pointer's address"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for byte-wise copies, with `ptr::copy_nonoverlapping` and related functions, of
+    /// memory that holds pointers.
+    ///
+    /// ### Why is this bad?
+    /// Byte buffers are rarely aligned for pointers. On CHERI targets capabilities only keep their
+    /// validity tag when copied to aligned memory as a whole, so the pointers read back from the
+    /// bytes are invalid.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # use std::mem::size_of;
+    /// # use std::ptr;
+    /// let x = 0u8;
+    /// let ptr: *const u8 = &x;
+    /// let mut buf = [0u8; size_of::<*const u8>()];
+    /// unsafe {
+    ///     ptr::copy_nonoverlapping(&ptr as *const *const u8 as *const u8, buf.as_mut_ptr(), buf.len());
+    /// }
+    /// ```
+    ///
+    /// Use instead:
+    /// ```rust
+    /// # use std::mem::MaybeUninit;
+    /// let x = 0u8;
+    /// let ptr: *const u8 = &x;
+    /// let mut buf = MaybeUninit::<*const u8>::uninit();
+    /// buf.write(ptr);
+    /// ```
+    #[clippy::version = "1.66.0"]
+    pub POINTER_COPY_THROUGH_BYTES,
+    cheri,
+    "copying pointers through byte buffers"
+}
+
+declare_lint_pass!(Cheri => [
+    USIZE_POINTER_SIZE_ASSUMPTION,
+    POINTER_IN_USIZE_FIELD,
+    PTR_USIZE_PTR_ROUNDTRIP,
+    HIGH_BIT_POINTER_TAG,
+    POINTER_COPY_THROUGH_BYTES,
+]);
+
+impl<'tcx> LateLintPass<'tcx> for Cheri {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if let Some(macro_call) = root_macro_call_first_node(cx, expr)
+            && matches!(
+                cx.tcx.item_name(macro_call.def_id).as_str(),
+                "assert_eq" | "debug_assert_eq" | "assert_ne" | "debug_assert_ne"
+            )
+            && let Some((left, right, _)) = find_assert_eq_args(cx, expr, macro_call.expn)
+        {
+            usize_pointer_size_assumption::check(cx, macro_call.span, left, right);
+        }
+
+        if expr.span.from_expansion() {
+            return;
+        }
+
+        match expr.kind {
+            ExprKind::Binary(op, left, right) => match op.node {
+                BinOpKind::Eq | BinOpKind::Ne => usize_pointer_size_assumption::check(cx, expr.span, left, right),
+                BinOpKind::BitOr | BinOpKind::BitXor => high_bit_pointer_tag::check(cx, expr, left, right),
+                _ => {},
+            },
+            ExprKind::Struct(_, fields, _) => {
+                for field in fields {
+                    pointer_in_usize_field::check(cx, field.span, field.expr);
+                }
+            },
+            ExprKind::Assign(target, value, _) if matches!(target.kind, ExprKind::Field(..)) => {
+                pointer_in_usize_field::check(cx, expr.span, value);
+            },
+            ExprKind::Cast(cast_expr, _) => ptr_usize_ptr_roundtrip::check(cx, expr, cast_expr),
+            ExprKind::Call(..) | ExprKind::MethodCall(..) => pointer_copy_through_bytes::check(cx, expr),
+            _ => {},
+        }
+    }
+}
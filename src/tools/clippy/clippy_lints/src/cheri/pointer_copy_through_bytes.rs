@@ -0,0 +1,88 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::{match_def_path, paths};
+use rustc_hir::{BinOpKind, Expr, ExprKind};
+use rustc_lint::LateContext;
+use rustc_middle::ty::{self, Ty, TypeAndMut};
+
+use super::utils::{is_capability, size_of_ty};
+use super::POINTER_COPY_THROUGH_BYTES;
+
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+    let (element_ty, pointers, count) = match expr.kind {
+        // `ptr::copy_nonoverlapping(src, dst, count)` and `ptr::copy(src, dst, count)`
+        ExprKind::Call(func, [src, dst, count]) => {
+            if let ExprKind::Path(ref qpath) = func.kind
+                && let Some(def_id) = cx.qpath_res(qpath, func.hir_id).opt_def_id()
+                && (match_def_path(cx, def_id, &paths::PTR_COPY_NONOVERLAPPING)
+                    || match_def_path(cx, def_id, &paths::PTR_COPY))
+                && let Some(element_ty) = cx.typeck_results().node_substs(func.hir_id).types().next()
+            {
+                (element_ty, [*src, *dst], count)
+            } else {
+                return;
+            }
+        },
+        // `src.copy_to_nonoverlapping(dst, count)` and the like
+        ExprKind::MethodCall(path, receiver, [other, count], _)
+            if matches!(
+                path.ident.as_str(),
+                "copy_to" | "copy_from" | "copy_to_nonoverlapping" | "copy_from_nonoverlapping"
+            ) =>
+        {
+            if let ty::RawPtr(TypeAndMut { ty: element_ty, .. }) = *cx.typeck_results().expr_ty(receiver).kind() {
+                (element_ty, [receiver, *other], count)
+            } else {
+                return;
+            }
+        },
+        _ => return,
+    };
+    if !matches!(element_ty.kind(), ty::Uint(ty::UintTy::U8) | ty::Int(ty::IntTy::I8)) {
+        return;
+    }
+
+    let copies_pointer = pointers
+        .into_iter()
+        .any(|it| pointee_ty(cx, it).map_or(false, is_capability))
+        || size_of_elements_ty(cx, count).map_or(false, is_capability);
+    if copies_pointer {
+        span_lint_and_help(
+            cx,
+            POINTER_COPY_THROUGH_BYTES,
+            expr.span,
+            "copying a pointer as bytes",
+            None,
+            "pointers copied through byte buffers are invalid on CHERI targets; store the pointer in a \
+            variable or `MaybeUninit` of its own type and copy it as a whole",
+        );
+    }
+}
+
+/// Returns the type that `expr` points to before it was cast to a byte pointer.
+fn pointee_ty<'tcx>(cx: &LateContext<'tcx>, mut expr: &Expr<'_>) -> Option<Ty<'tcx>> {
+    loop {
+        expr = match expr.kind {
+            ExprKind::Cast(inner, _) => inner,
+            ExprKind::MethodCall(path, receiver, [], _)
+                if matches!(path.ident.as_str(), "cast" | "cast_mut" | "cast_const") =>
+            {
+                receiver
+            },
+            _ => break,
+        };
+    }
+    match *cx.typeck_results().expr_ty(expr).kind() {
+        ty::RawPtr(TypeAndMut { ty, .. }) | ty::Ref(_, ty, _) => Some(ty),
+        _ => None,
+    }
+}
+
+/// Returns `T` if `expr` is `size_of::<T>()`, possibly multiplied by a count.
+fn size_of_elements_ty<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'_>) -> Option<Ty<'tcx>> {
+    match expr.kind {
+        ExprKind::Binary(op, left, right) if op.node == BinOpKind::Mul => {
+            size_of_elements_ty(cx, left).or_else(|| size_of_elements_ty(cx, right))
+        },
+        _ => size_of_ty(cx, expr),
+    }
+}
@@ -0,0 +1,24 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use rustc_hir::Expr;
+use rustc_lint::LateContext;
+use rustc_span::Span;
+
+use super::utils::address_of;
+use super::POINTER_IN_USIZE_FIELD;
+
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, span: Span, value: &'tcx Expr<'tcx>) {
+    if let Some(ptr) = address_of(cx, value) {
+        let ptr_ty = cx.typeck_results().expr_ty(ptr);
+        span_lint_and_help(
+            cx,
+            POINTER_IN_USIZE_FIELD,
+            span,
+            "storing a pointer in an integer field",
+            None,
+            &format!(
+                "the field only holds the pointer's address, which can't be turned back into a pointer on \
+                CHERI targets; consider changing the field's type to `{ptr_ty}` or a raw pointer"
+            ),
+        );
+    }
+}
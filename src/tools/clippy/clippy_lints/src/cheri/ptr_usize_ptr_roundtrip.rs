@@ -0,0 +1,92 @@
+use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_sugg};
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::sugg::Sugg;
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, Expr, ExprKind};
+use rustc_lint::LateContext;
+use rustc_middle::ty::{self, TypeAndMut};
+
+use super::utils::address_of;
+use super::PTR_USIZE_PTR_ROUNDTRIP;
+
+const MSG: &str = "casting a pointer to an integer and back";
+
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, cast_expr: &'tcx Expr<'tcx>) {
+    let ty::RawPtr(TypeAndMut { ty: to_pointee, mutbl: to_mutbl }) = *cx.typeck_results().expr_ty(expr).kind() else {
+        return;
+    };
+    // `ptr as usize as *const T`, or `(ptr as usize + offset) as *const T` and the like. The address
+    // may be either operand, except of a subtraction.
+    let (ptr, arithmetic) = match cast_expr.kind {
+        ExprKind::Binary(op, left, right)
+            if matches!(
+                op.node,
+                BinOpKind::Add | BinOpKind::Sub | BinOpKind::BitAnd | BinOpKind::BitOr | BinOpKind::BitXor
+            ) =>
+        {
+            if let Some(ptr) = address_of(cx, left) {
+                (ptr, Some((op.node, right, true)))
+            } else if op.node != BinOpKind::Sub && let Some(ptr) = address_of(cx, right) {
+                (ptr, Some((op.node, left, false)))
+            } else {
+                return;
+            }
+        },
+        _ => {
+            let Some(ptr) = address_of(cx, cast_expr) else { return };
+            (ptr, None)
+        },
+    };
+
+    // Only suggest the strict provenance APIs for raw pointers that keep their mutability; function
+    // pointers can't be mapped.
+    let ty::RawPtr(TypeAndMut { ty: from_pointee, mutbl: from_mutbl }) = *cx.typeck_results().expr_ty(ptr).kind() else {
+        span_lint_and_help(
+            cx,
+            PTR_USIZE_PTR_ROUNDTRIP,
+            expr.span,
+            MSG,
+            None,
+            "the new pointer has no provenance; cast the function pointer to a raw pointer and use \
+            `pointer::map_addr` or `pointer::cast` instead",
+        );
+        return;
+    };
+    if from_mutbl != to_mutbl {
+        span_lint_and_help(
+            cx,
+            PTR_USIZE_PTR_ROUNDTRIP,
+            expr.span,
+            MSG,
+            None,
+            "the new pointer has no provenance; use `pointer::map_addr` or `pointer::cast` and change \
+            the mutability with an `as` cast between pointers",
+        );
+        return;
+    }
+
+    let mut applicability = Applicability::MaybeIncorrect;
+    let ptr_sugg = Sugg::hir_with_applicability(cx, ptr, "..", &mut applicability).maybe_par();
+    let cast = if from_pointee == to_pointee {
+        String::new()
+    } else {
+        format!(".cast::<{to_pointee}>()")
+    };
+    let (help, sugg) = match arithmetic {
+        Some((op, operand, addr_first)) => {
+            let operand = snippet_with_applicability(cx, operand.span, "..", &mut applicability);
+            let map = if addr_first {
+                format!("addr {} {operand}", op.as_str())
+            } else {
+                format!("{operand} {} addr", op.as_str())
+            };
+            (
+                "use `pointer::map_addr` to keep the pointer's provenance",
+                format!("{ptr_sugg}.map_addr(|addr| {map}){cast}"),
+            )
+        },
+        None if cast.is_empty() => ("the cast does nothing, use the pointer", ptr_sugg.to_string()),
+        None => ("use `pointer::cast` to keep the pointer's provenance", format!("{ptr_sugg}{cast}")),
+    };
+    span_lint_and_sugg(cx, PTR_USIZE_PTR_ROUNDTRIP, expr.span, MSG, help, sugg, applicability);
+}
@@ -0,0 +1,24 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use rustc_hir::Expr;
+use rustc_lint::LateContext;
+use rustc_middle::ty::{self, Ty};
+use rustc_span::Span;
+
+use super::utils::{is_capability, size_of_ty};
+use super::USIZE_POINTER_SIZE_ASSUMPTION;
+
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, span: Span, left: &'tcx Expr<'_>, right: &'tcx Expr<'_>) {
+    let (Some(left_ty), Some(right_ty)) = (size_of_ty(cx, left), size_of_ty(cx, right)) else { return };
+    let is_usize = |ty: Ty<'_>| matches!(ty.kind(), ty::Uint(ty::UintTy::Usize) | ty::Int(ty::IntTy::Isize));
+    if (is_usize(left_ty) && is_capability(right_ty)) || (is_capability(left_ty) && is_usize(right_ty)) {
+        span_lint_and_help(
+            cx,
+            USIZE_POINTER_SIZE_ASSUMPTION,
+            span,
+            "comparing the size of `usize` with the size of a pointer",
+            None,
+            "pointers are larger than `usize` on CHERI targets; keep pointers in pointer types and use \
+            `pointer::addr` where only the address is needed",
+        );
+    }
+}
@@ -0,0 +1,43 @@
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::LateContext;
+use rustc_middle::ty::{self, Ty};
+use rustc_span::sym;
+
+/// Returns `true` for the types that are capabilities on CHERI targets: references, raw pointers,
+/// boxes and function pointers. Function items are zero-sized and only become a capability once
+/// they are cast to a function pointer.
+pub(super) fn is_capability(ty: Ty<'_>) -> bool {
+    matches!(ty.kind(), ty::Ref(..) | ty::RawPtr(_) | ty::FnPtr(_)) || ty.is_box()
+}
+
+/// If `expr` turns a pointer into its address, with an `as` cast to an integer or with
+/// `pointer::addr` or `pointer::expose_addr`, returns the pointer.
+pub(super) fn address_of<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    match expr.kind {
+        ExprKind::Cast(inner, _)
+            if cx.typeck_results().expr_ty(expr).is_integral() && is_capability(cx.typeck_results().expr_ty(inner)) =>
+        {
+            Some(inner)
+        },
+        ExprKind::MethodCall(path, receiver, [], _)
+            if matches!(path.ident.as_str(), "addr" | "expose_addr")
+                && cx.typeck_results().expr_ty(receiver).is_unsafe_ptr() =>
+        {
+            Some(receiver)
+        },
+        _ => None,
+    }
+}
+
+/// Returns `T` if `expr` is `size_of::<T>()`.
+pub(super) fn size_of_ty<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'_>) -> Option<Ty<'tcx>> {
+    if let ExprKind::Call(func, []) = expr.kind
+        && let ExprKind::Path(ref qpath) = func.kind
+        && let Some(def_id) = cx.qpath_res(qpath, func.hir_id).opt_def_id()
+        && cx.tcx.is_diagnostic_item(sym::mem_size_of, def_id)
+    {
+        cx.typeck_results().node_substs(func.hir_id).types().next()
+    } else {
+        None
+    }
+}
@@ -0,0 +1,11 @@
+// This file was generated by `cargo dev update_lints`.
+// Use that command to update this file and do not edit by hand.
+// Manual edits will be overwritten.
+
+store.register_group(true, "clippy::cheri", Some("clippy_cheri"), vec![
+    LintId::of(cheri::HIGH_BIT_POINTER_TAG),
+    LintId::of(cheri::POINTER_COPY_THROUGH_BYTES),
+    LintId::of(cheri::POINTER_IN_USIZE_FIELD),
+    LintId::of(cheri::PTR_USIZE_PTR_ROUNDTRIP),
+    LintId::of(cheri::USIZE_POINTER_SIZE_ASSUMPTION),
+])
@@ -88,6 +88,11 @@
     casts::FN_TO_NUMERIC_CAST_WITH_TRUNCATION,
     casts::PTR_AS_PTR,
     casts::UNNECESSARY_CAST,
+    cheri::HIGH_BIT_POINTER_TAG,
+    cheri::POINTER_COPY_THROUGH_BYTES,
+    cheri::POINTER_IN_USIZE_FIELD,
+    cheri::PTR_USIZE_PTR_ROUNDTRIP,
+    cheri::USIZE_POINTER_SIZE_ASSUMPTION,
     checked_conversions::CHECKED_CONVERSIONS,
     cognitive_complexity::COGNITIVE_COMPLEXITY,
     collapsible_if::COLLAPSIBLE_ELSE_IF,
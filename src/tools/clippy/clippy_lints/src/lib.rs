@@ -141,6 +141,11 @@ macro_rules! declare_clippy_lint {
             $(#[$attr])* pub clippy::$name, Allow, $description, report_in_external_macro: true
         }
     };
+    { $(#[$attr:meta])* pub $name:tt, cheri, $description:tt } => {
+        declare_tool_lint! {
+            $(#[$attr])* pub clippy::$name, Allow, $description, report_in_external_macro: true
+        }
+    };
     { $(#[$attr:meta])* pub $name:tt, nursery, $description:tt } => {
         declare_tool_lint! {
             $(#[$attr])* pub clippy::$name, Allow, $description, report_in_external_macro: true
@@ -183,6 +188,7 @@ macro_rules! declare_clippy_lint {
 mod box_default;
 mod cargo;
 mod casts;
+mod cheri;
 mod checked_conversions;
 mod cognitive_complexity;
 mod collapsible_if;
@@ -519,6 +525,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     include!("lib.register_perf.rs");
     include!("lib.register_cargo.rs");
     include!("lib.register_nursery.rs");
+    include!("lib.register_cheri.rs");
 
     #[cfg(feature = "internal")]
     {
@@ -919,6 +926,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_late_pass(|_| Box::new(implicit_saturating_add::ImplicitSaturatingAdd));
     store.register_early_pass(|| Box::new(partial_pub_fields::PartialPubFields));
     store.register_late_pass(|_| Box::new(missing_trait_methods::MissingTraitMethods));
+    store.register_late_pass(|_| Box::new(cheri::Cheri));
     // add lints here, do not remove this comment, it's used in `new_lint`
 }
 
@@ -59,6 +59,7 @@
     ("complexity", "warn"),
     ("perf", "warn"),
     ("cargo", "allow"),
+    ("cheri", "allow"),
     ("nursery", "allow"),
 ];
 /// This prefix is in front of the lint groups in the lint store. The prefix will be trimmed
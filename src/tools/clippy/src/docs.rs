@@ -184,6 +184,7 @@ pub fn explain(lint: &str) {
     "get_first",
     "get_last_with_len",
     "get_unwrap",
+    "high_bit_pointer_tag",
     "identity_op",
     "if_let_mutex",
     "if_not_else",
@@ -400,6 +401,8 @@ pub fn explain(lint: &str) {
     "partialeq_to_none",
     "path_buf_push_overwrite",
     "pattern_type_mismatch",
+    "pointer_copy_through_bytes",
+    "pointer_in_usize_field",
     "possible_missing_comma",
     "precedence",
     "print_in_format_impl",
@@ -412,6 +415,7 @@ pub fn explain(lint: &str) {
     "ptr_as_ptr",
     "ptr_eq",
     "ptr_offset_with_cast",
+    "ptr_usize_ptr_roundtrip",
     "pub_use",
     "question_mark",
     "range_minus_one",
@@ -579,6 +583,7 @@ pub fn explain(lint: &str) {
     "useless_let_if_seq",
     "useless_transmute",
     "useless_vec",
+    "usize_pointer_size_assumption",
     "vec_box",
     "vec_init_then_push",
     "vec_resize_to_zero",
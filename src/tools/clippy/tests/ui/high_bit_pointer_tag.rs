@@ -0,0 +1,21 @@
+#![feature(strict_provenance)]
+#![warn(clippy::high_bit_pointer_tag)]
+
+const TAG: usize = 1 << 63;
+
+fn main() {
+    let x = 0u64;
+    let ptr: *const u64 = &x;
+
+    let _ = ptr as usize | (1 << 63);
+    let _ = TAG | ptr as usize;
+    let _ = ptr.addr() ^ 0xffff_0000_0000_0000;
+    let _ = ptr.map_addr(|addr| addr | TAG);
+
+    // Ok
+    let _ = ptr as usize | 1;
+    let _ = ptr.map_addr(|addr| addr | 0b111);
+    let _ = ptr as usize & !TAG;
+    let addr = 8usize;
+    let _ = addr | TAG;
+}
@@ -0,0 +1,23 @@
+#![warn(clippy::pointer_copy_through_bytes)]
+
+use std::mem::size_of;
+use std::ptr;
+
+fn main() {
+    let x = 0u8;
+    let ptr: *const u8 = &x;
+    let mut out: *const u8 = ptr::null();
+    let mut buf = [0u8; 64];
+
+    unsafe {
+        ptr::copy_nonoverlapping(&ptr as *const *const u8 as *const u8, buf.as_mut_ptr(), 16);
+        ptr::copy(buf.as_ptr(), (&mut out as *mut *const u8).cast(), 16);
+        buf.as_mut_ptr().copy_from(buf.as_ptr().add(16), size_of::<&u8>());
+        buf.as_ptr().copy_to_nonoverlapping(buf.as_mut_ptr().add(32), 2 * size_of::<*const u8>());
+
+        // Ok
+        ptr::copy_nonoverlapping(&ptr, &mut out, 1);
+        ptr::copy_nonoverlapping(buf.as_ptr(), buf.as_mut_ptr().add(32), 32);
+        buf.as_mut_ptr().copy_from(buf.as_ptr().add(16), size_of::<u64>());
+    }
+}
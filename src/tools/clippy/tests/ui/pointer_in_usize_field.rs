@@ -0,0 +1,25 @@
+#![feature(strict_provenance)]
+#![warn(clippy::pointer_in_usize_field)]
+#![allow(dead_code)]
+
+struct Node {
+    next: usize,
+    len: usize,
+}
+
+fn main() {
+    let x = 0u8;
+    let ptr: *const u8 = &x;
+    let mut node = Node {
+        next: ptr as usize,
+        len: 1,
+    };
+    node.next = &x as *const u8 as usize;
+    node.next = ptr.addr();
+
+    // Ok
+    let _ = Node { next: 0, len: 1 };
+    let _ = ptr as usize;
+    node.len = 2;
+    node.len = main as usize;
+}
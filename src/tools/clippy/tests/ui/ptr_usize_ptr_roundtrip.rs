@@ -0,0 +1,27 @@
+#![feature(strict_provenance)]
+#![warn(clippy::ptr_usize_ptr_roundtrip)]
+#![allow(clippy::ptr_as_ptr)]
+
+fn main() {
+    let mut x = [0u32; 4];
+    let ptr = x.as_ptr();
+    let mut_ptr = x.as_mut_ptr();
+    let fn_ptr: fn() = main;
+
+    let _ = ptr as usize as *const u32;
+    let _ = ptr as usize as *const u8;
+    let _ = (ptr as usize + 4) as *const u32;
+    let _ = (mut_ptr.addr() & !3) as *mut u32;
+    let _ = ptr.expose_addr() as *const u8;
+    let _ = ptr as usize as *mut u32;
+    let _ = &x as *const [u32; 4] as usize as *const u32;
+    let _ = fn_ptr as usize as *const u8;
+    let _ = (4 + ptr as usize) as *const u32;
+
+    // Ok
+    let addr = ptr as usize;
+    let _ = addr as *const u32;
+    let _ = ptr.map_addr(|addr| addr + 4);
+    let _ = ptr.cast::<u8>();
+    let _ = main as usize as *const u8;
+}
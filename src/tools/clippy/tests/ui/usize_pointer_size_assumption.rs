@@ -0,0 +1,15 @@
+#![warn(clippy::usize_pointer_size_assumption)]
+
+use std::mem::size_of;
+
+fn main() {
+    assert_eq!(size_of::<usize>(), size_of::<*const u8>());
+    debug_assert_eq!(size_of::<&u8>(), size_of::<usize>());
+    let _ = size_of::<usize>() == size_of::<fn()>();
+    let _ = size_of::<isize>() != size_of::<Box<u8>>();
+
+    // Ok
+    assert_eq!(size_of::<usize>(), size_of::<u64>());
+    assert_eq!(size_of::<*const u8>(), size_of::<*mut u8>());
+    let _ = size_of::<usize>() == 8;
+}
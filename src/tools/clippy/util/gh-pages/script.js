@@ -116,6 +116,7 @@
 
             const GROUPS_FILTER_DEFAULT = {
                 cargo: true,
+                cheri: true,
                 complexity: true,
                 correctness: true,
                 deprecated: false,
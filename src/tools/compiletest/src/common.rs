@@ -423,6 +423,26 @@ pub fn get_pointer_width(&self) -> u32 {
         *&self.target_cfg().pointer_width
     }
 
+    /// Returns the width of capabilities if every pointer on the target is a capability, i.e.
+    /// the target is CHERI purecap and pointers are wider than their address.
+    pub fn get_capability_width(&self) -> Option<u32> {
+        let target_cfg = self.target_cfg();
+        (target_cfg.pointer_type_width != target_cfg.pointer_width)
+            .then_some(target_cfg.pointer_type_width)
+    }
+
+    pub fn is_cheri_purecap(&self) -> bool {
+        self.get_capability_width().is_some()
+    }
+
+    /// Whether the target has CHERI capabilities, either as all of its pointers (purecap) or
+    /// next to integer pointers (hybrid).
+    pub fn is_cheri(&self) -> bool {
+        self.is_cheri_purecap()
+            || self.target.contains("cheri")
+            || self.target.starts_with("morello")
+    }
+
     pub fn can_unwind(&self) -> bool {
         self.target_cfg().panic == PanicStrategy::Unwind
     }
@@ -446,6 +466,7 @@ pub struct TargetCfg {
     abi: String,
     families: Vec<String>,
     pointer_width: u32,
+    pointer_type_width: u32,
     endian: Endian,
     panic: PanicStrategy,
 }
@@ -485,6 +506,7 @@ fn new(config: &Config) -> TargetCfg {
         let mut abi = None;
         let mut families = Vec::new();
         let mut pointer_width = None;
+        let mut pointer_type_width = None;
         let mut endian = None;
         let mut panic = None;
         for line in print_cfg.lines() {
@@ -497,6 +519,9 @@ fn new(config: &Config) -> TargetCfg {
                     "target_abi" => abi = Some(value),
                     "target_family" => families.push(value.to_string()),
                     "target_pointer_width" => pointer_width = Some(value.parse().unwrap()),
+                    "target_pointer_type_width" => {
+                        pointer_type_width = Some(value.parse().unwrap())
+                    }
                     "target_endian" => {
                         endian = Some(match value {
                             "little" => Endian::Little,
@@ -515,13 +540,17 @@ fn new(config: &Config) -> TargetCfg {
                 }
             }
         }
+        // Compilers that predate `target_pointer_type_width` don't print it, and pointers are
+        // plain addresses on all of their targets.
+        let pointer_width = pointer_width.unwrap();
         TargetCfg {
             arch: arch.unwrap().to_string(),
             os: os.unwrap().to_string(),
             env: env.unwrap().to_string(),
             abi: abi.unwrap().to_string(),
             families,
-            pointer_width: pointer_width.unwrap(),
+            pointer_width,
+            pointer_type_width: pointer_type_width.unwrap_or(pointer_width),
             endian: endian.unwrap(),
             panic: panic.unwrap(),
         }
@@ -692,6 +692,8 @@ fn parse_cfg_name_directive(&self, line: &str, prefix: &str) -> ParsedNameDirect
             name == self.channel ||                             // channel
             (self.target != self.host && name == "cross-compile") ||
             (name == "endian-big" && self.is_big_endian()) ||
+            (name == "cheri" && self.is_cheri()) ||
+            (name == "cheri-purecap" && self.is_cheri_purecap()) ||
             (self.remote_test_client.is_some() && name == "remote") ||
             match self.compare_mode {
                 Some(CompareMode::Polonius) => name == "compare-mode-polonius",
@@ -948,6 +950,7 @@ pub fn make_test_description<R: Read>(
             };
         }
         ignore |= ignore_llvm(config, ln);
+        ignore |= ignore_capability_width(config, ln);
         ignore |=
             config.run_clang_based_tests_with.is_none() && config.parse_needs_matching_clang(ln);
         ignore |= !has_asm_support && config.parse_name_directive(ln, "needs-asm-support");
@@ -1056,6 +1059,17 @@ fn ignore_lldb(config: &Config, line: &str) -> bool {
     }
 }
 
+fn ignore_capability_width(config: &Config, line: &str) -> bool {
+    if let Some(width) = config.parse_name_value_directive(line, "needs-capability-width") {
+        let width: u32 = width.trim().parse().unwrap_or_else(|_| {
+            panic!("malformed needs-capability-width directive: expected a number of bits")
+        });
+        config.get_capability_width() != Some(width)
+    } else {
+        false
+    }
+}
+
 fn ignore_llvm(config: &Config, line: &str) -> bool {
     if config.system_llvm && line.starts_with("no-system-llvm") {
         return true;
@@ -404,6 +404,36 @@ fn pointer_width() {
     }
 }
 
+#[test]
+fn cheri() {
+    let targets = [
+        ("x86_64-unknown-linux-gnu", None),
+        ("morello-unknown-linux-purecap", Some(128)),
+        ("morello-unknown-freebsd-purecap", Some(128)),
+        ("riscv32imcxcheri-unknown-none-purecap", Some(64)),
+        ("riscv32cheriot-unknown-none", Some(64)),
+    ];
+    for (target, capability_width) in targets {
+        let mut config = config();
+        config.target = target.to_string();
+        let purecap = capability_width.is_some();
+        assert_eq!(config.get_capability_width(), capability_width, "{target}");
+        assert_eq!(check_ignore(&config, "// ignore-cheri"), purecap, "{target}");
+        assert_eq!(check_ignore(&config, "// ignore-cheri-purecap"), purecap, "{target}");
+        assert_eq!(check_ignore(&config, "// only-cheri-purecap"), !purecap, "{target}");
+        assert_eq!(
+            check_ignore(&config, "// needs-capability-width: 128"),
+            capability_width != Some(128),
+            "{target}"
+        );
+        assert_eq!(
+            check_ignore(&config, "// needs-capability-width: 64"),
+            capability_width != Some(64),
+            "{target}"
+        );
+    }
+}
+
 #[test]
 fn wasm_special() {
     let ignores = [
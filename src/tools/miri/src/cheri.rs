@@ -0,0 +1,284 @@
+//! Emulation of CHERI capabilities, enabled with `-Zmiri-cheri`.
+//!
+//! On CHERI every pointer is a capability: an address together with bounds, permissions and a
+//! validity tag that is kept out of band. Miri keeps the pointer layout of the target, and instead
+//! derives the capability of a pointer from its provenance:
+//!
+//! * A pointer with concrete provenance carries the capability that was handed out for its
+//!   allocation. Its bounds cover the allocation, rounded out to the nearest bounds that can be
+//!   represented in a compressed capability, and it may only be used to store if the allocation
+//!   is mutable. Miri already rejects every access outside of an allocation or through an
+//!   immutable one, so an access through such a capability can't fail its bounds or permission
+//!   checks on its own. Narrower bounds, such as the sub-object bounds that some CHERI compilers
+//!   set on references to fields, are not modelled.
+//! * A wildcard pointer has a cleared tag. Such pointers are created by integer-to-pointer casts,
+//!   and by wrapping pointer arithmetic that moves a pointer so far outside its bounds that the
+//!   capability can no longer be represented; the tag stays cleared when the pointer is moved
+//!   back in bounds. Any access through an untagged pointer is an error.
+//! * Copying only some of the bytes of a pointer drops its provenance, just like CHERI clears the
+//!   tag of a capability that is not copied as a whole.
+//!
+//! Bounds follow the CHERI Concentrate encoding of 128-bit capabilities. We use the mantissa
+//! width of CHERI-RISC-V; Morello encodes bounds at least as precisely, so everything that is
+//! representable here is also representable there.
+
+use rustc_ast::ast::Mutability;
+use rustc_target::abi::Size;
+
+use crate::*;
+
+/// Width of the bounds mantissa of a compressed capability.
+const MANTISSA_WIDTH: u32 = 14;
+/// Number of mantissa bits used to store the exponent when it is not zero.
+const INTERNAL_EXPONENT_BITS: u32 = 3;
+
+/// Returns the exponent used to encode bounds of `len` bytes, or `None` if such bounds are exact.
+fn exponent(len: u64) -> Option<u32> {
+    // Lengths that leave the top two mantissa bits clear are encoded without an exponent.
+    if len < 1 << (MANTISSA_WIDTH - 2) {
+        return None;
+    }
+    let mut e = u64::BITS - (len >> (MANTISSA_WIDTH - 1)).leading_zeros();
+    // Rounding the length up to the alignment imposed by the exponent may make it too large for
+    // the mantissa, in which case the next exponent is needed.
+    let align = 1u128 << (e + INTERNAL_EXPONENT_BITS);
+    let rounded = (u128::from(len) + align - 1) & !(align - 1);
+    if rounded >= 1u128 << (e + MANTISSA_WIDTH - 1) {
+        e += 1;
+    }
+    Some(e)
+}
+
+/// Returns the alignment that the base and length of a capability covering `len` bytes must
+/// have for its bounds to be exact, like `__builtin_cheri_representable_alignment_mask`.
+pub fn representable_alignment(len: u64) -> u64 {
+    match exponent(len) {
+        None => 1,
+        Some(e) => 1u64.checked_shl(e + INTERNAL_EXPONENT_BITS).unwrap_or(1 << 63),
+    }
+}
+
+/// Returns `len` rounded up to the nearest length that can be represented exactly, like
+/// `__builtin_cheri_round_representable_length`.
+pub fn representable_length(len: u64) -> u64 {
+    let align = representable_alignment(len);
+    len.checked_add(align - 1).map_or(u64::MAX & !(align - 1), |len| len & !(align - 1))
+}
+
+/// Permissions of a capability. We only model the ones that are relevant for data accesses.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Perms {
+    pub load: bool,
+    pub store: bool,
+}
+
+impl Perms {
+    fn for_allocation(mutability: Mutability) -> Self {
+        Perms { load: true, store: mutability == Mutability::Mut }
+    }
+
+    /// Returns the architectural permission bits, as read by `cheri_cap_perms_get`. Loading and
+    /// storing capabilities is allowed along with loading and storing data.
+    fn bits(self, morello: bool) -> u64 {
+        let (load, store) = if morello {
+            ((1 << 17) | (1 << 14), (1 << 16) | (1 << 13))
+        } else {
+            ((1 << 2) | (1 << 4), (1 << 3) | (1 << 5))
+        };
+        (if self.load { load } else { 0 }) | (if self.store { store } else { 0 })
+    }
+}
+
+/// The bounds of a capability, in absolute addresses.
+#[derive(Copy, Clone, Debug)]
+pub struct Bounds {
+    pub base: u64,
+    pub length: u64,
+}
+
+impl Bounds {
+    /// The bounds of the capability handed out for an allocation of `size` bytes at `base`.
+    pub fn for_allocation(base: u64, size: Size) -> Self {
+        Bounds { base, length: representable_length(size.bytes()) }
+    }
+
+    /// Returns whether a capability with these bounds can have its address set to `addr` without
+    /// losing its tag.
+    ///
+    /// Compressed bounds are encoded relative to the address, which therefore has to stay within
+    /// a window of `2^(E + MANTISSA_WIDTH)` bytes around the bounds. The window starts one eighth
+    /// of its size below the aligned base.
+    pub fn is_representable(&self, addr: u64) -> bool {
+        let e = exponent(self.length).unwrap_or(0);
+        let window_bits = e + MANTISSA_WIDTH;
+        if window_bits >= u64::BITS {
+            return true;
+        }
+        let window = 1u64 << window_bits;
+        let mantissa_mask = (1u64 << MANTISSA_WIDTH) - 1;
+        let b = (self.base >> e) & mantissa_mask;
+        // The bottom of the window, as a mantissa: the top three bits of `b`, minus one.
+        let r = (b >> (MANTISSA_WIDTH - 3)).wrapping_sub(1) << (MANTISSA_WIDTH - 3);
+        let r = r & mantissa_mask;
+        let window_start = self.base.wrapping_sub((b.wrapping_sub(r) & mantissa_mask) << e);
+        addr.wrapping_sub(window_start) < window
+    }
+}
+
+/// Checks that the capability used for an access is tagged.
+pub fn check_tag<'tcx>(
+    alloc_id: AllocId,
+    prov_extra: ProvenanceExtra,
+    range: AllocRange,
+    access: &str,
+) -> InterpResult<'tcx> {
+    if let ProvenanceExtra::Wildcard = prov_extra {
+        throw_machine_stop!(TerminationInfo::CapabilityFault {
+            msg: format!(
+                "{access} of {alloc_id:?}{range:?} through a capability with a cleared tag"
+            ),
+        });
+    }
+    Ok(())
+}
+
+impl<'mir, 'tcx: 'mir> EvalContextExt<'mir, 'tcx> for crate::MiriInterpCx<'mir, 'tcx> {}
+pub trait EvalContextExt<'mir, 'tcx: 'mir>: crate::MiriInterpCxExt<'mir, 'tcx> {
+    /// Offsets `ptr` by `offset` bytes with wrapping arithmetic. With `-Zmiri-cheri`, if the
+    /// result is outside the representable bounds of the capability, its tag is cleared.
+    fn cheri_wrapping_offset(
+        &self,
+        ptr: Pointer<Option<Provenance>>,
+        offset: i64,
+    ) -> Pointer<Option<Provenance>> {
+        let this = self.eval_context_ref();
+        let new_ptr = ptr.wrapping_signed_offset(offset, this);
+        if !this.machine.cheri {
+            return new_ptr;
+        }
+        let Some(Provenance::Concrete { alloc_id, .. }) = ptr.provenance else {
+            // Untagged pointers stay untagged.
+            return new_ptr;
+        };
+
+        let (size, _align, _kind) = this.get_alloc_info(alloc_id);
+        let base =
+            intptrcast::GlobalStateInner::rel_ptr_to_addr(this, Pointer::new(alloc_id, Size::ZERO));
+        let bounds = Bounds::for_allocation(base, size);
+        let (provenance, addr) = new_ptr.into_parts();
+        if bounds.is_representable(addr.bytes()) {
+            Pointer::new(provenance, addr)
+        } else {
+            Pointer::new(Some(Provenance::Wildcard), addr)
+        }
+    }
+
+    /// Reads a field of the capability `ptr` for one of the `cheri_cap_*_get` intrinsics.
+    ///
+    /// Without `-Zmiri-cheri` pointers are queried like unsealed capabilities covering the whole
+    /// address space with every permission, which are only invalid if null. Sealing is not
+    /// modelled, so capabilities are never sealed.
+    fn cheri_cap_query(
+        &self,
+        name: &str,
+        ptr: Pointer<Option<Provenance>>,
+    ) -> InterpResult<'tcx, Scalar<Provenance>> {
+        let this = self.eval_context_ref();
+        let usize_max = this.machine_usize_max();
+        let (base, length, perms, tag) = if !this.machine.cheri {
+            (0, usize_max, usize_max, ptr.addr().bytes() != 0)
+        } else if let Some(Provenance::Concrete { alloc_id, .. }) = ptr.provenance {
+            let (size, _align, kind) = this.get_alloc_info(alloc_id);
+            let base = intptrcast::GlobalStateInner::rel_ptr_to_addr(
+                this,
+                Pointer::new(alloc_id, Size::ZERO),
+            );
+            let mutability = match kind {
+                AllocKind::LiveData => this.get_alloc_mutability(alloc_id)?,
+                _ => Mutability::Not,
+            };
+            let perms = Perms::for_allocation(mutability);
+            let morello = this.tcx.sess.target.arch == "morello+c64";
+            (base, Bounds::for_allocation(base, size).length, perms.bits(morello), true)
+        } else {
+            // Untagged capabilities are derived from the null capability.
+            (0, usize_max, 0, false)
+        };
+
+        Ok(match name {
+            "cheri_cap_base_get" => Scalar::from_machine_usize(base, this),
+            "cheri_cap_length_get" => Scalar::from_machine_usize(length, this),
+            "cheri_cap_perms_get" => Scalar::from_machine_usize(perms, this),
+            "cheri_cap_sealed_get" => Scalar::from_bool(false),
+            "cheri_cap_tag_get" => Scalar::from_bool(tag),
+            "cheri_cap_type_get" => Scalar::from_machine_isize(0, this),
+            _ => bug!("unknown CHERI capability query `{name}`"),
+        })
+    }
+
+    /// Returns whether two pointers are the same capability, like `llvm.cheri.cap.equal.exact`.
+    ///
+    /// The bounds, permissions and tag of a capability are derived from its provenance, so two
+    /// pointers are exactly equal if they have the same address and the same provenance. Without
+    /// `-Zmiri-cheri` this is also the best approximation of exact equality we have.
+    fn cheri_cap_equal_exact(
+        &self,
+        a: Pointer<Option<Provenance>>,
+        b: Pointer<Option<Provenance>>,
+    ) -> bool {
+        let this = self.eval_context_ref();
+        let same_provenance = match (a.provenance, b.provenance) {
+            (None, None) | (Some(Provenance::Wildcard), Some(Provenance::Wildcard)) => true,
+            // Both are untagged capabilities, which only differ in their address.
+            (None, Some(Provenance::Wildcard)) | (Some(Provenance::Wildcard), None) =>
+                this.machine.cheri,
+            (
+                Some(Provenance::Concrete { alloc_id: a_id, sb: a_sb }),
+                Some(Provenance::Concrete { alloc_id: b_id, sb: b_sb }),
+            ) => a_id == b_id && a_sb == b_sb,
+            _ => false,
+        };
+        same_provenance && a.addr() == b.addr()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_representable_length() {
+        // Small lengths are exact.
+        assert_eq!(representable_length(0), 0);
+        assert_eq!(representable_length(1), 1);
+        assert_eq!(representable_length(4095), 4095);
+        // From 4KiB on, the length is rounded to a multiple of 8 bytes, then 16, and so on.
+        assert_eq!(representable_length(4096), 4096);
+        assert_eq!(representable_length(4097), 4104);
+        assert_eq!(representable_length(8193), 8208);
+        assert_eq!(representable_length(1 << 20), 1 << 20);
+        assert_eq!(representable_length((1 << 20) + 1), (1 << 20) + 2048);
+        // Lengths that would round up into the next exponent get its coarser alignment.
+        assert_eq!(representable_length(8191), 8192);
+        assert_eq!(representable_alignment(8191), 16);
+        assert_eq!(representable_alignment(u64::MAX), 1 << 55);
+        assert_eq!(representable_length(u64::MAX), u64::MAX & !((1 << 55) - 1));
+    }
+
+    #[test]
+    fn test_is_representable() {
+        let bounds = Bounds { base: 0x10000, length: 16 };
+        assert!(bounds.is_representable(0x10000));
+        assert!(bounds.is_representable(0x10010));
+        // The window spans 16KiB, starting 2KiB below the base.
+        assert!(bounds.is_representable(0x10000 - 0x800));
+        assert!(!bounds.is_representable(0x10000 - 0x801));
+        assert!(bounds.is_representable(0x10000 + 0x3800 - 1));
+        assert!(!bounds.is_representable(0x10000 + 0x3800));
+        assert!(!bounds.is_representable(0));
+
+        // Huge capabilities can have any address.
+        let bounds = Bounds { base: 0, length: 1 << 62 };
+        assert!(bounds.is_representable(u64::MAX));
+    }
+}
@@ -844,7 +844,7 @@ pub(super) fn race_free_with_atomic(
         if global.race_detecting() {
             let (_, clocks) = global.current_thread_state(thread_mgr);
             let alloc_ranges = self.alloc_ranges.borrow();
-            for (_, range) in alloc_ranges.iter(range.start, range.size) {
+            for (_, range) in alloc_ranges.iter(range.start, range.total_size) {
                 if !range.race_free_with_atomic(&clocks) {
                     return false;
                 }
@@ -868,7 +868,7 @@ pub fn read<'tcx>(
         if global.race_detecting() {
             let (index, clocks) = global.current_thread_state(thread_mgr);
             let mut alloc_ranges = self.alloc_ranges.borrow_mut();
-            for (offset, range) in alloc_ranges.iter_mut(range.start, range.size) {
+            for (offset, range) in alloc_ranges.iter_mut(range.start, range.total_size) {
                 if let Err(DataRace) = range.read_race_detect(&clocks, index) {
                     // Report data-race.
                     return Self::report_data_race(
@@ -898,7 +898,9 @@ fn unique_access<'tcx>(
     ) -> InterpResult<'tcx> {
         if global.race_detecting() {
             let (index, clocks) = global.current_thread_state(thread_mgr);
-            for (offset, range) in self.alloc_ranges.get_mut().iter_mut(range.start, range.size) {
+            for (offset, range) in
+                self.alloc_ranges.get_mut().iter_mut(range.start, range.total_size)
+            {
                 if let Err(DataRace) = range.write_race_detect(&clocks, index, write_type) {
                     // Report data-race
                     return Self::report_data_race(
@@ -995,10 +997,10 @@ fn atomic_access_check(&self, place: &MPlaceTy<'tcx, Provenance>) -> InterpResul
         // Check alignment requirements. Atomics must always be aligned to their size,
         // even if the type they wrap would be less aligned (e.g. AtomicU64 on 32bit must
         // be 8-aligned).
-        let align = Align::from_bytes(place.layout.size.bytes()).unwrap();
+        let align = Align::from_bytes(place.layout.ty_size.bytes()).unwrap();
         this.check_ptr_access_align(
             place.ptr,
-            place.layout.size,
+            place.layout.ty_size,
             align,
             CheckInAllocMsg::MemoryAccessTest,
         )?;
@@ -1113,7 +1115,7 @@ fn validate_atomic_op<A: Debug + Copy>(
         let this = self.eval_context_ref();
         if let Some(data_race) = &this.machine.data_race {
             if data_race.race_detecting() {
-                let size = place.layout.size;
+                let size = place.layout.ty_size;
                 let (alloc_id, base_offset, _prov) = this.ptr_get_alloc_id(place.ptr)?;
                 // Load and log the atomic operation.
                 // Note that atomic loads are possible even from read-only allocations, so `get_alloc_extra_mut` is not an option.
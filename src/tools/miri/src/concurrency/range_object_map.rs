@@ -76,7 +76,7 @@ pub fn access_type(&self, range: AllocRange) -> AccessType {
                 // Start of the range belongs to an existing object, now let's check the overlapping situation
                 let elem = &self.v[pos];
                 // FIXME: derive Eq for AllocRange in rustc
-                if elem.range.start == range.start && elem.range.size == range.size {
+                if elem.range.start == range.start && elem.range.total_size == range.total_size {
                     // Happy case: perfectly overlapping access
                     AccessType::PerfectlyOverlapping(pos)
                 } else {
@@ -154,10 +154,13 @@ fn index_mut(&mut self, pos: Position) -> &mut Self::Output {
 
 #[cfg(test)]
 mod tests {
-    use rustc_const_eval::interpret::alloc_range;
-
     use super::*;
 
+    /// A range of plain bytes, i.e. one that does not hold a pointer.
+    fn alloc_range(start: Size, size: Size) -> AllocRange {
+        rustc_const_eval::interpret::alloc_range(start, size, size)
+    }
+
     #[test]
     fn empty_map() {
         // FIXME: make Size::from_bytes const
@@ -751,7 +751,7 @@ fn start_thread(
         // to treat this write operation as occuring on the current thread.
         if let Some(thread_info_place) = thread {
             this.write_scalar(
-                Scalar::from_uint(new_thread_id.to_u32(), thread_info_place.layout.size),
+                Scalar::from_uint(new_thread_id.to_u32(), thread_info_place.layout.ty_size),
                 &thread_info_place.into(),
             )?;
         }
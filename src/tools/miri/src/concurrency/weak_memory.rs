@@ -488,7 +488,7 @@ fn validate_overlapping_atomic(
             ..
         } = this.get_alloc_extra(alloc_id)?
         {
-            let range = alloc_range(base_offset, place.layout.size);
+            let range = alloc_range(base_offset, place.layout.ty_size, place.layout.ty_size);
             if alloc_buffers.is_overlapping(range)
                 && !alloc_clocks.race_free_with_atomic(
                     range,
@@ -522,7 +522,7 @@ fn buffered_atomic_rmw(
                 global.sc_read(threads);
                 global.sc_write(threads);
             }
-            let range = alloc_range(base_offset, place.layout.size);
+            let range = alloc_range(base_offset, place.layout.ty_size, place.layout.ty_size);
             let buffer = alloc_buffers.get_or_create_store_buffer_mut(range, init)?;
             buffer.read_from_last_store(global, threads, atomic == AtomicRwOrd::SeqCst);
             buffer.buffered_write(new_val, global, threads, atomic == AtomicRwOrd::SeqCst)?;
@@ -546,7 +546,7 @@ fn buffered_atomic_read(
                 }
                 let mut rng = this.machine.rng.borrow_mut();
                 let buffer = alloc_buffers.get_or_create_store_buffer(
-                    alloc_range(base_offset, place.layout.size),
+                    alloc_range(base_offset, place.layout.ty_size, place.layout.ty_size),
                     latest_in_mo,
                 )?;
                 let (loaded, recency) = buffer.buffered_read(
@@ -591,15 +591,12 @@ fn buffered_atomic_write(
             // so init == val always. If the buffer is fresh then we would've duplicated an entry,
             // so we need to remove it.
             // See https://github.com/rust-lang/miri/issues/2164
+            let range = alloc_range(base_offset, dest.layout.ty_size, dest.layout.ty_size);
             let was_empty = matches!(
-                alloc_buffers
-                    .store_buffers
-                    .borrow()
-                    .access_type(alloc_range(base_offset, dest.layout.size)),
+                alloc_buffers.store_buffers.borrow().access_type(range),
                 AccessType::Empty(_)
             );
-            let buffer = alloc_buffers
-                .get_or_create_store_buffer_mut(alloc_range(base_offset, dest.layout.size), init)?;
+            let buffer = alloc_buffers.get_or_create_store_buffer_mut(range, init)?;
             if was_empty {
                 buffer.buffer.pop_front();
             }
@@ -626,11 +623,11 @@ fn perform_read_on_buffered_latest(
             if atomic == AtomicReadOrd::SeqCst {
                 global.sc_read(&this.machine.threads);
             }
-            let size = place.layout.size;
+            let size = place.layout.ty_size;
             let (alloc_id, base_offset, ..) = this.ptr_get_alloc_id(place.ptr)?;
             if let Some(alloc_buffers) = this.get_alloc_extra(alloc_id)?.weak_memory.as_ref() {
                 let buffer = alloc_buffers
-                    .get_or_create_store_buffer(alloc_range(base_offset, size), init)?;
+                    .get_or_create_store_buffer(alloc_range(base_offset, size, size), init)?;
                 buffer.read_from_last_store(
                     global,
                     &this.machine.threads,
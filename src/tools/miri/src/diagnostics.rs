@@ -20,6 +20,9 @@ pub enum TerminationInfo {
         history: Option<TagHistory>,
     },
     Int2PtrWithStrictProvenance,
+    CapabilityFault {
+        msg: String,
+    },
     Deadlock,
     MultipleSymbolDefinitions {
         link_name: Symbol,
@@ -47,6 +50,7 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
                     "integer-to-pointer casts and `ptr::from_exposed_addr` are not supported with `-Zmiri-strict-provenance`"
                 ),
             StackedBorrowsUb { msg, .. } => write!(f, "{msg}"),
+            CapabilityFault { msg, .. } => write!(f, "{msg}"),
             Deadlock => write!(f, "the evaluated program deadlocked"),
             MultipleSymbolDefinitions { link_name, .. } =>
                 write!(f, "multiple definitions of symbol `{link_name}`"),
@@ -165,6 +169,7 @@ pub fn report_error<'tcx, 'mir>(
                 UnsupportedInIsolation(_) | Int2PtrWithStrictProvenance =>
                     Some("unsupported operation"),
                 StackedBorrowsUb { .. } => Some("Undefined Behavior"),
+                CapabilityFault { .. } => Some("capability fault"),
                 Deadlock => Some("deadlock"),
                 MultipleSymbolDefinitions { .. } | SymbolShimClashing { .. } => None,
             };
@@ -202,6 +207,12 @@ pub fn report_error<'tcx, 'mir>(
                     vec![(Some(*span), format!("the `{link_name}` symbol is defined here"))],
                 Int2PtrWithStrictProvenance =>
                     vec![(None, format!("use Strict Provenance APIs (https://doc.rust-lang.org/nightly/std/ptr/index.html#strict-provenance, https://crates.io/crates/sptr) instead"))],
+                CapabilityFault { .. } =>
+                    vec![
+                        (None, format!("this operation would raise SIGPROT on a CHERI system, where pointers are capabilities")),
+                        (None, format!("pointers cast from integers, and pointers that were moved too far outside of their bounds, do not have a valid tag")),
+                        (None, format!("use Strict Provenance APIs such as `with_addr` to derive pointers from existing ones instead")),
+                    ],
                 _ => vec![],
             };
             (title, helps)
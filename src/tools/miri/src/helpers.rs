@@ -185,9 +185,9 @@ fn write_int(
     ) -> InterpResult<'tcx> {
         assert!(dest.layout.abi.is_scalar(), "write_int on non-scalar type {}", dest.layout.ty);
         let val = if dest.layout.abi.is_signed() {
-            Scalar::from_int(i, dest.layout.size)
+            Scalar::from_int(i, dest.layout.ty_size)
         } else {
-            Scalar::from_uint(u64::try_from(i.into()).unwrap(), dest.layout.size)
+            Scalar::from_uint(u64::try_from(i.into()).unwrap(), dest.layout.ty_size)
         };
         self.eval_context_mut().write_scalar(val, dest)
     }
@@ -327,7 +327,7 @@ fn visit_freeze_sensitive(
             size,
             this.size_and_align_of_mplace(place)?
                 .map(|(size, _)| size)
-                .unwrap_or_else(|| place.layout.size)
+                .unwrap_or_else(|| place.layout.ty_size)
         );
         // Store how far we proceeded into the place so far. Everything to the left of
         // this offset has already been handled, in the sense that the frozen parts
@@ -345,13 +345,16 @@ fn visit_freeze_sensitive(
             let frozen_size = unsafe_cell_addr - cur_addr;
             // Everything between the cur_ptr and this `UnsafeCell` is frozen.
             if frozen_size != Size::ZERO {
-                action(alloc_range(cur_addr - start_addr, frozen_size), /*frozen*/ true)?;
+                action(
+                    alloc_range(cur_addr - start_addr, frozen_size, frozen_size),
+                    /*frozen*/ true,
+                )?;
             }
             cur_addr += frozen_size;
             // This `UnsafeCell` is NOT frozen.
             if unsafe_cell_size != Size::ZERO {
                 action(
-                    alloc_range(cur_addr - start_addr, unsafe_cell_size),
+                    alloc_range(cur_addr - start_addr, unsafe_cell_size, unsafe_cell_size),
                     /*frozen*/ false,
                 )?;
             }
@@ -370,7 +373,7 @@ fn visit_freeze_sensitive(
                         .size_and_align_of_mplace(place)?
                         .map(|(size, _)| size)
                         // for extern types, just cover what we can
-                        .unwrap_or_else(|| place.layout.size);
+                        .unwrap_or_else(|| place.layout.ty_size);
                     // Now handle this `UnsafeCell`, unless it is empty.
                     if unsafe_cell_size != Size::ZERO {
                         unsafe_cell_action(&place.ptr, unsafe_cell_size)
@@ -671,7 +674,7 @@ fn deref_operand_and_offset(
         let offset = Size::from_bytes(offset);
 
         // Ensure that the access is within bounds.
-        assert!(op_place.layout.size >= offset + layout.size);
+        assert!(op_place.layout.ty_size >= offset + layout.ty_size);
         let value_place = op_place.offset(offset, layout, this)?;
         Ok(value_place)
     }
@@ -751,7 +754,7 @@ fn read_c_str<'a>(&'a self, ptr: Pointer<Option<Provenance>>) -> InterpResult<'t
             // FIXME: We are re-getting the allocation each time around the loop.
             // Would be nice if we could somehow "extend" an existing AllocRange.
             let alloc = this.get_ptr_alloc(ptr.offset(len, this)?, size1, Align::ONE)?.unwrap(); // not a ZST, so we will get a result
-            let byte = alloc.read_integer(alloc_range(Size::ZERO, size1))?.to_u8()?;
+            let byte = alloc.read_integer(alloc_range(Size::ZERO, size1, size1))?.to_u8()?;
             if byte == 0 {
                 break;
             } else {
@@ -797,7 +800,7 @@ fn read_wide_str(&self, mut ptr: Pointer<Option<Provenance>>) -> InterpResult<'t
             // FIXME: We are re-getting the allocation each time around the loop.
             // Would be nice if we could somehow "extend" an existing AllocRange.
             let alloc = this.get_ptr_alloc(ptr, size2, align2)?.unwrap(); // not a ZST, so we will get a result
-            let wchar = alloc.read_integer(alloc_range(Size::ZERO, size2))?.to_u16()?;
+            let wchar = alloc.read_integer(alloc_range(Size::ZERO, size2, size2))?.to_u16()?;
             if wchar == 0 {
                 break;
             } else {
@@ -837,7 +840,7 @@ fn write_wide_str(
             .unwrap(); // not a ZST, so we will get a result
         for (offset, wchar) in wide_str.iter().copied().chain(iter::once(0x0000)).enumerate() {
             let offset = u64::try_from(offset).unwrap();
-            alloc.write_scalar(alloc_range(size2 * offset, size2), Scalar::from_u16(wchar))?;
+            alloc.write_scalar(alloc_range(size2 * offset, size2, size2), Scalar::from_u16(wchar))?;
         }
         Ok((true, string_length))
     }
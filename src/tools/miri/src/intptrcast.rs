@@ -42,6 +42,9 @@ pub struct GlobalStateInner {
     next_base_addr: u64,
     /// The provenance to use for int2ptr casts
     provenance_mode: ProvenanceMode,
+    /// Whether pointers are CHERI capabilities. Then base addresses are chosen such that the
+    /// bounds of each allocation can be represented exactly.
+    cheri: bool,
 }
 
 impl VisitTags for GlobalStateInner {
@@ -58,6 +61,7 @@ pub fn new(config: &MiriConfig) -> Self {
             exposed: FxHashSet::default(),
             next_base_addr: STACK_ADDR,
             provenance_mode: config.provenance_mode,
+            cheri: config.cheri,
         }
     }
 }
@@ -67,7 +71,8 @@ impl<'mir, 'tcx> GlobalStateInner {
     // or `None` if the addr is out of bounds
     fn alloc_id_from_addr(ecx: &MiriInterpCx<'mir, 'tcx>, addr: u64) -> Option<AllocId> {
         let global_state = ecx.machine.intptrcast.borrow();
-        assert!(global_state.provenance_mode != ProvenanceMode::Strict);
+        // With CHERI, wildcard pointers also arise from pointer arithmetic that clears the tag.
+        assert!(global_state.cheri || global_state.provenance_mode != ProvenanceMode::Strict);
 
         let pos = global_state.int_to_ptr_map.binary_search_by_key(&addr, |(addr, _)| *addr);
 
@@ -89,7 +94,9 @@ fn alloc_id_from_addr(ecx: &MiriInterpCx<'mir, 'tcx>, addr: u64) -> Option<Alloc
         }?;
 
         // We only use this provenance if it has been exposed, *and* is still live.
-        if global_state.exposed.contains(&alloc_id) {
+        // With CHERI, accesses through wildcard pointers always fault; we still find the
+        // allocation so that the fault can be reported against it.
+        if global_state.cheri || global_state.exposed.contains(&alloc_id) {
             let (_size, _align, kind) = ecx.get_alloc_info(alloc_id);
             match kind {
                 AllocKind::LiveData | AllocKind::Function | AllocKind::VTable => {
@@ -138,6 +145,9 @@ pub fn ptr_from_addr_cast(
         let global_state = ecx.machine.intptrcast.borrow();
 
         match global_state.provenance_mode {
+            // With CHERI, the result of the cast does not have a valid tag. We report that on
+            // use instead of warning about the precision loss.
+            ProvenanceMode::Default if global_state.cheri => {}
             ProvenanceMode::Default => {
                 // The first time this happens at a particular location, print a warning.
                 thread_local! {
@@ -172,6 +182,11 @@ fn alloc_base_addr(ecx: &MiriInterpCx<'mir, 'tcx>, alloc_id: AllocId) -> u64 {
                 // There is nothing wrong with a raw pointer being cast to an integer only after
                 // it became dangling.  Hence we allow dead allocations.
                 let (size, align, _kind) = ecx.get_alloc_info(alloc_id);
+                let mut align = align.bytes();
+                if global_state.cheri {
+                    // Make sure the bounds of the allocation are exact.
+                    align = max(align, cheri::representable_alignment(size.bytes()));
+                }
 
                 // This allocation does not have a base address yet, pick one.
                 // Leave some space to the previous allocation, to give it some chance to be less aligned.
@@ -182,14 +197,14 @@ fn alloc_base_addr(ecx: &MiriInterpCx<'mir, 'tcx>, alloc_id: AllocId) -> u64 {
                 };
                 // From next_base_addr + slack, round up to adjust for alignment.
                 let base_addr = global_state.next_base_addr.checked_add(slack).unwrap();
-                let base_addr = Self::align_addr(base_addr, align.bytes());
+                let base_addr = Self::align_addr(base_addr, align);
                 entry.insert(base_addr);
                 trace!(
                     "Assigning base address {:#x} to allocation {:?} (size: {}, align: {}, slack: {})",
                     base_addr,
                     alloc_id,
                     size.bytes(),
-                    align.bytes(),
+                    align,
                     slack,
                 );
 
@@ -197,7 +212,14 @@ fn alloc_base_addr(ecx: &MiriInterpCx<'mir, 'tcx>, alloc_id: AllocId) -> u64 {
                 // of at least 1 to avoid two allocations having the same base address.
                 // (The logic in `alloc_id_from_addr` assumes unique addresses, and different
                 // function/vtable pointers need to be distinguishable!)
-                global_state.next_base_addr = base_addr.checked_add(max(size.bytes(), 1)).unwrap();
+                // With CHERI, the capability may cover more than the allocation itself, so the
+                // next allocation has to start after its bounds.
+                let len = if global_state.cheri {
+                    cheri::representable_length(max(size.bytes(), 1))
+                } else {
+                    max(size.bytes(), 1)
+                };
+                global_state.next_base_addr = base_addr.checked_add(len).unwrap();
                 // Given that `next_base_addr` increases in each allocation, pushing the
                 // corresponding tuple keeps `int_to_ptr_map` sorted
                 global_state.int_to_ptr_map.push((base_addr, alloc_id));
@@ -462,6 +462,9 @@ pub struct MiriMachine<'mir, 'tcx> {
     pub(crate) since_gc: u32,
     /// The number of CPUs to be reported by miri.
     pub(crate) num_cpus: u32,
+
+    /// Whether pointers are modeled as CHERI capabilities.
+    pub(crate) cheri: bool,
 }
 
 impl<'mir, 'tcx> MiriMachine<'mir, 'tcx> {
@@ -546,6 +549,7 @@ pub(crate) fn new(config: &MiriConfig, layout_cx: LayoutCx<'tcx, TyCtxt<'tcx>>)
             gc_interval: config.gc_interval,
             since_gc: 0,
             num_cpus: config.num_cpus,
+            cheri: config.cheri,
         }
     }
 
@@ -688,6 +692,7 @@ fn visit_tags(&self, visit: &mut dyn FnMut(SbTag)) {
             gc_interval: _,
             since_gc: _,
             num_cpus: _,
+            cheri: _,
         } = self;
 
         threads.visit_tags(visit);
@@ -766,6 +771,11 @@ fn enforce_abi(ecx: &MiriInterpCx<'mir, 'tcx>) -> bool {
         ecx.machine.enforce_abi
     }
 
+    #[inline(always)]
+    fn partial_ptr_copy_drops_provenance(ecx: &MiriInterpCx<'mir, 'tcx>) -> bool {
+        ecx.machine.cheri
+    }
+
     #[inline(always)]
     fn checked_binop_checks_overflow(ecx: &MiriInterpCx<'mir, 'tcx>) -> bool {
         ecx.tcx.sess.overflow_checks()
@@ -855,7 +865,8 @@ fn extern_static_base_pointer(
             let (shim_size, shim_align, _kind) = ecx.get_alloc_info(alloc_id);
             let extern_decl_layout =
                 ecx.tcx.layout_of(ty::ParamEnv::empty().and(ecx.tcx.type_of(def_id))).unwrap();
-            if extern_decl_layout.size != shim_size || extern_decl_layout.align.abi != shim_align {
+            if extern_decl_layout.ty_size != shim_size || extern_decl_layout.align.abi != shim_align
+            {
                 throw_unsup_format!(
                     "`extern` static `{name}` from crate `{krate}` has been declared \
                     with a size of {decl_size} bytes and alignment of {decl_align} bytes, \
@@ -863,7 +874,7 @@ fn extern_static_base_pointer(
                     with a size of {shim_size} bytes and alignment of {shim_align} bytes",
                     name = ecx.tcx.def_path_str(def_id),
                     krate = ecx.tcx.crate_name(def_id.krate),
-                    decl_size = extern_decl_layout.size.bytes(),
+                    decl_size = extern_decl_layout.ty_size.bytes(),
                     decl_align = extern_decl_layout.align.abi.bytes(),
                     shim_size = shim_size.bytes(),
                     shim_align = shim_align.bytes(),
@@ -1004,6 +1015,9 @@ fn before_memory_read(
         (alloc_id, prov_extra): (AllocId, Self::ProvenanceExtra),
         range: AllocRange,
     ) -> InterpResult<'tcx> {
+        if machine.cheri {
+            cheri::check_tag(alloc_id, prov_extra, range, "read")?;
+        }
         if let Some(data_race) = &alloc_extra.data_race {
             data_race.read(
                 alloc_id,
@@ -1036,6 +1050,9 @@ fn before_memory_write(
         (alloc_id, prov_extra): (AllocId, Self::ProvenanceExtra),
         range: AllocRange,
     ) -> InterpResult<'tcx> {
+        if machine.cheri {
+            cheri::check_tag(alloc_id, prov_extra, range, "write")?;
+        }
         if let Some(data_race) = &mut alloc_extra.data_race {
             data_race.write(
                 alloc_id,
@@ -1071,6 +1088,9 @@ fn before_memory_deallocation(
         if machine.tracked_alloc_ids.contains(&alloc_id) {
             machine.emit_diagnostic(NonHaltingDiagnostic::FreedAlloc(alloc_id));
         }
+        if machine.cheri {
+            cheri::check_tag(alloc_id, prove_extra, range, "deallocation")?;
+        }
         if let Some(data_race) = &mut alloc_extra.data_race {
             data_race.deallocate(
                 alloc_id,
@@ -1,7 +1,7 @@
 use log::trace;
 
 use rustc_middle::{mir, ty::Ty};
-use rustc_target::abi::Size;
+use rustc_target::abi::{Abi, Size};
 
 use crate::*;
 
@@ -28,17 +28,23 @@ fn binary_ptr_op(
         Ok(match bin_op {
             Eq | Ne | Lt | Le | Gt | Ge => {
                 assert_eq!(left.layout.abi, right.layout.abi); // types an differ, e.g. fn ptrs with different `for`
-                let size = self.pointer_size();
+                // The metadata of a wide pointer is a `usize`, which is smaller than the pointer
+                // itself on capability targets, so take each component's size from the layout.
+                let (size1, size2) = match left.layout.abi {
+                    Abi::Scalar(s) => (s.size(self), Size::ZERO),
+                    Abi::ScalarPair(s1, s2) => (s1.size(self), s2.size(self)),
+                    _ => bug!("comparing pointers with non-scalar layout {:?}", left.layout),
+                };
                 // Just compare the bits. ScalarPairs are compared lexicographically.
                 // We thus always compare pairs and simply fill scalars up with 0.
                 let left = match **left {
-                    Immediate::Scalar(l) => (l.to_bits(size)?, 0),
-                    Immediate::ScalarPair(l1, l2) => (l1.to_bits(size)?, l2.to_bits(size)?),
+                    Immediate::Scalar(l) => (l.to_bits(size1)?, 0),
+                    Immediate::ScalarPair(l1, l2) => (l1.to_bits(size1)?, l2.to_bits(size2)?),
                     Immediate::Uninit => panic!("we should never see uninit data here"),
                 };
                 let right = match **right {
-                    Immediate::Scalar(r) => (r.to_bits(size)?, 0),
-                    Immediate::ScalarPair(r1, r2) => (r1.to_bits(size)?, r2.to_bits(size)?),
+                    Immediate::Scalar(r) => (r.to_bits(size1)?, 0),
+                    Immediate::ScalarPair(r1, r2) => (r1.to_bits(size1)?, r2.to_bits(size2)?),
                     Immediate::Uninit => panic!("we should never see uninit data here"),
                 };
                 let res = match bin_op {
@@ -102,7 +102,7 @@ fn handle_miri_get_backtrace(
                 let ptr_layout = this.layout_of(ptr_ty)?;
 
                 for (i, ptr) in ptrs.into_iter().enumerate() {
-                    let offset = ptr_layout.size * i.try_into().unwrap();
+                    let offset = ptr_layout.ty_size * i.try_into().unwrap();
 
                     let op_place = buf_place.offset(offset, ptr_layout, this)?;
 
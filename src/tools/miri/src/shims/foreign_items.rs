@@ -46,7 +46,7 @@ fn min_align(&self, size: u64, kind: MiriMemoryKind) -> Align {
         // This list should be kept in sync with the one from libstd.
         let min_align = match this.tcx.sess.target.arch.as_ref() {
             "x86" | "arm" | "mips" | "powerpc" | "powerpc64" | "asmjs" | "wasm32" => 8,
-            "x86_64" | "aarch64" | "mips64" | "s390x" | "sparc64" => 16,
+            "x86_64" | "aarch64" | "mips64" | "s390x" | "sparc64" | "morello+c64" => 16,
             arch => bug!("Unsupported target architecture: {}", arch),
         };
         // Windows always aligns, even small allocations.
@@ -29,15 +29,17 @@ fn call_intrinsic(
         _unwind: StackPopUnwind,
     ) -> InterpResult<'tcx> {
         let this = self.eval_context_mut();
+        let intrinsic_name = this.tcx.item_name(instance.def_id());
+        let intrinsic_name = intrinsic_name.as_str();
 
         // See if the core engine can handle this intrinsic.
-        if this.emulate_intrinsic(instance, args, dest, ret)? {
+        // With CHERI, wrapping pointer arithmetic can clear the tag, so we handle it ourselves.
+        let cheri_override = this.machine.cheri && intrinsic_name == "arith_offset";
+        if !cheri_override && this.emulate_intrinsic(instance, args, dest, ret)? {
             return Ok(());
         }
 
         // All remaining supported intrinsics have a return place.
-        let intrinsic_name = this.tcx.item_name(instance.def_id());
-        let intrinsic_name = intrinsic_name.as_str();
         let ret = match ret {
             None => throw_unsup_format!("unimplemented (diverging) intrinsic: `{intrinsic_name}`"),
             Some(p) => p,
@@ -114,12 +116,47 @@ fn emulate_intrinsic_by_name(
                 let count = this.read_scalar(count)?.to_machine_usize(this)?;
                 // `checked_mul` enforces a too small bound (the correct one would probably be machine_isize_max),
                 // but no actual allocation can be big enough for the difference to be noticeable.
-                let byte_count = ty_layout.size.checked_mul(count, this).ok_or_else(|| {
+                let byte_count = ty_layout.ty_size.checked_mul(count, this).ok_or_else(|| {
                     err_ub_format!("overflow computing total size of `{intrinsic_name}`")
                 })?;
                 this.write_bytes_ptr(ptr, iter::repeat(val_byte).take(byte_count.bytes_usize()))?;
             }
 
+            "arith_offset" => {
+                let [ptr, offset] = check_arg_count(args)?;
+                let pointee_ty = ptr.layout.ty.builtin_deref(true).unwrap().ty;
+
+                let ptr = this.read_pointer(ptr)?;
+                let offset_count = this.read_scalar(offset)?.to_machine_isize(this)?;
+                let pointee_size =
+                    i64::try_from(this.layout_of(pointee_ty)?.ty_size.bytes()).unwrap();
+                let offset_bytes = offset_count.wrapping_mul(pointee_size);
+
+                let offset_ptr = this.cheri_wrapping_offset(ptr, offset_bytes);
+                this.write_pointer(offset_ptr, dest)?;
+            }
+            "cheri_cap_equal_exact" => {
+                let [a, b] = check_arg_count(args)?;
+
+                let a = this.read_pointer(a)?;
+                let b = this.read_pointer(b)?;
+
+                let equal = this.cheri_cap_equal_exact(a, b);
+                this.write_scalar(Scalar::from_bool(equal), dest)?;
+            }
+            "cheri_cap_base_get"
+            | "cheri_cap_length_get"
+            | "cheri_cap_perms_get"
+            | "cheri_cap_sealed_get"
+            | "cheri_cap_tag_get"
+            | "cheri_cap_type_get" => {
+                let [ptr] = check_arg_count(args)?;
+
+                let ptr = this.read_pointer(ptr)?;
+
+                let val = this.cheri_cap_query(intrinsic_name, ptr)?;
+                this.write_scalar(val, dest)?;
+            }
             "ptr_mask" => {
                 let [ptr, mask] = check_arg_count(args)?;
 
@@ -182,7 +182,7 @@ enum Op {
                                 // Shifts have extra UB as SIMD operations that the MIR binop does not have.
                                 // See <https://github.com/rust-lang/rust/issues/91237>.
                                 if overflowed {
-                                    let r_val = right.to_scalar().to_bits(right.layout.size)?;
+                                    let r_val = right.to_scalar().to_bits(right.layout.ty_size)?;
                                     throw_ub_format!("overflowing shift by {r_val} in `simd_{intrinsic_name}` in SIMD lane {i}");
                                 }
                             }
@@ -190,7 +190,7 @@ enum Op {
                                 // Special handling for boolean-returning operations
                                 assert_eq!(ty, this.tcx.types.bool);
                                 let val = val.to_bool().unwrap();
-                                bool_to_simd_element(val, dest.layout.size)
+                                bool_to_simd_element(val, dest.layout.ty_size)
                             } else {
                                 assert_ne!(ty, this.tcx.types.bool);
                                 assert_eq!(ty, dest.layout.ty);
@@ -205,9 +205,9 @@ enum Op {
                             let offset_count = right.to_scalar().to_machine_isize(this)?;
                             let pointee_ty = left.layout.ty.builtin_deref(true).unwrap().ty;
 
-                            let pointee_size = i64::try_from(this.layout_of(pointee_ty)?.size.bytes()).unwrap();
+                            let pointee_size = i64::try_from(this.layout_of(pointee_ty)?.ty_size.bytes()).unwrap();
                             let offset_bytes = offset_count.wrapping_mul(pointee_size);
-                            let offset_ptr = ptr.wrapping_signed_offset(offset_bytes, this);
+                            let offset_ptr = this.cheri_wrapping_offset(ptr, offset_bytes);
                             Scalar::from_maybe_pointer(offset_ptr, this)
                         }
                         Op::FMax => {
@@ -390,14 +390,14 @@ enum Op {
 
                 assert!(mask.layout.ty.is_integral());
                 assert!(bitmask_len <= 64);
-                assert_eq!(bitmask_len, mask.layout.size.bits());
+                assert_eq!(bitmask_len, mask.layout.ty_size.bits());
                 assert_eq!(dest_len, yes_len);
                 assert_eq!(dest_len, no_len);
                 let dest_len = u32::try_from(dest_len).unwrap();
                 let bitmask_len = u32::try_from(bitmask_len).unwrap();
 
                 let mask: u64 =
-                    this.read_scalar(mask)?.to_bits(mask.layout.size)?.try_into().unwrap();
+                    this.read_scalar(mask)?.to_bits(mask.layout.ty_size)?.try_into().unwrap();
                 for i in 0..dest_len {
                     let mask = mask
                         & 1u64
@@ -420,6 +420,44 @@ enum Op {
                     }
                 }
             }
+            "addr" => {
+                let [op] = check_arg_count(args)?;
+                let (op, op_len) = this.operand_to_simd(op)?;
+                let (dest, dest_len) = this.place_to_simd(dest)?;
+
+                assert_eq!(dest_len, op_len);
+
+                for i in 0..dest_len {
+                    let ptr = this.read_pointer(&this.mplace_index(&op, i)?.into())?;
+                    let dest = this.mplace_index(&dest, i)?;
+
+                    // Like `pointer::addr`, this does not expose the provenance.
+                    let addr = Scalar::from_machine_usize(ptr.addr().bytes(), this);
+                    this.write_scalar(addr, &dest.into())?;
+                }
+            }
+            "with_addr" => {
+                let [ptrs, addrs] = check_arg_count(args)?;
+                let (ptrs, ptrs_len) = this.operand_to_simd(ptrs)?;
+                let (addrs, addrs_len) = this.operand_to_simd(addrs)?;
+                let (dest, dest_len) = this.place_to_simd(dest)?;
+
+                assert_eq!(dest_len, ptrs_len);
+                assert_eq!(dest_len, addrs_len);
+
+                for i in 0..dest_len {
+                    let ptr = this.read_pointer(&this.mplace_index(&ptrs, i)?.into())?;
+                    let addr = this.read_scalar(&this.mplace_index(&addrs, i)?.into())?;
+                    let addr = addr.to_machine_usize(this)?;
+                    let dest = this.mplace_index(&dest, i)?;
+
+                    // Like `pointer::with_addr`, this keeps the provenance, and moving a
+                    // capability out of its representable bounds clears its tag.
+                    let offset = addr.wrapping_sub(ptr.addr().bytes()) as i64;
+                    let new_ptr = this.cheri_wrapping_offset(ptr, offset);
+                    this.write_pointer(new_ptr, &dest.into())?;
+                }
+            }
             #[rustfmt::skip]
             "cast" | "as" => {
                 let [op] = check_arg_count(args)?;
@@ -549,7 +587,7 @@ enum Op {
 
                 assert!(dest.layout.ty.is_integral());
                 assert!(bitmask_len <= 64);
-                assert_eq!(bitmask_len, dest.layout.size.bits());
+                assert_eq!(bitmask_len, dest.layout.ty_size.bits());
                 let op_len = u32::try_from(op_len).unwrap();
 
                 let mut res = 0u64;
@@ -577,7 +615,7 @@ fn bool_to_simd_element(b: bool, size: Size) -> Scalar<Provenance> {
 }
 
 fn simd_element_to_bool(elem: ImmTy<'_, Provenance>) -> InterpResult<'_, bool> {
-    let val = elem.to_scalar().to_int(elem.layout.size)?;
+    let val = elem.to_scalar().to_int(elem.layout.ty_size)?;
     Ok(match val {
         0 => false,
         -1 => true,
@@ -189,7 +189,7 @@ fn emulate_foreign_item_by_name(
                 let size = this.read_scalar(size)?.to_machine_usize(this)?;
                 // Align must be power of 2, and also at least ptr-sized (POSIX rules).
                 // But failure to adhere to this is not UB, it's an error condition.
-                if !align.is_power_of_two() || align < this.pointer_size().bytes() {
+                if !align.is_power_of_two() || align < this.pointer_ty_size().bytes() {
                     let einval = this.eval_libc_i32("EINVAL")?;
                     this.write_int(einval, dest)?;
                 } else {
@@ -228,12 +228,12 @@ fn emulate_foreign_item_by_name(
                 // FIXME: Which of these are POSIX, and which are GNU/Linux?
                 // At least the names seem to all also exist on macOS.
                 let sysconfs: &[(&str, fn(&MiriInterpCx<'_, '_>) -> Scalar<Provenance>)] = &[
-                    ("_SC_PAGESIZE", |this| Scalar::from_int(PAGE_SIZE, this.pointer_size())),
-                    ("_SC_NPROCESSORS_CONF", |this| Scalar::from_int(this.machine.num_cpus, this.pointer_size())),
-                    ("_SC_NPROCESSORS_ONLN", |this| Scalar::from_int(this.machine.num_cpus, this.pointer_size())),
+                    ("_SC_PAGESIZE", |this| Scalar::from_int(PAGE_SIZE, this.pointer_val_size())),
+                    ("_SC_NPROCESSORS_CONF", |this| Scalar::from_int(this.machine.num_cpus, this.pointer_val_size())),
+                    ("_SC_NPROCESSORS_ONLN", |this| Scalar::from_int(this.machine.num_cpus, this.pointer_val_size())),
                     // 512 seems to be a reasonable default. The value is not critical, in
                     // the sense that getpwuid_r takes and checks the buffer length.
-                    ("_SC_GETPW_R_SIZE_MAX", |this| Scalar::from_int(512, this.pointer_size()))
+                    ("_SC_GETPW_R_SIZE_MAX", |this| Scalar::from_int(512, this.pointer_val_size()))
                 ];
                 let mut result = None;
                 for &(sysconf_name, value) in sysconfs {
@@ -274,29 +274,29 @@ fn emulate_foreign_item_by_name(
                 let key_layout = this.layout_of(key_type)?;
 
                 // Create key and write it into the memory where `key_ptr` wants it.
-                let key = this.machine.tls.create_tls_key(dtor, key_layout.size)?;
-                this.write_scalar(Scalar::from_uint(key, key_layout.size), &key_place.into())?;
+                let key = this.machine.tls.create_tls_key(dtor, key_layout.ty_size)?;
+                this.write_scalar(Scalar::from_uint(key, key_layout.ty_size), &key_place.into())?;
 
                 // Return success (`0`).
                 this.write_null(dest)?;
             }
             "pthread_key_delete" => {
                 let [key] = this.check_shim(abi, Abi::C { unwind: false }, link_name, args)?;
-                let key = this.read_scalar(key)?.to_bits(key.layout.size)?;
+                let key = this.read_scalar(key)?.to_bits(key.layout.ty_size)?;
                 this.machine.tls.delete_tls_key(key)?;
                 // Return success (0)
                 this.write_null(dest)?;
             }
             "pthread_getspecific" => {
                 let [key] = this.check_shim(abi, Abi::C { unwind: false }, link_name, args)?;
-                let key = this.read_scalar(key)?.to_bits(key.layout.size)?;
+                let key = this.read_scalar(key)?.to_bits(key.layout.ty_size)?;
                 let active_thread = this.get_active_thread();
                 let ptr = this.machine.tls.load_tls(key, active_thread, this)?;
                 this.write_scalar(ptr, dest)?;
             }
             "pthread_setspecific" => {
                 let [key, new_ptr] = this.check_shim(abi, Abi::C { unwind: false }, link_name, args)?;
-                let key = this.read_scalar(key)?.to_bits(key.layout.size)?;
+                let key = this.read_scalar(key)?.to_bits(key.layout.ty_size)?;
                 let active_thread = this.get_active_thread();
                 let new_data = this.read_scalar(new_ptr)?;
                 this.machine.tls.store_tls(key, active_thread, new_data, &*this.tcx)?;
@@ -490,7 +490,7 @@ fn emulate_foreign_item_by_name(
                 let [_attr, guard_size] = this.check_shim(abi, Abi::C { unwind: false }, link_name, args)?;
                 let guard_size = this.deref_operand(guard_size)?;
                 let guard_size_layout = this.libc_ty_layout("size_t")?;
-                this.write_scalar(Scalar::from_uint(crate::PAGE_SIZE, guard_size_layout.size), &guard_size.into())?;
+                this.write_scalar(Scalar::from_uint(crate::PAGE_SIZE, guard_size_layout.ty_size), &guard_size.into())?;
 
                 // Return success (`0`).
                 this.write_null(dest)?;
@@ -519,11 +519,11 @@ fn emulate_foreign_item_by_name(
                 let size_place = this.deref_operand(size_place)?;
 
                 this.write_scalar(
-                    Scalar::from_uint(STACK_ADDR, this.pointer_size()),
+                    Scalar::from_maybe_pointer(Pointer::from_addr(STACK_ADDR), this),
                     &addr_place.into(),
                 )?;
                 this.write_scalar(
-                    Scalar::from_uint(STACK_SIZE, this.pointer_size()),
+                    Scalar::from_machine_usize(STACK_SIZE, this),
                     &size_place.into(),
                 )?;
 
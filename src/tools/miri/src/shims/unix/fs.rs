@@ -1426,7 +1426,7 @@ fn macos_readdir_r(
                 let (name_fits, file_name_buf_len) = this.write_os_str_to_c_str(
                     &file_name,
                     name_place.ptr,
-                    name_place.layout.size.bytes(),
+                    name_place.layout.ty_size.bytes(),
                 )?;
                 let file_name_len = file_name_buf_len.checked_sub(1).unwrap();
                 if !name_fits {
@@ -67,7 +67,7 @@ fn mutex_get_kind<'mir, 'tcx: 'mir>(
     ecx: &MiriInterpCx<'mir, 'tcx>,
     mutex_op: &OpTy<'tcx, Provenance>,
 ) -> InterpResult<'tcx, Scalar<Provenance>> {
-    let offset = if ecx.pointer_size().bytes() == 8 { 16 } else { 12 };
+    let offset = if ecx.pointer_val_size().bytes() == 8 { 16 } else { 12 };
     ecx.read_scalar_at_offset_atomic(
         mutex_op,
         offset,
@@ -81,7 +81,7 @@ fn mutex_set_kind<'mir, 'tcx: 'mir>(
     mutex_op: &OpTy<'tcx, Provenance>,
     kind: impl Into<Scalar<Provenance>>,
 ) -> InterpResult<'tcx, ()> {
-    let offset = if ecx.pointer_size().bytes() == 8 { 16 } else { 12 };
+    let offset = if ecx.pointer_val_size().bytes() == 8 { 16 } else { 12 };
     ecx.write_scalar_at_offset_atomic(
         mutex_op,
         offset,
@@ -122,7 +122,7 @@ fn emulate_foreign_item_by_name(
                 // Initialize with `0`.
                 this.write_bytes_ptr(
                     system_info.ptr,
-                    iter::repeat(0u8).take(system_info.layout.size.bytes_usize()),
+                    iter::repeat(0u8).take(system_info.layout.ty_size.bytes_usize()),
                 )?;
                 // Set selected fields.
                 let word_layout = this.machine.layouts.u16;
@@ -133,17 +133,17 @@ fn emulate_foreign_item_by_name(
                 // Pointer fields have different sizes on different targets.
                 // To avoid all these issue we calculate the offsets ourselves.
                 let field_sizes = [
-                    word_layout.size,  // 0,  wProcessorArchitecture      : WORD
-                    word_layout.size,  // 1,  wReserved                   : WORD
-                    dword_layout.size, // 2,  dwPageSize                  : DWORD
-                    usize_layout.size, // 3,  lpMinimumApplicationAddress : LPVOID
-                    usize_layout.size, // 4,  lpMaximumApplicationAddress : LPVOID
-                    usize_layout.size, // 5,  dwActiveProcessorMask       : DWORD_PTR
-                    dword_layout.size, // 6,  dwNumberOfProcessors        : DWORD
-                    dword_layout.size, // 7,  dwProcessorType             : DWORD
-                    dword_layout.size, // 8,  dwAllocationGranularity     : DWORD
-                    word_layout.size,  // 9,  wProcessorLevel             : WORD
-                    word_layout.size,  // 10, wProcessorRevision          : WORD
+                    word_layout.ty_size,  // 0,  wProcessorArchitecture      : WORD
+                    word_layout.ty_size,  // 1,  wReserved                   : WORD
+                    dword_layout.ty_size, // 2,  dwPageSize                  : DWORD
+                    usize_layout.ty_size, // 3,  lpMinimumApplicationAddress : LPVOID
+                    usize_layout.ty_size, // 4,  lpMaximumApplicationAddress : LPVOID
+                    usize_layout.ty_size, // 5,  dwActiveProcessorMask       : DWORD_PTR
+                    dword_layout.ty_size, // 6,  dwNumberOfProcessors        : DWORD
+                    dword_layout.ty_size, // 7,  dwProcessorType             : DWORD
+                    dword_layout.ty_size, // 8,  dwAllocationGranularity     : DWORD
+                    word_layout.ty_size,  // 9,  wProcessorLevel             : WORD
+                    word_layout.ty_size,  // 10, wProcessorRevision          : WORD
                 ];
                 let field_offsets: SmallVec<[Size; 11]> = field_sizes
                     .iter()
@@ -158,13 +158,13 @@ fn emulate_foreign_item_by_name(
                 // Set page size.
                 let page_size = system_info.offset(field_offsets[2], dword_layout, &this.tcx)?;
                 this.write_scalar(
-                    Scalar::from_int(PAGE_SIZE, dword_layout.size),
+                    Scalar::from_int(PAGE_SIZE, dword_layout.ty_size),
                     &page_size.into(),
                 )?;
                 // Set number of processors.
                 let num_cpus = system_info.offset(field_offsets[6], dword_layout, &this.tcx)?;
                 this.write_scalar(
-                    Scalar::from_int(this.machine.num_cpus, dword_layout.size),
+                    Scalar::from_int(this.machine.num_cpus, dword_layout.ty_size),
                     &num_cpus.into(),
                 )?;
             }
@@ -175,8 +175,8 @@ fn emulate_foreign_item_by_name(
 
                 // Create key and return it.
                 let [] = this.check_shim(abi, Abi::System { unwind: false }, link_name, args)?;
-                let key = this.machine.tls.create_tls_key(None, dest.layout.size)?;
-                this.write_scalar(Scalar::from_uint(key, dest.layout.size), dest)?;
+                let key = this.machine.tls.create_tls_key(None, dest.layout.ty_size)?;
+                this.write_scalar(Scalar::from_uint(key, dest.layout.ty_size), dest)?;
             }
             "TlsGetValue" => {
                 let [key] = this.check_shim(abi, Abi::System { unwind: false }, link_name, args)?;
@@ -38,7 +38,7 @@ fn generate_diagnostic(&self) -> (String, SpanData) {
                 self.span.data(),
             )
         } else {
-            assert!(self.retag.range.size == Size::ZERO);
+            assert!(self.retag.range.total_size == Size::ZERO);
             (
                 format!(
                     "{tag:?} would have been created here, but this is a zero-size retag ({:?}) so the tag in question does not exist anywhere",
@@ -261,9 +261,11 @@ pub fn start_grant(&mut self, perm: Permission) {
                 if previous != perm {
                     // 'Split up' the creation event.
                     let previous_range = last_creation.retag.range;
-                    last_creation.retag.range = alloc_range(previous_range.start, self.offset);
+                    last_creation.retag.range =
+                        alloc_range(previous_range.start, self.offset, self.offset);
                     let mut new_event = last_creation.clone();
-                    new_event.retag.range = alloc_range(self.offset, previous_range.end());
+                    new_event.retag.range =
+                        alloc_range(self.offset, previous_range.end(), previous_range.end());
                     new_event.retag.permission = Some(perm);
                     self.history.creations.push(new_event);
                 },
@@ -316,7 +318,7 @@ pub fn get_logs_relevant_to(
                 let range = event.retag.range;
                 if event.retag.new_tag == tag
                     && self.offset >= range.start
-                    && self.offset < (range.start + range.size)
+                    && self.offset < (range.start + range.total_size)
                 {
                     Some(event.generate_diagnostic())
                 } else {
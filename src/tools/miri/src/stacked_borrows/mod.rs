@@ -581,7 +581,7 @@ fn for_each(
         ) -> InterpResult<'tcx>,
     ) -> InterpResult<'tcx> {
         self.modified_since_last_gc = true;
-        for (offset, stack) in self.stacks.iter_mut(range.start, range.size) {
+        for (offset, stack) in self.stacks.iter_mut(range.start, range.total_size) {
             let mut dcx = dcx_builder.build(&mut self.history, offset);
             f(stack, &mut dcx, &mut self.exposed_tags)?;
             dcx_builder = dcx.unbuild();
@@ -631,7 +631,7 @@ pub fn before_memory_read<'tcx, 'mir, 'ecx>(
             "read access with tag {:?}: {:?}, size {}",
             tag,
             Pointer::new(alloc_id, range.start),
-            range.size.bytes()
+            range.total_size.bytes()
         );
         let dcx = DiagnosticCxBuilder::read(&mut current_span, threads, tag, range);
         let mut state = state.borrow_mut();
@@ -654,7 +654,7 @@ pub fn before_memory_write<'tcx, 'mir, 'ecx>(
             "write access with tag {:?}: {:?}, size {}",
             tag,
             Pointer::new(alloc_id, range.start),
-            range.size.bytes()
+            range.total_size.bytes()
         );
         let dcx = DiagnosticCxBuilder::write(&mut current_span, threads, tag, range);
         let mut state = state.borrow_mut();
@@ -673,7 +673,12 @@ pub fn before_memory_deallocation<'tcx, 'mir, 'ecx>(
         mut current_span: CurrentSpan<'ecx, 'mir, 'tcx>,
         threads: &'ecx ThreadManager<'mir, 'tcx>,
     ) -> InterpResult<'tcx> {
-        trace!("deallocation with tag {:?}: {:?}, size {}", tag, alloc_id, range.size.bytes());
+        trace!(
+            "deallocation with tag {:?}: {:?}, size {}",
+            tag,
+            alloc_id,
+            range.total_size.bytes()
+        );
         let dcx = DiagnosticCxBuilder::dealloc(&mut current_span, threads, tag);
         let state = state.borrow();
         self.for_each(range, dcx, |stack, dcx, exposed_tags| {
@@ -727,7 +732,7 @@ fn reborrow(
                 this.emit_diagnostic(NonHaltingDiagnostic::CreatedPointerTag(
                     new_tag.0,
                     Some(kind_str),
-                    loc.map(|(alloc_id, base_offset, orig_tag)| (alloc_id, alloc_range(base_offset, size), orig_tag)),
+                    loc.map(|(alloc_id, base_offset, orig_tag)| (alloc_id, alloc_range(base_offset, size, size), orig_tag)),
                 ));
             }
             drop(global); // don't hold that reference any longer than we have to
@@ -758,7 +763,7 @@ fn reborrow(
                         retag_cause,
                         new_tag,
                         orig_tag,
-                        alloc_range(base_offset, size),
+                        alloc_range(base_offset, size, size),
                     );
                     let mut dcx = dcx.build(&mut stacked_borrows.history, base_offset);
                     dcx.log_creation();
@@ -881,7 +886,7 @@ fn reborrow(
                         retag_cause,
                         new_tag,
                         orig_tag,
-                        alloc_range(base_offset, size),
+                        alloc_range(base_offset, size, size),
                     );
                     stacked_borrows.for_each(range, dcx, |stack, dcx, exposed_tags| {
                         stack.grant(orig_tag, item, &mut global, dcx, exposed_tags)
@@ -901,7 +906,7 @@ fn reborrow(
             .expect("we should have Stacked Borrows data")
             .borrow_mut();
         let item = Item::new(new_tag, perm, protect);
-        let range = alloc_range(base_offset, size);
+        let range = alloc_range(base_offset, size, size);
         let mut global = machine.stacked_borrows.as_ref().unwrap().borrow_mut();
         // FIXME: can't share this with the current_span inside log_creation
         let current_span = &mut machine.current_span();
@@ -911,7 +916,7 @@ fn reborrow(
             retag_cause,
             new_tag,
             orig_tag,
-            alloc_range(base_offset, size),
+            alloc_range(base_offset, size, size),
         );
         stacked_borrows.for_each(range, dcx, |stack, dcx, exposed_tags| {
             stack.grant(orig_tag, item, &mut global, dcx, exposed_tags)
@@ -1053,7 +1058,7 @@ fn visit_value(&mut self, place: &PlaceTy<'tcx, Provenance>) -> InterpResult<'tc
                 // pointers we need to retag, so we can stop recursion early.
                 // This optimization is crucial for ZSTs, because they can contain way more fields
                 // than we can ever visit.
-                if !place.layout.is_unsized() && place.layout.size < self.ecx.pointer_size() {
+                if !place.layout.is_unsized() && place.layout.ty_size < self.ecx.pointer_ty_size() {
                     return Ok(());
                 }
 
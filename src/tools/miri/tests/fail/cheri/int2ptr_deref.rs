@@ -0,0 +1,9 @@
+//@compile-flags: -Zmiri-cheri
+#![feature(strict_provenance)]
+
+fn main() {
+    let x = 0i32;
+    let addr = (&x as *const i32).expose_addr();
+    let ptr = std::ptr::from_exposed_addr::<i32>(addr);
+    let _val = unsafe { *ptr }; //~ ERROR: through a capability with a cleared tag
+}
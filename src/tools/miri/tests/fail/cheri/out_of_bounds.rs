@@ -0,0 +1,9 @@
+//@compile-flags: -Zmiri-cheri
+
+fn main() {
+    let x = Box::new([0u8; 16]);
+    let ptr = x.as_ptr();
+    // The capability of `x` covers exactly its allocation, so reading past its end is caught by
+    // the bounds check every access goes through anyway.
+    let _val = unsafe { *ptr.wrapping_add(16) }; //~ ERROR: out-of-bounds
+}
@@ -0,0 +1,25 @@
+//@compile-flags: -Zmiri-cheri
+#![feature(platform_intrinsics, repr_simd)]
+
+extern "platform-intrinsic" {
+    fn simd_arith_offset<T, U>(ptrs: T, offsets: U) -> T;
+}
+
+#[repr(simd)]
+#[derive(Copy, Clone)]
+struct Ptrs([*const u8; 2]);
+
+#[repr(simd)]
+#[derive(Copy, Clone)]
+struct Offsets([isize; 2]);
+
+fn main() {
+    let x = [0u8; 16];
+    let ptr = x.as_ptr();
+    // Vectors of pointers lose their tags like single pointers do when moved too far.
+    let far = unsafe { simd_arith_offset(Ptrs([ptr, ptr]), Offsets([0, 1 << 20])) };
+    let back = unsafe { simd_arith_offset(far, Offsets([0, -(1 << 20)])) };
+    assert_eq!(back.0, [ptr, ptr]);
+    let _val = unsafe { *back.0[0] };
+    let _val = unsafe { *back.0[1] }; //~ ERROR: through a capability with a cleared tag
+}
@@ -0,0 +1,11 @@
+//@compile-flags: -Zmiri-cheri
+
+fn main() {
+    let x = [0u8; 16];
+    let ptr = x.as_ptr();
+    // Move the pointer far outside the representable range of its bounds and back.
+    let far = ptr.wrapping_add(1 << 20);
+    let back = far.wrapping_sub(1 << 20);
+    assert_eq!(back, ptr);
+    let _val = unsafe { *back }; //~ ERROR: through a capability with a cleared tag
+}
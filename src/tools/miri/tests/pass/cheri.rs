@@ -0,0 +1,102 @@
+//@compile-flags: -Zmiri-cheri
+#![feature(strict_provenance, ptr_eq_exact, core_intrinsics)]
+#![allow(dead_code)]
+
+use std::intrinsics::{
+    cheri_cap_base_get, cheri_cap_length_get, cheri_cap_perms_get, cheri_cap_sealed_get,
+    cheri_cap_tag_get,
+};
+use std::ptr;
+
+fn wrapping_arithmetic() {
+    let x = [0u8, 1, 2, 3];
+    let ptr = x.as_ptr();
+    // Moving a pointer out of bounds and back is fine as long as it stays representable.
+    let oob = ptr.wrapping_add(64).wrapping_sub(62);
+    assert_eq!(unsafe { *oob }, 2);
+    let oob = ptr.wrapping_sub(16).wrapping_add(3);
+    assert_eq!(unsafe { *oob }, 3);
+}
+
+fn with_addr() {
+    let x = [0u16; 8];
+    let ptr = x.as_ptr();
+    let addr = ptr.addr();
+    // Deriving a pointer from an existing one keeps the tag.
+    let ptr2 = ptr.with_addr(addr + 4);
+    assert_eq!(unsafe { *ptr2 }, 0);
+}
+
+fn representable_alignment() {
+    // Bounds of large allocations are exact, so their base is aligned accordingly.
+    let b = Box::new([0u8; 8191]);
+    assert_eq!(b.as_ptr().addr() % 16, 0);
+    let v = vec![0u8; 100_000];
+    assert_eq!(v.as_ptr().addr() % 64, 0);
+    assert_eq!(v[99_999], 0);
+}
+
+// We use packed structs to get around alignment restrictions
+#[repr(packed)]
+struct Data {
+    pad: u8,
+    ptr: &'static i32,
+}
+
+static G: i32 = 0;
+
+fn partial_copy() {
+    let mut d = Data { pad: 0, ptr: &G };
+    // Copying only some bytes of a pointer clears the tag instead of being an error.
+    let d_alias = &mut d as *mut _ as *mut usize;
+    let x = unsafe { d_alias.read_unaligned() };
+    let _ = x;
+    // Copying a pointer as a whole keeps it usable.
+    let copy = unsafe { ptr::addr_of!(d.ptr).read_unaligned() };
+    assert_eq!(*copy, 0);
+}
+
+fn eq_exact() {
+    let x = [0u8; 4];
+    let ptr = x.as_ptr();
+    assert!(ptr::eq_exact(ptr, ptr.wrapping_add(1).wrapping_sub(1)));
+    assert!(!ptr::eq_exact(ptr, ptr.wrapping_add(1)));
+    // Moving a pointer so far out of bounds that it loses its tag makes it a different
+    // capability, even once it is back at the same address.
+    let untagged = ptr.wrapping_add(1 << 20).wrapping_sub(1 << 20);
+    assert!(ptr == untagged);
+    assert!(!ptr::eq_exact(ptr, untagged));
+}
+
+fn cap_queries() {
+    let x = [0u8; 16];
+    let ptr = x.as_ptr().wrapping_add(4);
+    // The capability of a pointer covers its whole allocation, wherever it points.
+    assert_eq!(cheri_cap_base_get(ptr), x.as_ptr().addr());
+    assert_eq!(cheri_cap_length_get(ptr), 16);
+    assert!(cheri_cap_tag_get(ptr));
+    assert!(!cheri_cap_sealed_get(ptr));
+    let v = vec![0u8; 100_000];
+    // Bounds of large allocations are rounded up to the next representable length.
+    assert_eq!(cheri_cap_length_get(v.as_ptr()), 100_096);
+
+    // Immutable allocations can't be stored through.
+    static X: u8 = 0;
+    let mut y = 0u8;
+    assert_ne!(cheri_cap_perms_get(&X), cheri_cap_perms_get(&mut y));
+
+    // Pointers made up from integers are untagged, and derived from the null capability.
+    let int_ptr = ptr::from_exposed_addr::<u8>(ptr.expose_addr());
+    assert!(!cheri_cap_tag_get(int_ptr));
+    assert_eq!(cheri_cap_base_get(int_ptr), 0);
+    assert_eq!(cheri_cap_perms_get(int_ptr), 0);
+}
+
+fn main() {
+    wrapping_arithmetic();
+    with_addr();
+    representable_alignment();
+    partial_copy();
+    eq_exact();
+    cap_queries();
+}
@@ -0,0 +1,26 @@
+#![feature(core_intrinsics)]
+
+use std::intrinsics::{
+    cheri_cap_base_get, cheri_cap_length_get, cheri_cap_perms_get, cheri_cap_sealed_get,
+    cheri_cap_tag_get, cheri_cap_type_get,
+};
+use std::ptr;
+
+// Without `-Zmiri-cheri`, pointers are queried like capabilities that cover the whole address
+// space with every permission.
+fn main() {
+    let x = 0u8;
+    let ptr: *const u8 = &x;
+    assert_eq!(cheri_cap_base_get(ptr), 0);
+    assert_eq!(cheri_cap_length_get(ptr), usize::MAX);
+    assert_eq!(cheri_cap_perms_get(ptr), usize::MAX);
+    assert!(cheri_cap_tag_get(ptr));
+    assert!(!cheri_cap_sealed_get(ptr));
+    assert_eq!(cheri_cap_type_get(ptr), 0);
+
+    assert!(!cheri_cap_tag_get(ptr::null::<u8>()));
+
+    // Only the alternate form prints capability metadata, and only on capability targets.
+    let width = 2 + 2 * std::mem::size_of::<usize>();
+    assert_eq!(format!("{:#p}", ptr), format!("{:#0width$x}", ptr as usize));
+}
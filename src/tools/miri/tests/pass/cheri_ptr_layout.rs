@@ -0,0 +1,94 @@
+//@only-target-purecap: tests the layout of capability targets
+#![feature(strict_provenance)]
+
+use std::alloc::{alloc, dealloc, Layout};
+use std::mem::{align_of, size_of};
+use std::ptr;
+use std::sync::atomic::{AtomicPtr, Ordering};
+use std::thread;
+
+extern "C" {
+    fn malloc(size: usize) -> *mut u8;
+    fn free(ptr: *mut u8);
+}
+
+fn sizes() {
+    // Pointers are twice as large as their address.
+    assert_eq!(size_of::<*const u8>(), 16);
+    assert_eq!(align_of::<*const u8>(), 16);
+    assert_eq!(size_of::<&[u8]>(), 32);
+    assert_eq!(size_of::<usize>(), 8);
+    assert_eq!(size_of::<Option<&u8>>(), 16);
+}
+
+fn ptrs_in_memory() {
+    let a = [1u32, 2, 3];
+    let ptrs = [&a[0] as *const u32, &a[1], &a[2]];
+    // Copying an array of pointers keeps them intact.
+    let mut copy = [ptr::null(); 3];
+    unsafe { ptr::copy_nonoverlapping(ptrs.as_ptr(), copy.as_mut_ptr(), 3) };
+    assert_eq!(unsafe { *copy[2] }, 3);
+    // The address of a pointer is a `usize`.
+    assert_eq!(copy[1].addr() - copy[0].addr(), 4);
+    // Reading an integer as a pointer only uses the address part of its bits.
+    let mut p = &a[0] as *const u32;
+    unsafe { ptr::addr_of_mut!(p).cast::<u128>().write((1 << 64) | 16) };
+    assert_eq!(p.addr(), 16);
+}
+
+fn wide_pointer_comparisons() {
+    // Wide pointers are a capability followed by `usize` metadata.
+    let a = [1u8, 2, 3, 4];
+    let (x, y): (&[u8], &[u8]) = (&a[..2], &a[..3]);
+    assert!(ptr::eq(x, x));
+    assert!(!ptr::eq(x, y));
+    assert!((x as *const [u8]) < (y as *const [u8]));
+
+    let s = "hello";
+    assert!(ptr::eq(s, &s[..]));
+    assert!(!ptr::eq(s, &s[..4]));
+
+    let d: &dyn std::fmt::Debug = &a;
+    let e: &dyn std::fmt::Debug = &s;
+    assert!(ptr::eq(d, d));
+    assert!(!ptr::eq(d as *const dyn std::fmt::Debug, e as *const dyn std::fmt::Debug));
+}
+
+fn allocations() {
+    unsafe {
+        let p = malloc(24);
+        assert_eq!(p.addr() % 16, 0);
+        p.cast::<*const u8>().write(p);
+        assert_eq!(p.cast::<*const u8>().read(), p);
+        free(p);
+
+        let layout = Layout::new::<[*const u8; 4]>();
+        let p = alloc(layout).cast::<*const u8>();
+        p.add(3).write(ptr::null());
+        assert!(p.add(3).read().is_null());
+        dealloc(p.cast(), layout);
+    }
+}
+
+fn atomics_and_threads() {
+    static mut X: u8 = 0;
+    static P: AtomicPtr<u8> = AtomicPtr::new(ptr::null_mut());
+    P.store(unsafe { ptr::addr_of_mut!(X) }, Ordering::Release);
+    let t = thread::spawn(|| P.load(Ordering::Acquire).addr());
+    assert_eq!(t.join().unwrap(), unsafe { ptr::addr_of_mut!(X) }.addr());
+}
+
+fn env() {
+    std::env::set_var("MIRI_CHERI_TEST", "1");
+    assert_eq!(std::env::var("MIRI_CHERI_TEST").unwrap(), "1");
+    assert!(std::env::vars().any(|(k, _)| k == "MIRI_CHERI_TEST"));
+}
+
+fn main() {
+    sizes();
+    ptrs_in_memory();
+    wide_pointer_comparisons();
+    allocations();
+    atomics_and_threads();
+    env();
+}
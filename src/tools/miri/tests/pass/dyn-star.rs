@@ -0,0 +1,44 @@
+#![feature(dyn_star)]
+#![allow(incomplete_features)]
+
+use std::fmt::Debug;
+
+trait Get {
+    fn get(&self) -> usize;
+}
+
+impl Get for usize {
+    fn get(&self) -> usize {
+        *self
+    }
+}
+
+impl Get for &usize {
+    fn get(&self) -> usize {
+        **self
+    }
+}
+
+fn get(x: dyn* Get) -> usize {
+    x.get()
+}
+
+fn integer_data() {
+    let x: dyn* Get = 42usize;
+    assert_eq!(get(x), 42);
+}
+
+fn pointer_data() {
+    // The pointer keeps its provenance, so it can still be dereferenced through the `dyn*`.
+    let val = 7usize;
+    let x: dyn* Get = &val;
+    assert_eq!(get(x), 7);
+
+    let x: dyn* Debug = &val;
+    assert_eq!(format!("{x:?}"), "7");
+}
+
+fn main() {
+    integer_data();
+    pointer_data();
+}
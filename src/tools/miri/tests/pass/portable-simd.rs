@@ -357,7 +357,15 @@ fn simd_intrinsics() {
         fn simd_reduce_any<T>(x: T) -> bool;
         fn simd_reduce_all<T>(x: T) -> bool;
         fn simd_select<M, T>(m: M, yes: T, no: T) -> T;
+        fn simd_addr<T, U>(x: T) -> U;
+        fn simd_with_addr<T, U>(x: T, y: U) -> T;
     }
+
+    #[repr(simd)]
+    #[derive(Copy, Clone)]
+    #[allow(non_camel_case_types)]
+    struct ptrx2([*const i32; 2]);
+
     unsafe {
         // Make sure simd_eq returns all-1 for `true`
         let a = i32x4::splat(10);
@@ -380,6 +388,15 @@ fn simd_intrinsics() {
             simd_select(i8x4::from_array([0, -1, -1, 0]), b, a),
             i32x4::from_array([10, 2, 10, 10])
         );
+
+        // Pointers keep their provenance when their addresses are replaced.
+        let x = [1i32, 2, 3, 4];
+        let ptrs = ptrx2([x.as_ptr(), x.as_ptr().wrapping_add(1)]);
+        let addrs: usizex2 = simd_addr(ptrs);
+        assert_eq!(addrs[1] - addrs[0], 4);
+        let moved: ptrx2 = simd_with_addr(ptrs, addrs + usizex2::splat(8));
+        assert_eq!(*moved.0[0], 3);
+        assert_eq!(*moved.0[1], 4);
     }
 }
 
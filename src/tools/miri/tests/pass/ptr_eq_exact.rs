@@ -0,0 +1,18 @@
+#![feature(ptr_eq_exact, strict_provenance)]
+
+use std::ptr;
+
+fn main() {
+    let x = [0u8; 4];
+    let a = x.as_ptr();
+    assert!(ptr::eq_exact(a, a));
+    assert!(ptr::eq_exact(a, a.wrapping_add(1).wrapping_sub(1)));
+    assert!(!ptr::eq_exact(a, a.wrapping_add(1)));
+    assert!(!ptr::eq_exact(&x[..2], &x[..3]));
+
+    // Pointers with the same address but different provenance are not exactly equal.
+    let y = [0u8; 4];
+    let b = y.as_ptr().with_addr(a.addr());
+    assert!(a == b);
+    assert!(!ptr::eq_exact(a, b));
+}
@@ -3,7 +3,7 @@
 //! push artifacts and run tests on the server instead of locally.
 //!
 //! Here is also where we bake in the support to spawn the QEMU emulator as
-//! well.
+//! well, including booting a CheriBSD disk image for CHERI targets.
 
 use std::env;
 use std::fs::{self, File};
@@ -13,11 +13,17 @@
 use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 const REMOTE_ADDR_ENV: &str = "TEST_DEVICE_ADDR";
 const DEFAULT_ADDR: &str = "127.0.0.1:12345";
 
+// Host port forwarded to the SSH server of a CheriBSD guest, which is how we get the test server
+// into it and running.
+const CHERIBSD_SSH_PORT: &str = "12346";
+// Booting CheriBSD under emulation takes a while, so be generous before giving up on it.
+const CHERIBSD_BOOT_TIMEOUT: Duration = Duration::from_secs(15 * 60);
+
 macro_rules! t {
     ($e:expr) => {
         match $e {
@@ -41,6 +47,7 @@ fn main() {
             Path::new(&args.next().unwrap()),
             args.next().map(|s| s.into()),
         ),
+        "stop-emulator" => stop_emulator(Path::new(&args.next().unwrap())),
         "push" => push(Path::new(&args.next().unwrap())),
         "run" => run(
             args.next().and_then(|count| count.parse().ok()).unwrap(),
@@ -64,6 +71,9 @@ fn spawn_emulator(target: &str, server: &Path, tmpdir: &Path, rootfs: Option<Pat
         println!("Connecting to remote device {} ...", device_address);
     } else if target.contains("android") {
         start_android_emulator(server);
+    } else if target.contains("freebsd") {
+        let image = rootfs.as_ref().expect("need a CheriBSD disk image");
+        start_cheribsd_emulator(target, image, server, tmpdir);
     } else {
         let rootfs = rootfs.as_ref().expect("need rootfs on non-android");
         start_qemu_emulator(target, rootfs, server, tmpdir);
@@ -239,6 +249,108 @@ fn start_qemu_emulator(target: &str, rootfs: &Path, server: &Path, tmpdir: &Path
     }
 }
 
+fn start_cheribsd_emulator(target: &str, image: &Path, server: &Path, tmpdir: &Path) {
+    // Don't fight over the forwarded ports with an emulator a previous run failed to shut down.
+    stop_emulator(tmpdir);
+
+    let mut cmd = match target {
+        "morello-unknown-freebsd-purecap" => {
+            let mut cmd = Command::new("qemu-system-morello");
+            cmd.arg("-M")
+                .arg("virt,gic-version=3")
+                .arg("-cpu")
+                .arg("morello")
+                .arg("-bios")
+                .arg("edk2-aarch64-code.fd");
+            cmd
+        }
+        _ => panic!("cannot start CheriBSD emulator for: {}", target),
+    };
+    // `-snapshot` keeps the guest from writing back to the disk image, so every run boots the
+    // same system.
+    cmd.arg("-m")
+        .arg("2048")
+        .arg("-snapshot")
+        .arg("-drive")
+        .arg(&format!("if=none,file={},id=drv,format=raw", image.display()))
+        .arg("-device")
+        .arg("virtio-blk-pci,drive=drv")
+        .arg("-netdev")
+        .arg(&format!(
+            "user,id=net0,hostfwd=tcp::12345-:12345,hostfwd=tcp::{}-:22",
+            CHERIBSD_SSH_PORT
+        ))
+        .arg("-device")
+        .arg("virtio-net-pci,netdev=net0")
+        .arg("-display")
+        .arg("none")
+        .arg("-serial")
+        .arg(&format!("file:{}", tmpdir.join("cheribsd-console.log").display()))
+        .arg("-pidfile")
+        .arg(tmpdir.join("qemu.pid"))
+        .stdin(Stdio::null());
+    t!(cmd.spawn());
+
+    println!("waiting for CheriBSD to boot");
+    let start = Instant::now();
+    while !cheribsd_ssh().arg("true").stderr(Stdio::null()).status().unwrap().success() {
+        if start.elapsed() > CHERIBSD_BOOT_TIMEOUT {
+            stop_emulator(tmpdir);
+            panic!(
+                "CheriBSD did not come up within {:?}, see {} for its console output",
+                CHERIBSD_BOOT_TIMEOUT,
+                tmpdir.join("cheribsd-console.log").display()
+            );
+        }
+        thread::sleep(Duration::from_secs(5));
+    }
+
+    println!("pushing server");
+    let status = Command::new("scp")
+        .args(CHERIBSD_SSH_OPTIONS)
+        .arg("-P")
+        .arg(CHERIBSD_SSH_PORT)
+        .arg(server)
+        .arg("root@localhost:/tmp/testd")
+        .status()
+        .unwrap();
+    assert!(status.success());
+
+    println!("executing server");
+    // daemon(8) detaches the server from the SSH session so it outlives it.
+    let status = cheribsd_ssh().arg("daemon -f /tmp/testd").status().unwrap();
+    assert!(status.success());
+}
+
+const CHERIBSD_SSH_OPTIONS: &[&str] = &[
+    "-o",
+    "StrictHostKeyChecking=no",
+    "-o",
+    "UserKnownHostsFile=/dev/null",
+    "-o",
+    "LogLevel=ERROR",
+    "-o",
+    "ConnectTimeout=5",
+];
+
+fn cheribsd_ssh() -> Command {
+    let mut cmd = Command::new("ssh");
+    cmd.args(CHERIBSD_SSH_OPTIONS).arg("-p").arg(CHERIBSD_SSH_PORT).arg("root@localhost");
+    cmd
+}
+
+fn stop_emulator(tmpdir: &Path) {
+    let pidfile = tmpdir.join("qemu.pid");
+    let pid = match fs::read_to_string(&pidfile) {
+        Ok(pid) => pid,
+        Err(_) => return,
+    };
+    println!("stopping emulator");
+    // The emulator may already be gone, in which case there is nothing left to do.
+    let _ = Command::new("kill").arg(pid.trim()).status();
+    t!(fs::remove_file(&pidfile));
+}
+
 fn push(path: &Path) {
     let device_address = env::var(REMOTE_ADDR_ENV).unwrap_or(DEFAULT_ADDR.to_string());
     let client = t!(TcpStream::connect(device_address));
@@ -360,6 +472,7 @@ fn help() {
 
 Sub-commands:
     spawn-emulator <target> <server> <tmpdir> [rootfs]   See below
+    stop-emulator <tmpdir>                               Shut down an emulator spawned with <tmpdir>
     push <path>                                          Copy <path> to emulator
     run <support_lib_count> <file> [support_libs...] [args...]
                                                          Run program on emulator
@@ -370,6 +483,9 @@ fn help() {
 For Android <target>s, adb will push the <server>, set up TCP forwarding and run
 the <server>. Otherwise qemu emulates the target using a rootfs image created in
 <tmpdir> and generated from <rootfs> plus the <server> executable.
+For CheriBSD <target>s, <rootfs> is instead a CheriBSD disk image which qemu
+boots without modifying it. The <server> is copied into the guest and started
+over SSH, so the image must accept SSH logins as root with the user's key.
 If {1} is set in the environment, this step is skipped.
 
 Pushing a path to a running emulator:
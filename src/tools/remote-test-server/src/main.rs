@@ -263,7 +263,13 @@ fn handle_run(socket: TcpStream, work: &Path, tmp: &Path, lock: &Mutex<()>, conf
     if let Some(library_path) = env::var_os(library_path) {
         paths.extend(env::split_paths(&library_path));
     }
-    cmd.env(library_path, env::join_paths(paths).unwrap());
+    let paths = env::join_paths(paths).unwrap();
+    // CheriBSD releases whose base system uses the hybrid ABI load pure-capability programs
+    // with a separate run-time linker, which has its own search path.
+    if cfg!(all(target_os = "freebsd", target_pointer_type_width = "128")) {
+        cmd.env("LD_CHERI_LIBRARY_PATH", &paths);
+    }
+    cmd.env(library_path, paths);
 
     // Some tests assume RUST_TEST_TMPDIR exists
     cmd.env("RUST_TEST_TMPDIR", tmp.to_owned());
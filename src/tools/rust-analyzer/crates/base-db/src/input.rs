@@ -275,6 +275,9 @@ pub struct CrateData {
     pub proc_macro: ProcMacroLoadResult,
     pub origin: CrateOrigin,
     pub is_proc_macro: bool,
+    /// The LLVM data layout string of the crate's target, as in the `data-layout` field of
+    /// `rustc --print target-spec-json`, if it could be queried.
+    pub target_layout: Option<Arc<str>>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -341,6 +344,7 @@ pub fn add_crate_root(
             dependencies: Vec::new(),
             origin,
             is_proc_macro,
+            target_layout: None,
         };
         let crate_id = CrateId(self.arena.len() as u32);
         let prev = self.arena.insert(crate_id, data);
@@ -369,6 +373,11 @@ pub fn add_dep(
         Ok(())
     }
 
+    /// Sets the data layout of the target that `krate` is compiled for.
+    pub fn set_target_layout(&mut self, krate: CrateId, target_layout: Option<Arc<str>>) {
+        self.arena.get_mut(&krate).unwrap().target_layout = target_layout;
+    }
+
     pub fn is_empty(&self) -> bool {
         self.arena.is_empty()
     }
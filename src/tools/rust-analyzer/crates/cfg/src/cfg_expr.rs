@@ -34,6 +34,7 @@ pub fn is_target_defined(&self) -> bool {
                     | "target_family"
                     | "target_endian"
                     | "target_pointer_width"
+                    | "target_pointer_type_width"
                     | "target_vendor" // NOTE: `target_feature` is left out since it can be configured via `-Ctarget-feature`
             ),
         }
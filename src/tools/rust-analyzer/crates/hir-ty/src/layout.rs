@@ -0,0 +1,656 @@
+//! Computes the size, alignment and field offsets of types for the target of a crate, the way
+//! `rustc_ty_utils::layout` does.
+
+use std::cmp;
+
+use base_db::CrateId;
+use chalk_ir::{FloatTy, IntTy, UintTy};
+use hir_def::{
+    adt::{ReprData, ReprKind},
+    attr::Attrs,
+    expr::{Expr, Literal},
+    type_ref::ConstScalar,
+    AdtId, EnumId, EnumVariantId, HasModule, VariantId,
+};
+use itertools::Either;
+
+use crate::{
+    consteval::ComputedExpr,
+    db::HirDatabase,
+    primitive::{int_ty_from_builtin, uint_ty_from_builtin},
+    Const, ConstValue, Interner, Scalar, Substitution, Ty, TyKind,
+};
+
+mod target;
+
+pub(crate) use self::target::target_data_layout_query;
+pub use self::target::{PointerLayout, TargetDataLayout};
+
+/// How values of a type are laid out in memory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Layout {
+    /// The size in bytes, which is a multiple of `align`.
+    pub size: u64,
+    /// The ABI alignment in bytes.
+    pub align: u64,
+    /// The offsets of the fields of a struct, union or tuple in bytes, in declaration order.
+    /// Empty for all other types.
+    pub field_offsets: Vec<u64>,
+    /// The scalar with the most invalid values, which enums can store their discriminant in.
+    niche: Option<Niche>,
+    uninhabited: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Niche {
+    offset: u64,
+    size: u64,
+    /// The number of values the scalar never takes.
+    available: u128,
+}
+
+impl Niche {
+    fn new(offset: u64, size: u64, available: u128) -> Option<Niche> {
+        (available > 0).then(|| Niche { offset, size, available })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LayoutError {
+    /// The type depends on generic parameters, or hasn't been inferred.
+    HasPlaceholder,
+    /// The type is dynamically sized.
+    Unsized,
+    /// The type contains itself.
+    Recursive,
+    /// The type is too large for the target.
+    SizeOverflow,
+    /// We don't know how the type is laid out, as for closures.
+    NotImplemented,
+}
+
+impl Layout {
+    fn scalar(size: u64, align: u64, available: u128) -> Layout {
+        Layout {
+            size,
+            align,
+            field_offsets: Vec::new(),
+            niche: Niche::new(0, size, available),
+            uninhabited: false,
+        }
+    }
+
+    fn zst(uninhabited: bool) -> Layout {
+        Layout { size: 0, align: 1, field_offsets: Vec::new(), niche: None, uninhabited }
+    }
+}
+
+/// The parts of a `#[repr]` attribute that matter for layout.
+#[derive(Debug, Default, Clone, Copy)]
+struct Repr {
+    c: bool,
+    packed: bool,
+    align: Option<u64>,
+    /// The size of the discriminant that `#[repr(u8)]` and the like ask for.
+    int: Option<u64>,
+}
+
+impl Repr {
+    fn new(dl: &TargetDataLayout, data: Option<ReprData>) -> Repr {
+        let data = match data {
+            Some(it) => it,
+            None => return Repr::default(),
+        };
+        let int = match data.kind {
+            ReprKind::BuiltinInt { builtin: Either::Left(it), .. } => {
+                Some(int_size(dl, int_ty_from_builtin(it)))
+            }
+            ReprKind::BuiltinInt { builtin: Either::Right(it), .. } => {
+                Some(uint_size(dl, uint_ty_from_builtin(it)))
+            }
+            _ => None,
+        };
+        Repr {
+            c: matches!(data.kind, ReprKind::C | ReprKind::BuiltinInt { is_c: true, .. }),
+            // FIXME: `ReprData` doesn't record the `N` of `packed(N)`, so we always pack to 1.
+            packed: data.packed,
+            align: data.align.map(|it| it.get().into()),
+            int,
+        }
+    }
+
+    fn reorder_fields(&self) -> bool {
+        !self.c && !self.packed && self.int.is_none()
+    }
+
+    fn inhibit_enum_layout_opt(&self) -> bool {
+        self.c || self.int.is_some()
+    }
+}
+
+pub fn layout_of_ty(db: &dyn HirDatabase, ty: &Ty, krate: CrateId) -> Result<Layout, LayoutError> {
+    let dl = &*db.target_data_layout(krate);
+    let layout = match ty.kind(Interner) {
+        TyKind::Adt(chalk_ir::AdtId(def), subst) => db.layout_of_adt(*def, subst.clone())?,
+        TyKind::Scalar(scalar) => scalar_layout(dl, scalar),
+        TyKind::Tuple(_, subst) => {
+            let fields = subst
+                .iter(Interner)
+                .map(|it| layout_of_ty(db, it.assert_ty_ref(Interner), krate))
+                .collect::<Result<Vec<_>, _>>()?;
+            univariant(dl, &fields, &Repr::default(), None)?
+        }
+        TyKind::Array(element, count) => {
+            let count = const_usize(count).ok_or(LayoutError::HasPlaceholder)?;
+            let element = layout_of_ty(db, element, krate)?;
+            let size = element.size.checked_mul(count).ok_or(LayoutError::SizeOverflow)?;
+            check_size(dl, size)?;
+            Layout {
+                size,
+                align: element.align,
+                field_offsets: Vec::new(),
+                niche: element.niche.filter(|_| count > 0),
+                uninhabited: element.uninhabited && count > 0,
+            }
+        }
+        TyKind::Ref(_, _, pointee) => pointer_layout(db, dl, pointee, true),
+        TyKind::Raw(_, pointee) => pointer_layout(db, dl, pointee, false),
+        TyKind::Function(_) => {
+            let pointer = dl.code_pointer();
+            Layout::scalar(pointer.size, pointer.align, 1)
+        }
+        TyKind::FnDef(..) => Layout::zst(false),
+        TyKind::Never => Layout::zst(true),
+        TyKind::Str | TyKind::Slice(_) | TyKind::Dyn(_) | TyKind::Foreign(_) => {
+            return Err(LayoutError::Unsized)
+        }
+        TyKind::Placeholder(_) | TyKind::BoundVar(_) | TyKind::InferenceVar(..) | TyKind::Error => {
+            return Err(LayoutError::HasPlaceholder)
+        }
+        TyKind::Alias(_)
+        | TyKind::AssociatedType(..)
+        | TyKind::OpaqueType(..)
+        | TyKind::Closure(..)
+        | TyKind::Generator(..)
+        | TyKind::GeneratorWitness(..) => return Err(LayoutError::NotImplemented),
+    };
+    Ok(layout)
+}
+
+pub(crate) fn layout_of_adt_query(
+    db: &dyn HirDatabase,
+    def: AdtId,
+    subst: Substitution,
+) -> Result<Layout, LayoutError> {
+    let krate = def.module(db.upcast()).krate();
+    let dl = &*db.target_data_layout(krate);
+    let field_layouts = |variant: VariantId| {
+        db.field_types(variant)
+            .iter()
+            .map(|(_, ty)| layout_of_ty(db, &ty.clone().substitute(Interner, &subst), krate))
+            .collect::<Result<Vec<_>, _>>()
+    };
+    match def {
+        AdtId::StructId(id) => {
+            let repr = Repr::new(dl, db.struct_data(id).repr);
+            let mut layout = univariant(dl, &field_layouts(id.into())?, &repr, None)?;
+            let attrs = db.attrs(def.into());
+            if attrs.lang().map(|it| &**it) == Some("unsafe_cell") {
+                layout.niche = None;
+            } else if let Some(available) = valid_range_niche(&attrs, layout.size) {
+                if available >= layout.niche.map_or(0, |it| it.available) {
+                    layout.niche = Niche::new(0, layout.size, available);
+                }
+            }
+            Ok(layout)
+        }
+        AdtId::UnionId(id) => {
+            let repr = Repr::new(dl, db.union_data(id).repr);
+            union_layout(dl, &field_layouts(id.into())?, &repr)
+        }
+        AdtId::EnumId(id) => enum_layout(db, dl, id, field_layouts),
+    }
+}
+
+pub(crate) fn layout_of_adt_recover(
+    _: &dyn HirDatabase,
+    _: &[String],
+    _: &AdtId,
+    _: &Substitution,
+) -> Result<Layout, LayoutError> {
+    Err(LayoutError::Recursive)
+}
+
+fn int_size(dl: &TargetDataLayout, ty: IntTy) -> u64 {
+    match ty {
+        IntTy::Isize => dl.data_pointer().index_size,
+        IntTy::I8 => 1,
+        IntTy::I16 => 2,
+        IntTy::I32 => 4,
+        IntTy::I64 => 8,
+        IntTy::I128 => 16,
+    }
+}
+
+fn uint_size(dl: &TargetDataLayout, ty: UintTy) -> u64 {
+    match ty {
+        UintTy::Usize => dl.data_pointer().index_size,
+        UintTy::U8 => 1,
+        UintTy::U16 => 2,
+        UintTy::U32 => 4,
+        UintTy::U64 => 8,
+        UintTy::U128 => 16,
+    }
+}
+
+fn scalar_layout(dl: &TargetDataLayout, scalar: &Scalar) -> Layout {
+    let int = |size| Layout::scalar(size, dl.int_align(size), 0);
+    match scalar {
+        Scalar::Bool => Layout::scalar(1, dl.i8_align, 254),
+        Scalar::Char => Layout::scalar(4, dl.i32_align, (1 << 32) - 0x11_0000),
+        Scalar::Int(ty) => int(int_size(dl, *ty)),
+        Scalar::Uint(ty) => int(uint_size(dl, *ty)),
+        Scalar::Float(FloatTy::F32) => Layout::scalar(4, dl.f32_align, 0),
+        Scalar::Float(FloatTy::F64) => Layout::scalar(8, dl.f64_align, 0),
+    }
+}
+
+fn const_usize(c: &Const) -> Option<u64> {
+    match &c.data(Interner).value {
+        ConstValue::Concrete(c) => match c.interned {
+            ConstScalar::Int(it) => it.try_into().ok(),
+            ConstScalar::UInt(it) => it.try_into().ok(),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+enum Metadata {
+    Length,
+    VTable,
+}
+
+/// Returns what a pointer to `ty` carries besides the address, if `ty` is dynamically sized.
+fn pointee_metadata(db: &dyn HirDatabase, ty: &Ty) -> Option<Metadata> {
+    // Follow the last field of structs and tuples to their unsized tail, if there is one.
+    let mut ty = ty.clone();
+    for _ in 0..64 {
+        ty = match ty.kind(Interner) {
+            TyKind::Str | TyKind::Slice(_) => return Some(Metadata::Length),
+            TyKind::Dyn(_) => return Some(Metadata::VTable),
+            TyKind::Tuple(_, subst) => subst.iter(Interner).last()?.assert_ty_ref(Interner).clone(),
+            TyKind::Adt(chalk_ir::AdtId(AdtId::StructId(id)), subst) => {
+                let field_types = db.field_types((*id).into());
+                let (_, last) = field_types.iter().last()?;
+                last.clone().substitute(Interner, subst)
+            }
+            _ => return None,
+        };
+    }
+    None
+}
+
+fn pointer_layout(
+    db: &dyn HirDatabase,
+    dl: &TargetDataLayout,
+    pointee: &Ty,
+    non_null: bool,
+) -> Layout {
+    let pointer = dl.data_pointer();
+    let address = Layout::scalar(pointer.size, pointer.align, non_null.into());
+    let metadata = match pointee_metadata(db, pointee) {
+        None => return address,
+        Some(Metadata::Length) => {
+            Layout::scalar(pointer.index_size, dl.int_align(pointer.index_size), 0)
+        }
+        Some(Metadata::VTable) => Layout::scalar(pointer.size, pointer.align, 1),
+    };
+    // Wide pointers are a pair of scalars, like two-field structs that are never reordered.
+    let metadata_offset = align_to(address.size, metadata.align);
+    let align = dl.aggregate_align.max(address.align).max(metadata.align);
+    let niche = match (address.niche, metadata.niche) {
+        (Some(a), Some(b)) if b.available > a.available => {
+            Some(Niche { offset: metadata_offset, ..b })
+        }
+        (None, Some(b)) => Some(Niche { offset: metadata_offset, ..b }),
+        (a, _) => a,
+    };
+    Layout {
+        size: align_to(metadata_offset + metadata.size, align),
+        align,
+        field_offsets: Vec::new(),
+        niche,
+        uninhabited: false,
+    }
+}
+
+fn align_to(offset: u64, align: u64) -> u64 {
+    (offset + align - 1) / align * align
+}
+
+fn check_size(dl: &TargetDataLayout, size: u64) -> Result<(), LayoutError> {
+    // Sizes must fit in an `isize`, and LLVM wants them in bits as a `u64`.
+    let bound = match dl.data_pointer().index_size {
+        2 => 1 << 15,
+        4 => 1 << 31,
+        _ => 1 << 47,
+    };
+    if size < bound {
+        Ok(())
+    } else {
+        Err(LayoutError::SizeOverflow)
+    }
+}
+
+/// Lays out the fields of a struct, tuple or enum variant, after a prefix holding the
+/// discriminant of an enum if there is one.
+fn univariant(
+    dl: &TargetDataLayout,
+    fields: &[Layout],
+    repr: &Repr,
+    prefix: Option<(u64, u64)>,
+) -> Result<Layout, LayoutError> {
+    let field_align = |field: &Layout| if repr.packed { 1 } else { field.align };
+    let mut align = if repr.packed { 1 } else { dl.aggregate_align };
+
+    let mut memory_order = (0..fields.len()).collect::<Vec<_>>();
+    if repr.reorder_fields() {
+        match prefix {
+            // Put ZSTs first, then sort by decreasing alignment to avoid padding.
+            None => memory_order
+                .sort_by_key(|&i| (fields[i].size != 0, cmp::Reverse(field_align(&fields[i])))),
+            // Sort by increasing alignment so that no padding follows the discriminant.
+            Some(_) => memory_order.sort_by_key(|&i| field_align(&fields[i])),
+        }
+    }
+
+    let mut offsets = vec![0; fields.len()];
+    let mut offset = 0;
+    let mut niche: Option<Niche> = None;
+    if let Some((prefix_size, prefix_align)) = prefix {
+        let prefix_align = if repr.packed { 1 } else { prefix_align };
+        align = align.max(prefix_align);
+        offset = align_to(prefix_size, prefix_align);
+    }
+    for i in memory_order {
+        let field = &fields[i];
+        offset = align_to(offset, field_align(field));
+        align = align.max(field_align(field));
+        offsets[i] = offset;
+        if let Some(field_niche) = field.niche {
+            if field_niche.available > niche.map_or(0, |it| it.available) {
+                niche = Some(Niche { offset: offset + field_niche.offset, ..field_niche });
+            }
+        }
+        offset = offset.checked_add(field.size).ok_or(LayoutError::SizeOverflow)?;
+    }
+    if let Some(repr_align) = repr.align {
+        align = align.max(repr_align);
+    }
+    let size = align_to(offset, align);
+    check_size(dl, size)?;
+    Ok(Layout {
+        size,
+        align,
+        field_offsets: offsets,
+        niche,
+        uninhabited: fields.iter().any(|it| it.uninhabited),
+    })
+}
+
+fn union_layout(
+    dl: &TargetDataLayout,
+    fields: &[Layout],
+    repr: &Repr,
+) -> Result<Layout, LayoutError> {
+    let mut align = if repr.packed { 1 } else { dl.aggregate_align };
+    let mut size = 0;
+    for field in fields {
+        align = align.max(if repr.packed { 1 } else { field.align });
+        size = size.max(field.size);
+    }
+    if let Some(repr_align) = repr.align {
+        align = align.max(repr_align);
+    }
+    let size = align_to(size, align);
+    check_size(dl, size)?;
+    Ok(Layout {
+        size,
+        align,
+        field_offsets: vec![0; fields.len()],
+        niche: None,
+        uninhabited: false,
+    })
+}
+
+/// Returns the number of values that `#[rustc_layout_scalar_valid_range_start]` and
+/// `#[rustc_layout_scalar_valid_range_end]` rule out for a scalar of `size` bytes, if the
+/// type has them.
+fn valid_range_niche(attrs: &Attrs, size: u64) -> Option<u128> {
+    let bound = |key| {
+        // The attribute's argument is a single integer literal, as in `(1)`.
+        let tt = attrs.by_key(key).tt_values().next()?.to_string();
+        tt.trim_start_matches('(').trim_end_matches(')').replace('_', "").parse().ok()
+    };
+    let start: Option<u128> = bound("rustc_layout_scalar_valid_range_start");
+    let end: Option<u128> = bound("rustc_layout_scalar_valid_range_end");
+    if start.is_none() && end.is_none() {
+        return None;
+    }
+    let max_value = max_value(size);
+    Some(start.unwrap_or(0) + (max_value - end.unwrap_or(max_value)))
+}
+
+fn max_value(size: u64) -> u128 {
+    if size >= 16 {
+        u128::MAX
+    } else {
+        (1 << (size * 8)) - 1
+    }
+}
+
+fn enum_layout(
+    db: &dyn HirDatabase,
+    dl: &TargetDataLayout,
+    id: EnumId,
+    field_layouts: impl Fn(VariantId) -> Result<Vec<Layout>, LayoutError>,
+) -> Result<Layout, LayoutError> {
+    let data = db.enum_data(id);
+    let repr = Repr::new(dl, data.repr);
+    let variant_ids =
+        data.variants.iter().map(|(local_id, _)| EnumVariantId { parent: id, local_id });
+    let variants =
+        variant_ids.clone().map(|it| field_layouts(it.into())).collect::<Result<Vec<_>, _>>()?;
+
+    // Uninhabited variants without data don't need a discriminant value.
+    let absent = |fields: &[Layout]| {
+        fields.iter().any(|it| it.uninhabited) && fields.iter().all(|it| it.size == 0)
+    };
+    let mut present = variants.iter().enumerate().filter(|(_, it)| !absent(&it[..]));
+    let present_first = match present.next() {
+        Some((i, _)) => i,
+        None => return Ok(Layout::zst(true)),
+    };
+    if present.next().is_none() && !repr.inhibit_enum_layout_opt() {
+        let mut layout = univariant(dl, &variants[present_first], &repr, None)?;
+        layout.field_offsets.clear();
+        return Ok(layout);
+    }
+
+    let has_explicit_discriminant = variant_ids.clone().any(|it| {
+        let body = db.body(it.into());
+        !matches!(body.exprs[body.body_expr], Expr::Missing)
+    });
+    let discriminants = variant_ids.enumerate().map(|(i, it)| match db.const_eval_variant(it) {
+        Ok(ComputedExpr::Literal(Literal::Int(it, _))) => it,
+        Ok(ComputedExpr::Literal(Literal::Uint(it, _))) => it as i128,
+        _ => i as i128,
+    });
+
+    let niche_filling = if repr.inhibit_enum_layout_opt() || has_explicit_discriminant {
+        None
+    } else {
+        niche_filling_layout(dl, &variants, &repr, absent)?
+    };
+
+    let (mut min, mut max) = (i128::MAX, i128::MIN);
+    for (fields, discriminant) in variants.iter().zip(discriminants) {
+        if !fields.iter().any(|it| it.uninhabited) {
+            min = min.min(discriminant);
+            max = max.max(discriminant);
+        }
+    }
+    if min > max {
+        (min, max) = (0, 0);
+    }
+    let min_tag_size = discriminant_size(&repr, min, max);
+
+    // With `#[repr(C)]`, the fields of all variants start after the discriminant, at the
+    // alignment of the most aligned one.
+    let mut prefix_align = dl.int_align(min_tag_size);
+    if repr.c {
+        for field in variants.iter().flatten() {
+            prefix_align = prefix_align.max(field.align);
+        }
+    }
+    let mut align = dl.aggregate_align;
+    let mut size = 0;
+    let mut start_align = 256;
+    let mut variant_layouts = Vec::with_capacity(variants.len());
+    for fields in &variants {
+        let layout = univariant(dl, fields, &repr, Some((min_tag_size, prefix_align)))?;
+        let mut memory_order = (0..fields.len()).collect::<Vec<_>>();
+        memory_order.sort_by_key(|&i| layout.field_offsets[i]);
+        if let Some(first) = memory_order
+            .into_iter()
+            .map(|i| &fields[i])
+            .find(|field| field.size != 0 || field.align != 1)
+        {
+            start_align = start_align.min(first.align);
+        }
+        size = size.max(layout.size);
+        align = align.max(layout.align);
+        variant_layouts.push(layout);
+    }
+    let size = align_to(size, align);
+    check_size(dl, size)?;
+
+    // The discriminant grows up to the alignment of the first field of every variant, as that is
+    // padding otherwise.
+    let tag_size = if repr.inhibit_enum_layout_opt() {
+        min_tag_size
+    } else {
+        [1, 2, 4, 8, 16]
+            .into_iter()
+            .find(|&it| it == start_align && dl.int_align(it) == start_align)
+            .unwrap_or(min_tag_size)
+            .max(min_tag_size)
+    };
+    let valid_values = (max as u128).wrapping_sub(min as u128) & max_value(tag_size);
+    let tagged = Layout {
+        size,
+        align,
+        field_offsets: Vec::new(),
+        niche: Niche::new(0, tag_size, max_value(tag_size) - valid_values),
+        uninhabited: variant_layouts.iter().all(|it| it.uninhabited),
+    };
+
+    let available = |layout: &Layout| layout.niche.map_or(0, |it| it.available);
+    Ok(match niche_filling {
+        Some(niche_filling)
+            if niche_filling.size < tagged.size
+                || niche_filling.size == tagged.size
+                    && available(&niche_filling) > available(&tagged) =>
+        {
+            niche_filling
+        }
+        _ => tagged,
+    })
+}
+
+/// Lays an enum out as its largest variant, storing the discriminant of all other variants in
+/// invalid values of a field of the largest one, if the other variants fit around that field.
+fn niche_filling_layout(
+    dl: &TargetDataLayout,
+    variants: &[Vec<Layout>],
+    repr: &Repr,
+    absent: impl Fn(&[Layout]) -> bool,
+) -> Result<Option<Layout>, LayoutError> {
+    let layouts = variants
+        .iter()
+        .map(|fields| univariant(dl, fields, repr, None))
+        .collect::<Result<Vec<_>, _>>()?;
+    let align = layouts.iter().map(|it| it.align).fold(dl.aggregate_align, cmp::max);
+    let largest = match layouts.iter().enumerate().max_by_key(|(_, it)| it.size) {
+        Some((i, _)) => i,
+        None => return Ok(None),
+    };
+
+    let needs_discriminant = |i: &usize| *i != largest && !absent(&variants[*i]);
+    let (first, last) = match (
+        (0..variants.len()).find(needs_discriminant),
+        (0..variants.len()).rev().find(needs_discriminant),
+    ) {
+        (Some(first), Some(last)) => (first, last),
+        _ => return Ok(None),
+    };
+    let count = (last - first + 1) as u128;
+
+    let (field, niche) = match variants[largest]
+        .iter()
+        .enumerate()
+        .filter_map(|(i, field)| Some((i, field.niche?)))
+        .max_by_key(|(_, niche)| niche.available)
+    {
+        Some(it) if it.1.available >= count => it,
+        _ => return Ok(None),
+    };
+    let niche_offset = niche.offset + layouts[largest].field_offsets[field];
+    let size = align_to(layouts[largest].size, align);
+    let all_variants_fit = layouts.iter().enumerate().all(|(i, layout)| {
+        i == largest
+            || layout.size <= niche_offset
+            || align_to(niche_offset + niche.size, layout.align) + layout.size <= size
+    });
+    if !all_variants_fit {
+        return Ok(None);
+    }
+    Ok(Some(Layout {
+        size,
+        align,
+        field_offsets: Vec::new(),
+        niche: Niche::new(niche_offset, niche.size, niche.available - count),
+        uninhabited: layouts.iter().all(|it| it.uninhabited),
+    }))
+}
+
+/// Returns the size of the smallest integer that holds all discriminants between `min` and
+/// `max`, unless the enum asks for a particular one.
+fn discriminant_size(repr: &Repr, min: i128, max: i128) -> u64 {
+    if let Some(size) = repr.int {
+        return size;
+    }
+    let fit_signed = |x: i128| match x {
+        -0x80..=0x7f => 1,
+        -0x8000..=0x7fff => 2,
+        -0x8000_0000..=0x7fff_ffff => 4,
+        -0x8000_0000_0000_0000..=0x7fff_ffff_ffff_ffff => 8,
+        _ => 16,
+    };
+    let fit_unsigned = |x: u128| match x {
+        0..=0xff => 1,
+        0..=0xffff => 2,
+        0..=0xffff_ffff => 4,
+        0..=0xffff_ffff_ffff_ffff => 8,
+        _ => 16,
+    };
+    // `#[repr(C)]` enums are at least as large as a C `int`, which is 32 bits on all targets
+    // we know the data layout of.
+    let at_least = if repr.c { 4 } else { 1 };
+    if min >= 0 {
+        fit_unsigned(cmp::max(min as u128, max as u128)).max(at_least)
+    } else {
+        cmp::max(fit_signed(min), fit_signed(max)).max(at_least)
+    }
+}
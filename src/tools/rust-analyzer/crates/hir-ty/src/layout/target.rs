@@ -0,0 +1,181 @@
+//! The data layout of the target a crate is compiled for.
+
+use std::sync::Arc;
+
+use base_db::CrateId;
+
+use crate::db::HirDatabase;
+
+/// How pointers into one address space are laid out, in bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PointerLayout {
+    pub size: u64,
+    pub align: u64,
+    /// The size of the address part of a pointer, which is also the size of `usize`.
+    ///
+    /// This is smaller than `size` on CHERI targets, where `pf200:128:128:128:64` makes pointers
+    /// 16-byte capabilities holding an 8-byte address.
+    pub index_size: u64,
+}
+
+/// The parts of an LLVM data layout string that matter for the layout of Rust types, with
+/// alignments in bytes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TargetDataLayout {
+    pub i8_align: u64,
+    pub i16_align: u64,
+    pub i32_align: u64,
+    pub i64_align: u64,
+    pub i128_align: u64,
+    pub f32_align: u64,
+    pub f64_align: u64,
+    pub aggregate_align: u64,
+    pointer_layouts: Vec<(u32, PointerLayout)>,
+    /// The address space of function pointers.
+    pub instruction_address_space: u32,
+    /// The address space of all other pointers.
+    pub default_address_space: u32,
+}
+
+impl Default for TargetDataLayout {
+    /// The layout LLVM assumes for whatever the data layout string leaves out.
+    fn default() -> TargetDataLayout {
+        TargetDataLayout {
+            i8_align: 1,
+            i16_align: 2,
+            i32_align: 4,
+            i64_align: 4,
+            i128_align: 4,
+            f32_align: 4,
+            f64_align: 8,
+            aggregate_align: 1,
+            pointer_layouts: vec![(0, PointerLayout { size: 8, align: 8, index_size: 8 })],
+            instruction_address_space: 0,
+            default_address_space: 0,
+        }
+    }
+}
+
+impl TargetDataLayout {
+    /// Parses an LLVM data layout string, the way `rustc_target` does.
+    pub fn parse(data_layout: &str) -> Option<TargetDataLayout> {
+        let bits = |s: &str| s.parse::<u64>().ok();
+        let bytes = |s: &str| bits(s).map(|it| it / 8);
+        // Alignments of 0 bits, as in `a:0:64`, mean byte alignment.
+        let align = |s: &str| bits(s).map(|it| (it / 8).max(1));
+
+        let mut dl = TargetDataLayout::default();
+        let mut i128_align_src = 64;
+        for spec in data_layout.split('-') {
+            let parts = spec.split(':').collect::<Vec<_>>();
+            match &*parts {
+                [p] if p.starts_with('P') => dl.instruction_address_space = p[1..].parse().ok()?,
+                [g] if g.starts_with('G') => dl.default_address_space = g[1..].parse().ok()?,
+                ["a", a, ..] => dl.aggregate_align = align(a)?,
+                ["f32", a, ..] => dl.f32_align = align(a)?,
+                ["f64", a, ..] => dl.f64_align = align(a)?,
+                [p, size, abi, rest @ ..] if p.starts_with('p') => {
+                    // `p[f][n]:<size>:<abi>[:<pref>[:<idx>]]`, where `f` marks fat (capability)
+                    // pointers.
+                    let address_space = p[1..].trim_start_matches('f');
+                    let address_space =
+                        if address_space.is_empty() { 0 } else { address_space.parse().ok()? };
+                    let size = bytes(size)?;
+                    let index_size = match rest {
+                        [_, index_size, ..] => bytes(index_size)?,
+                        _ => size,
+                    };
+                    let layout = PointerLayout { size, align: align(abi)?, index_size };
+                    match dl.pointer_layouts.iter_mut().find(|it| it.0 == address_space) {
+                        Some(it) => it.1 = layout,
+                        None => dl.pointer_layouts.push((address_space, layout)),
+                    }
+                }
+                [i, a, ..] if i.starts_with('i') => {
+                    let size = match bits(&i[1..]) {
+                        Some(it) => it,
+                        None => continue,
+                    };
+                    let a = align(a)?;
+                    match size {
+                        8 => dl.i8_align = a,
+                        16 => dl.i16_align = a,
+                        32 => dl.i32_align = a,
+                        64 => dl.i64_align = a,
+                        _ => {}
+                    }
+                    // `i128` takes the alignment of the largest integer between 64 and 128 bits.
+                    if size >= i128_align_src && size <= 128 {
+                        i128_align_src = size;
+                        dl.i128_align = a;
+                    }
+                }
+                _ => {}
+            }
+        }
+        Some(dl)
+    }
+
+    /// Approximates the data layout of a target from its `target_pointer_width` and
+    /// `target_pointer_type_width` cfgs, for crates whose data layout we couldn't query.
+    pub fn from_pointer_widths(pointer_width: u64, pointer_type_width: u64) -> TargetDataLayout {
+        let size = pointer_type_width / 8;
+        let pointer = PointerLayout { size, align: size, index_size: pointer_width / 8 };
+        TargetDataLayout {
+            i64_align: 8,
+            i128_align: 8,
+            pointer_layouts: vec![(0, pointer)],
+            ..TargetDataLayout::default()
+        }
+    }
+
+    /// Returns the layout of pointers into `address_space`.
+    pub fn pointer_layout(&self, address_space: u32) -> PointerLayout {
+        // LLVM uses the layout of address space 0 for all address spaces not given explicitly.
+        self.pointer_layouts
+            .iter()
+            .find(|it| it.0 == address_space)
+            .or_else(|| self.pointer_layouts.iter().find(|it| it.0 == 0))
+            .map_or(TargetDataLayout::default().pointer_layouts[0].1, |it| it.1)
+    }
+
+    /// Returns the layout of references, raw pointers and `Box`es.
+    pub fn data_pointer(&self) -> PointerLayout {
+        self.pointer_layout(self.default_address_space)
+    }
+
+    /// Returns the layout of function pointers.
+    pub fn code_pointer(&self) -> PointerLayout {
+        self.pointer_layout(self.instruction_address_space)
+    }
+
+    /// Returns the ABI alignment of an integer of `size` bytes.
+    pub fn int_align(&self, size: u64) -> u64 {
+        match size {
+            1 => self.i8_align,
+            2 => self.i16_align,
+            4 => self.i32_align,
+            8 => self.i64_align,
+            _ => self.i128_align,
+        }
+    }
+}
+
+pub(crate) fn target_data_layout_query(
+    db: &dyn HirDatabase,
+    krate: CrateId,
+) -> Arc<TargetDataLayout> {
+    let crate_graph = db.crate_graph();
+    let data = &crate_graph[krate];
+    if let Some(target_layout) = &data.target_layout {
+        match TargetDataLayout::parse(target_layout) {
+            Some(it) => return Arc::new(it),
+            None => tracing::error!("failed to parse target data layout {target_layout:?}"),
+        }
+    }
+    let width = |key: &str| data.cfg_options.get_cfg_values(key).find_map(|it| it.parse().ok());
+    let pointer_width = width("target_pointer_width").unwrap_or(64);
+    // Only CHERI targets set `target_pointer_type_width`: pointers are wider than `usize` there.
+    let pointer_type_width = width("target_pointer_type_width").unwrap_or(pointer_width);
+    Arc::new(TargetDataLayout::from_pointer_widths(pointer_width, pointer_type_width))
+}
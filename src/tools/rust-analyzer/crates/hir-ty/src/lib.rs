@@ -24,6 +24,7 @@ macro_rules! eprintln {
 pub mod db;
 pub mod diagnostics;
 pub mod display;
+pub mod layout;
 pub mod method_resolution;
 pub mod primitive;
 pub mod traits;
@@ -9,6 +9,7 @@
 mod display_source_code;
 mod incremental;
 mod diagnostics;
+mod layout;
 
 use std::{collections::HashMap, env, sync::Arc};
 
@@ -0,0 +1,126 @@
+use std::sync::Arc;
+
+use base_db::{fixture::WithFixture, SourceDatabase};
+use hir_def::ModuleDefId;
+
+use crate::{
+    db::HirDatabase,
+    layout::{Layout, LayoutError, PointerLayout, TargetDataLayout},
+    test_db::TestDB,
+    Interner, Substitution,
+};
+
+/// The data layout of `morello-unknown-freebsd-purecap`.
+const MORELLO_PURECAP: &str =
+    "e-m:e-pf200:128:128:128:64-i8:8:32-i16:16:32-i64:64-i128:128-n32:64-S128-A200-P200-G200";
+
+fn layout_of(ra_fixture: &str, data_layout: Option<&str>) -> Result<Layout, LayoutError> {
+    let (mut db, file_id) = TestDB::with_single_file(ra_fixture);
+    if let Some(data_layout) = data_layout {
+        let mut crate_graph = (*db.crate_graph()).clone();
+        for krate in crate_graph.iter().collect::<Vec<_>>() {
+            crate_graph.set_target_layout(krate, Some(data_layout.into()));
+        }
+        db.set_crate_graph(Arc::new(crate_graph));
+    }
+    let module = db.module_for_file(file_id);
+    let def_map = module.def_map(&db);
+    let adt = def_map[module.local_id]
+        .scope
+        .entries()
+        .find(|(name, _)| name.to_smol_str() == "Goal")
+        .and_then(|(_, def)| def.take_types())
+        .and_then(|def| match def {
+            ModuleDefId::AdtId(it) => Some(it),
+            _ => None,
+        })
+        .expect("fixture has no `Goal` type");
+    db.layout_of_adt(adt, Substitution::empty(Interner))
+}
+
+#[track_caller]
+fn check_size_and_align(ra_fixture: &str, size: u64, align: u64) {
+    let layout = layout_of(ra_fixture, None).unwrap();
+    assert_eq!((layout.size, layout.align), (size, align));
+}
+
+#[track_caller]
+fn check_offsets(ra_fixture: &str, data_layout: Option<&str>, offsets: &[u64]) {
+    let layout = layout_of(ra_fixture, data_layout).unwrap();
+    assert_eq!(layout.field_offsets, offsets);
+}
+
+#[test]
+fn reorders_struct_fields() {
+    check_offsets("struct Goal(u8, u32, u16);", None, &[6, 0, 4]);
+    check_size_and_align("struct Goal(u8, u32, u16);", 8, 4);
+    check_size_and_align("struct Goal([u16; 3], u8);", 8, 2);
+}
+
+#[test]
+fn repr_c_keeps_field_order() {
+    check_offsets("#[repr(C)] struct Goal(u8, u32, u16);", None, &[0, 4, 8]);
+    check_size_and_align("#[repr(C)] struct Goal(u8, u32, u16);", 12, 4);
+    check_size_and_align("#[repr(C, packed)] struct Goal(u8, u32, u16);", 7, 1);
+    check_size_and_align("#[repr(align(16))] struct Goal(u8);", 16, 16);
+}
+
+#[test]
+fn unions() {
+    check_size_and_align("union Goal { a: u8, b: [u16; 3] }", 6, 2);
+}
+
+#[test]
+fn enums_use_niches() {
+    check_size_and_align("enum Goal { A(&'static u8), B }", 8, 8);
+    check_size_and_align("enum Goal { A(bool), B, C }", 1, 1);
+    check_size_and_align("enum Goal { A(char), B }", 4, 4);
+    check_size_and_align("enum Goal { A(u32), B }", 8, 4);
+    check_size_and_align("#[repr(u8)] enum Goal { A(&'static u8), B }", 16, 8);
+    check_size_and_align("enum Goal { A, B = 300 }", 2, 2);
+    check_size_and_align("#[repr(C)] enum Goal { A, B }", 4, 4);
+}
+
+#[test]
+fn recursive_types() {
+    assert_eq!(layout_of("struct Goal(Goal);", None), Err(LayoutError::Recursive));
+    check_size_and_align(
+        "struct Goal(Option<&'static Goal>); enum Option<T> { None, Some(T) }",
+        8,
+        8,
+    );
+}
+
+#[test]
+fn capabilities_from_cfgs() {
+    check_offsets(
+        r#"
+//- /main.rs cfg:target_pointer_width=64,target_pointer_type_width=128
+struct Goal { len: usize, ptr: *const u8 }
+"#,
+        None,
+        &[16, 0],
+    );
+}
+
+#[test]
+fn capabilities_from_data_layout() {
+    check_offsets("struct Goal { len: usize, ptr: *const u8 }", Some(MORELLO_PURECAP), &[16, 0]);
+    let layout = layout_of("struct Goal(&'static [u8]);", Some(MORELLO_PURECAP)).unwrap();
+    assert_eq!((layout.size, layout.align), (32, 16));
+    let layout = layout_of("struct Goal(fn(), u64);", Some(MORELLO_PURECAP)).unwrap();
+    assert_eq!((layout.size, layout.align), (32, 16));
+}
+
+#[test]
+fn parse_data_layout() {
+    let dl = TargetDataLayout::parse(MORELLO_PURECAP).unwrap();
+    let capability = PointerLayout { size: 16, align: 16, index_size: 8 };
+    assert_eq!(dl.data_pointer(), capability);
+    assert_eq!(dl.code_pointer(), capability);
+    assert_eq!(dl.pointer_layout(0), PointerLayout { size: 8, align: 8, index_size: 8 });
+    assert_eq!((dl.i64_align, dl.i128_align), (8, 16));
+
+    let dl = TargetDataLayout::parse("e-m:e-p:32:32-i64:64-n32-S128").unwrap();
+    assert_eq!(dl.data_pointer(), PointerLayout { size: 4, align: 4, index_size: 4 });
+}
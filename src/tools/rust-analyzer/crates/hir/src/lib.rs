@@ -59,6 +59,7 @@
     all_super_traits, autoderef,
     consteval::{unknown_const_as_generic, ComputedExpr, ConstEvalError, ConstExt},
     diagnostics::BodyValidationDiagnostic,
+    layout::layout_of_ty,
     method_resolution::{self, TyFingerprint},
     primitive::UintTy,
     traits::FnTrait,
@@ -117,7 +118,10 @@
         name::{known, Name},
         ExpandResult, HirFileId, InFile, MacroFile, Origin,
     },
-    hir_ty::display::HirDisplay,
+    hir_ty::{
+        display::HirDisplay,
+        layout::{Layout, LayoutError},
+    },
 };
 
 // These are negative re-exports: pub using these names is forbidden, they
@@ -862,6 +866,23 @@ pub fn ty(&self, db: &dyn HirDatabase) -> Type {
     pub fn parent_def(&self, _db: &dyn HirDatabase) -> VariantDef {
         self.parent
     }
+
+    /// Returns the layout of the field's type. Fails for fields of generic structs.
+    pub fn layout(&self, db: &dyn HirDatabase) -> Result<Layout, LayoutError> {
+        self.ty(db).layout(db)
+    }
+
+    /// Returns the offset of the field in its struct or union. Fields of enum variants have no
+    /// offset we could report independently of the discriminant.
+    pub fn offset(&self, db: &dyn HirDatabase) -> Result<u64, LayoutError> {
+        let parent = match self.parent {
+            VariantDef::Struct(it) => Adt::Struct(it),
+            VariantDef::Union(it) => Adt::Union(it),
+            VariantDef::Variant(_) => return Err(LayoutError::NotImplemented),
+        };
+        let index = u32::from(self.id.into_raw()) as usize;
+        Ok(parent.layout(db)?.field_offsets[index])
+    }
 }
 
 impl HasVisibility for Field {
@@ -1084,6 +1105,15 @@ pub fn ty(self, db: &dyn HirDatabase) -> Type {
         Type::from_def(db, id)
     }
 
+    /// Returns the layout of this ADT. Fails for ADTs with type or const parameters.
+    pub fn layout(self, db: &dyn HirDatabase) -> Result<Layout, LayoutError> {
+        let id = AdtId::from(self);
+        if !db.generic_params(id.into()).type_or_consts.is_empty() {
+            return Err(LayoutError::HasPlaceholder);
+        }
+        db.layout_of_adt(id, Substitution::empty(Interner))
+    }
+
     /// Turns this ADT into a type with the given type parameters. This isn't
     /// the greatest API, FIXME find a better one.
     pub fn ty_with_args(self, db: &dyn HirDatabase, args: &[Type]) -> Type {
@@ -2779,6 +2809,11 @@ pub(crate) fn new_for_crate(krate: CrateId, ty: Ty) -> Type {
         Type { env: Arc::new(TraitEnvironment::empty(krate)), ty }
     }
 
+    /// Returns the layout of this type on the target of the crate it was found in.
+    pub fn layout(&self, db: &dyn HirDatabase) -> Result<Layout, LayoutError> {
+        layout_of_ty(db, &self.ty, self.env.krate)
+    }
+
     pub fn reference(inner: &Type, m: Mutability) -> Type {
         inner.derived(
             TyKind::Ref(
@@ -28,6 +28,7 @@ pub struct HoverConfig {
     pub links_in_hover: bool,
     pub documentation: Option<HoverDocFormat>,
     pub keywords: bool,
+    pub memory_layout: bool,
 }
 
 impl HoverConfig {
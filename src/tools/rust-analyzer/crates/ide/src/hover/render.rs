@@ -342,10 +342,20 @@ pub(super) fn definition(
     let mod_path = definition_mod_path(db, &def);
     let (label, docs) = match def {
         Definition::Macro(it) => label_and_docs(db, it),
-        Definition::Field(it) => label_and_docs(db, it),
+        Definition::Field(it) => label_and_layout_info_and_docs(db, it, config, |&it| {
+            let layout = it.layout(db).ok()?;
+            let mut info = format!("size = {}, align = {}", layout.size, layout.align);
+            if let Ok(offset) = it.offset(db) {
+                format_to!(info, ", offset = {}", offset);
+            }
+            Some(info)
+        }),
         Definition::Module(it) => label_and_docs(db, it),
         Definition::Function(it) => label_and_docs(db, it),
-        Definition::Adt(it) => label_and_docs(db, it),
+        Definition::Adt(it) => label_and_layout_info_and_docs(db, it, config, |&it| {
+            let layout = it.layout(db).ok()?;
+            Some(format!("size = {}, align = {}", layout.size, layout.align))
+        }),
         Definition::Variant(it) => label_value_and_docs(db, it, |&it| {
             if !it.parent_enum(db).is_data_carrying(db) {
                 match it.eval(db) {
@@ -443,6 +453,25 @@ fn label_and_docs<D>(db: &RootDatabase, def: D) -> (String, Option<hir::Document
     (label, docs)
 }
 
+fn label_and_layout_info_and_docs<D, E, V>(
+    db: &RootDatabase,
+    def: D,
+    config: &HoverConfig,
+    layout_extractor: E,
+) -> (String, Option<hir::Documentation>)
+where
+    D: HasAttrs + HirDisplay,
+    E: Fn(&D) -> Option<V>,
+    V: Display,
+{
+    let label = match config.memory_layout.then(|| layout_extractor(&def)).flatten() {
+        Some(layout_info) => format!("{} // {}", def.display(db), layout_info),
+        None => def.display(db).to_string(),
+    };
+    let docs = def.attrs(db).docs();
+    (label, docs)
+}
+
 fn label_value_and_docs<D, E, V>(
     db: &RootDatabase,
     def: D,
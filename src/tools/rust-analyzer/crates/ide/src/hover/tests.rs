@@ -12,6 +12,7 @@ fn check_hover_no_result(ra_fixture: &str) {
                 links_in_hover: true,
                 documentation: Some(HoverDocFormat::Markdown),
                 keywords: true,
+                memory_layout: false,
             },
             FileRange { file_id: position.file_id, range: TextRange::empty(position.offset) },
         )
@@ -28,6 +29,30 @@ fn check(ra_fixture: &str, expect: Expect) {
                 links_in_hover: true,
                 documentation: Some(HoverDocFormat::Markdown),
                 keywords: true,
+                memory_layout: false,
+            },
+            FileRange { file_id: position.file_id, range: TextRange::empty(position.offset) },
+        )
+        .unwrap()
+        .unwrap();
+
+    let content = analysis.db.file_text(position.file_id);
+    let hovered_element = &content[hover.range];
+
+    let actual = format!("*{}*\n{}\n", hovered_element, hover.info.markup);
+    expect.assert_eq(&actual)
+}
+
+#[track_caller]
+fn check_hover_memory_layout(ra_fixture: &str, expect: Expect) {
+    let (analysis, position) = fixture::position(ra_fixture);
+    let hover = analysis
+        .hover(
+            &HoverConfig {
+                links_in_hover: true,
+                documentation: Some(HoverDocFormat::Markdown),
+                keywords: true,
+                memory_layout: true,
             },
             FileRange { file_id: position.file_id, range: TextRange::empty(position.offset) },
         )
@@ -49,6 +74,7 @@ fn check_hover_no_links(ra_fixture: &str, expect: Expect) {
                 links_in_hover: false,
                 documentation: Some(HoverDocFormat::Markdown),
                 keywords: true,
+                memory_layout: false,
             },
             FileRange { file_id: position.file_id, range: TextRange::empty(position.offset) },
         )
@@ -70,6 +96,7 @@ fn check_hover_no_markdown(ra_fixture: &str, expect: Expect) {
                 links_in_hover: true,
                 documentation: Some(HoverDocFormat::PlainText),
                 keywords: true,
+                memory_layout: false,
             },
             FileRange { file_id: position.file_id, range: TextRange::empty(position.offset) },
         )
@@ -91,6 +118,7 @@ fn check_actions(ra_fixture: &str, expect: Expect) {
                 links_in_hover: true,
                 documentation: Some(HoverDocFormat::Markdown),
                 keywords: true,
+                memory_layout: false,
             },
             FileRange { file_id, range: position.range_or_empty() },
         )
@@ -107,6 +135,7 @@ fn check_hover_range(ra_fixture: &str, expect: Expect) {
                 links_in_hover: false,
                 documentation: Some(HoverDocFormat::Markdown),
                 keywords: true,
+                memory_layout: false,
             },
             range,
         )
@@ -123,6 +152,7 @@ fn check_hover_range_no_results(ra_fixture: &str) {
                 links_in_hover: false,
                 documentation: Some(HoverDocFormat::Markdown),
                 keywords: true,
+                memory_layout: false,
             },
             range,
         )
@@ -522,6 +552,100 @@ pub fn foo(_: &Path)
     );
 }
 
+#[test]
+fn hover_shows_struct_memory_layout() {
+    check_hover_memory_layout(
+        r#"
+struct Foo$0 { a: u8, b: u32, c: u16 }
+"#,
+        expect![[r#"
+                *Foo*
+
+                ```rust
+                test
+                ```
+
+                ```rust
+                struct Foo // size = 8, align = 4
+                ```
+            "#]],
+    );
+    check_hover_memory_layout(
+        r#"
+struct Foo { a$0: u8, b: u32, c: u16 }
+"#,
+        expect![[r#"
+                *a*
+
+                ```rust
+                test::Foo
+                ```
+
+                ```rust
+                a: u8 // size = 1, align = 1, offset = 6
+                ```
+            "#]],
+    );
+}
+
+#[test]
+fn hover_shows_memory_layout_with_capabilities() {
+    check_hover_memory_layout(
+        r#"
+//- /main.rs cfg:target_pointer_width=64,target_pointer_type_width=128
+struct Node$0 { next: *const Node, len: usize }
+"#,
+        expect![[r#"
+                *Node*
+
+                ```rust
+                test
+                ```
+
+                ```rust
+                struct Node // size = 32, align = 16
+                ```
+            "#]],
+    );
+    check_hover_memory_layout(
+        r#"
+//- /main.rs cfg:target_pointer_width=64,target_pointer_type_width=128
+struct Node { next: *const Node, len$0: usize }
+"#,
+        expect![[r#"
+                *len*
+
+                ```rust
+                test::Node
+                ```
+
+                ```rust
+                len: usize // size = 8, align = 8, offset = 16
+                ```
+            "#]],
+    );
+}
+
+#[test]
+fn hover_omits_memory_layout_of_generic_struct() {
+    check_hover_memory_layout(
+        r#"
+struct Foo$0<T> { t: T }
+"#,
+        expect![[r#"
+                *Foo*
+
+                ```rust
+                test
+                ```
+
+                ```rust
+                struct Foo<T>
+                ```
+            "#]],
+    );
+}
+
 #[test]
 fn hover_shows_struct_field_info() {
     // Hovering over the field when instantiating
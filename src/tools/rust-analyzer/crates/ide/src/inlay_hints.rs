@@ -30,6 +30,7 @@ pub struct InlayHintsConfig {
     pub hide_named_constructor_hints: bool,
     pub hide_closure_initialization_hints: bool,
     pub max_length: Option<usize>,
+    pub memory_layout_hints: bool,
     pub closing_brace_hints_min_lines: Option<usize>,
 }
 
@@ -63,6 +64,7 @@ pub enum InlayKind {
     GenericParamListHint,
     ImplicitReborrowHint,
     LifetimeHint,
+    MemoryLayoutHint,
     ParameterHint,
     TypeHint,
 }
@@ -175,6 +177,7 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 // * return types of closure expressions
 // * elided lifetimes
 // * compiler inserted reborrows
+// * sizes and alignments of structs, enums and unions, and offsets of their fields
 //
 // image::https://user-images.githubusercontent.com/48062697/113020660-b5f98b80-917a-11eb-8d70-3be3fd558cdd.png[]
 pub(crate) fn inlay_hints(
@@ -247,6 +250,13 @@ fn hints(
                 // static type elisions
                 ast::Item::Static(it) => implicit_static_hints(hints, config, Either::Left(it)),
                 ast::Item::Const(it) => implicit_static_hints(hints, config, Either::Right(it)),
+                ast::Item::Struct(it) => {
+                    memory_layout_hints(hints, sema, config, ast::Adt::Struct(it))
+                }
+                ast::Item::Enum(it) => memory_layout_hints(hints, sema, config, ast::Adt::Enum(it)),
+                ast::Item::Union(it) => {
+                    memory_layout_hints(hints, sema, config, ast::Adt::Union(it))
+                }
                 _ => None,
             },
             // FIXME: fn-ptr type, dyn fn type, and trait object type elisions
@@ -362,6 +372,53 @@ fn closing_brace_hints(
     None
 }
 
+fn memory_layout_hints(
+    acc: &mut Vec<InlayHint>,
+    sema: &Semantics<'_, RootDatabase>,
+    config: &InlayHintsConfig,
+    adt: ast::Adt,
+) -> Option<()> {
+    if !config.memory_layout_hints {
+        return None;
+    }
+
+    let layout = sema.to_def(&adt)?.layout(sema.db).ok()?;
+    acc.push(InlayHint {
+        range: adt.name()?.syntax().text_range(),
+        kind: InlayKind::MemoryLayoutHint,
+        label: format!("size = {}, align = {}", layout.size, layout.align).into(),
+        tooltip: None,
+    });
+
+    // Enum variants have no offsets of their own to show, as their fields are laid out around
+    // the discriminant.
+    let fields = match &adt {
+        ast::Adt::Struct(it) => it.field_list(),
+        ast::Adt::Union(it) => it.record_field_list().map(ast::FieldList::RecordFieldList),
+        ast::Adt::Enum(_) => None,
+    };
+    let mut field_hint = |field: Option<hir::Field>, range: TextRange| {
+        if let Some(offset) = field.and_then(|it| it.offset(sema.db).ok()) {
+            acc.push(InlayHint {
+                range,
+                kind: InlayKind::MemoryLayoutHint,
+                label: format!("offset = {}", offset).into(),
+                tooltip: None,
+            });
+        }
+    };
+    match fields? {
+        ast::FieldList::RecordFieldList(list) => {
+            list.fields().for_each(|it| field_hint(sema.to_def(&it), it.syntax().text_range()))
+        }
+        ast::FieldList::TupleFieldList(list) => {
+            list.fields().for_each(|it| field_hint(sema.to_def(&it), it.syntax().text_range()))
+        }
+    }
+
+    Some(())
+}
+
 fn implicit_static_hints(
     acc: &mut Vec<InlayHint>,
     config: &InlayHintsConfig,
@@ -1236,6 +1293,7 @@ mod tests {
         hide_closure_initialization_hints: false,
         param_names_for_lifetime_elision_hints: false,
         max_length: None,
+        memory_layout_hints: false,
         closing_brace_hints_min_lines: None,
     };
     const TEST_CONFIG: InlayHintsConfig = InlayHintsConfig {
@@ -2991,6 +3049,31 @@ fn f() {
     ];
   }
 //^ fn f
+"#,
+        );
+    }
+
+    #[test]
+    fn memory_layout_hints() {
+        check_with_config(
+            InlayHintsConfig { memory_layout_hints: true, ..DISABLED_CONFIG },
+            r#"
+//- /main.rs cfg:target_pointer_width=64,target_pointer_type_width=128
+struct Foo { a: u8, b: u32 }
+     //^^^ size = 8, align = 4
+           //^^^^^ offset = 4
+                  //^^^^^^ offset = 0
+struct P(*const u8, u8);
+     //^ size = 32, align = 16
+       //^^^^^^^^^ offset = 0
+                  //^^ offset = 16
+enum E { A(u32), B }
+   //^ size = 8, align = 4
+union U { a: u8, b: u16 }
+    //^ size = 2, align = 2
+        //^^^^^ offset = 0
+               //^^^^^^ offset = 0
+struct G<T>(T);
 "#,
         );
     }
@@ -37,6 +37,7 @@ pub(crate) fn shuffle_crate_graph(db: &mut RootDatabase) {
             data.is_proc_macro,
             data.origin.clone(),
         );
+        new_graph.set_target_layout(new_id, data.target_layout.clone());
         map.insert(old_id, new_id);
     }
 
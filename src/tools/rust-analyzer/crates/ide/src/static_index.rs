@@ -117,6 +117,7 @@ fn add_file(&mut self, file_id: FileId) {
                     param_names_for_lifetime_elision_hints: false,
                     binding_mode_hints: false,
                     max_length: Some(25),
+                    memory_layout_hints: false,
                     closing_brace_hints_min_lines: Some(25),
                 },
                 file_id,
@@ -134,6 +135,7 @@ fn add_file(&mut self, file_id: FileId) {
             links_in_hover: true,
             documentation: Some(HoverDocFormat::Markdown),
             keywords: true,
+            memory_layout: false,
         };
         let tokens = tokens.filter(|token| {
             matches!(
@@ -24,6 +24,7 @@
 mod sysroot;
 mod workspace;
 mod rustc_cfg;
+mod target_data_layout;
 mod build_scripts;
 
 #[cfg(test)]
@@ -0,0 +1,35 @@
+//! Runs `rustc --print target-spec-json` to get the target's data layout.
+
+use std::process::Command;
+
+use anyhow::{format_err, Result};
+use rustc_hash::FxHashMap;
+
+use crate::utf8_stdout;
+
+pub(crate) fn get(target: Option<&str>, extra_env: &FxHashMap<String, String>) -> Option<String> {
+    let _p = profile::span("target_data_layout::get");
+    match get_data_layout(target, extra_env) {
+        Ok(it) => Some(it),
+        Err(e) => {
+            tracing::error!("failed to get target data layout: {e:?}");
+            None
+        }
+    }
+}
+
+fn get_data_layout(target: Option<&str>, extra_env: &FxHashMap<String, String>) -> Result<String> {
+    let mut cmd = Command::new(toolchain::rustc());
+    cmd.envs(extra_env);
+    cmd.args(&["-Z", "unstable-options", "--print", "target-spec-json"])
+        .env("RUSTC_BOOTSTRAP", "1");
+    if let Some(target) = target {
+        cmd.args(&["--target", target]);
+    }
+    let spec = utf8_stdout(cmd)?;
+    let spec = serde_json::from_str::<serde_json::Value>(&spec)?;
+    spec.get("data-layout")
+        .and_then(|it| it.as_str())
+        .map(ToOwned::to_owned)
+        .ok_or_else(|| format_err!("target spec has no data layout"))
+}
@@ -27,6 +27,7 @@ fn load_cargo_with_overrides(file: &str, cfg_overrides: CfgOverrides) -> CrateGr
         sysroot: None,
         rustc: None,
         rustc_cfg: Vec::new(),
+        target_layout: None,
         cfg_overrides,
         toolchain: None,
     };
@@ -37,7 +38,8 @@ fn load_rust_project(file: &str) -> CrateGraph {
     let data = get_test_json_file(file);
     let project = rooted_project_json(data);
     let sysroot = Some(get_fake_sysroot());
-    let project_workspace = ProjectWorkspace::Json { project, sysroot, rustc_cfg: Vec::new() };
+    let project_workspace =
+        ProjectWorkspace::Json { project, sysroot, rustc_cfg: Vec::new(), target_layout: None };
     to_crate_graph(project_workspace)
 }
 
@@ -190,6 +192,7 @@ fn cargo_hello_world_project_model_with_wildcard_overrides() {
                             ),
                         },
                         is_proc_macro: false,
+                        target_layout: None,
                     },
                     CrateId(
                         1,
@@ -268,6 +271,7 @@ fn cargo_hello_world_project_model_with_wildcard_overrides() {
                             ),
                         },
                         is_proc_macro: false,
+                        target_layout: None,
                     },
                     CrateId(
                         2,
@@ -346,6 +350,7 @@ fn cargo_hello_world_project_model_with_wildcard_overrides() {
                             ),
                         },
                         is_proc_macro: false,
+                        target_layout: None,
                     },
                     CrateId(
                         3,
@@ -424,6 +429,7 @@ fn cargo_hello_world_project_model_with_wildcard_overrides() {
                             ),
                         },
                         is_proc_macro: false,
+                        target_layout: None,
                     },
                     CrateId(
                         4,
@@ -494,6 +500,7 @@ fn cargo_hello_world_project_model_with_wildcard_overrides() {
                             ),
                         },
                         is_proc_macro: false,
+                        target_layout: None,
                     },
                 },
             }"#]],
@@ -587,6 +594,7 @@ fn cargo_hello_world_project_model_with_selective_overrides() {
                             ),
                         },
                         is_proc_macro: false,
+                        target_layout: None,
                     },
                     CrateId(
                         1,
@@ -667,6 +675,7 @@ fn cargo_hello_world_project_model_with_selective_overrides() {
                             ),
                         },
                         is_proc_macro: false,
+                        target_layout: None,
                     },
                     CrateId(
                         2,
@@ -747,6 +756,7 @@ fn cargo_hello_world_project_model_with_selective_overrides() {
                             ),
                         },
                         is_proc_macro: false,
+                        target_layout: None,
                     },
                     CrateId(
                         3,
@@ -827,6 +837,7 @@ fn cargo_hello_world_project_model_with_selective_overrides() {
                             ),
                         },
                         is_proc_macro: false,
+                        target_layout: None,
                     },
                     CrateId(
                         4,
@@ -897,6 +908,7 @@ fn cargo_hello_world_project_model_with_selective_overrides() {
                             ),
                         },
                         is_proc_macro: false,
+                        target_layout: None,
                     },
                 },
             }"#]],
@@ -981,6 +993,7 @@ fn cargo_hello_world_project_model() {
                             ),
                         },
                         is_proc_macro: false,
+                        target_layout: None,
                     },
                     CrateId(
                         1,
@@ -1061,6 +1074,7 @@ fn cargo_hello_world_project_model() {
                             ),
                         },
                         is_proc_macro: false,
+                        target_layout: None,
                     },
                     CrateId(
                         2,
@@ -1141,6 +1155,7 @@ fn cargo_hello_world_project_model() {
                             ),
                         },
                         is_proc_macro: false,
+                        target_layout: None,
                     },
                     CrateId(
                         3,
@@ -1221,6 +1236,7 @@ fn cargo_hello_world_project_model() {
                             ),
                         },
                         is_proc_macro: false,
+                        target_layout: None,
                     },
                     CrateId(
                         4,
@@ -1291,6 +1307,7 @@ fn cargo_hello_world_project_model() {
                             ),
                         },
                         is_proc_macro: false,
+                        target_layout: None,
                     },
                 },
             }"#]],
@@ -1348,6 +1365,7 @@ fn rust_project_hello_world_project_model() {
                             Alloc,
                         ),
                         is_proc_macro: false,
+                        target_layout: None,
                     },
                     CrateId(
                         1,
@@ -1382,6 +1400,7 @@ fn rust_project_hello_world_project_model() {
                             Core,
                         ),
                         is_proc_macro: false,
+                        target_layout: None,
                     },
                     CrateId(
                         2,
@@ -1416,6 +1435,7 @@ fn rust_project_hello_world_project_model() {
                             Other,
                         ),
                         is_proc_macro: false,
+                        target_layout: None,
                     },
                     CrateId(
                         3,
@@ -1450,6 +1470,7 @@ fn rust_project_hello_world_project_model() {
                             Other,
                         ),
                         is_proc_macro: false,
+                        target_layout: None,
                     },
                     CrateId(
                         4,
@@ -1494,6 +1515,7 @@ fn rust_project_hello_world_project_model() {
                             Other,
                         ),
                         is_proc_macro: false,
+                        target_layout: None,
                     },
                     CrateId(
                         5,
@@ -1528,6 +1550,7 @@ fn rust_project_hello_world_project_model() {
                             Other,
                         ),
                         is_proc_macro: false,
+                        target_layout: None,
                     },
                     CrateId(
                         6,
@@ -1644,6 +1667,7 @@ fn rust_project_hello_world_project_model() {
                             Std,
                         ),
                         is_proc_macro: false,
+                        target_layout: None,
                     },
                     CrateId(
                         7,
@@ -1678,6 +1702,7 @@ fn rust_project_hello_world_project_model() {
                             Other,
                         ),
                         is_proc_macro: false,
+                        target_layout: None,
                     },
                     CrateId(
                         8,
@@ -1712,6 +1737,7 @@ fn rust_project_hello_world_project_model() {
                             Other,
                         ),
                         is_proc_macro: false,
+                        target_layout: None,
                     },
                     CrateId(
                         9,
@@ -1746,6 +1772,7 @@ fn rust_project_hello_world_project_model() {
                             Test,
                         ),
                         is_proc_macro: false,
+                        target_layout: None,
                     },
                     CrateId(
                         10,
@@ -1780,6 +1807,7 @@ fn rust_project_hello_world_project_model() {
                             Other,
                         ),
                         is_proc_macro: false,
+                        target_layout: None,
                     },
                     CrateId(
                         11,
@@ -1854,6 +1882,7 @@ fn rust_project_hello_world_project_model() {
                             ),
                         },
                         is_proc_macro: false,
+                        target_layout: None,
                     },
                 },
             }"#]],
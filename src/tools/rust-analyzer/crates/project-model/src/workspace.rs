@@ -21,8 +21,8 @@
     cfg_flag::CfgFlag,
     rustc_cfg,
     sysroot::SysrootCrate,
-    utf8_stdout, CargoConfig, CargoWorkspace, InvocationStrategy, ManifestPath, Package,
-    ProjectJson, ProjectManifest, Sysroot, TargetKind, WorkspaceBuildScripts,
+    target_data_layout, utf8_stdout, CargoConfig, CargoWorkspace, InvocationStrategy, ManifestPath,
+    Package, ProjectJson, ProjectManifest, Sysroot, TargetKind, WorkspaceBuildScripts,
 };
 
 /// A set of cfg-overrides per crate.
@@ -77,11 +77,19 @@ pub enum ProjectWorkspace {
         /// FIXME: make this a per-crate map, as, eg, build.rs might have a
         /// different target.
         rustc_cfg: Vec<CfgFlag>,
+        /// The data layout of the current target. We get it by running
+        /// `rustc --print target-spec-json`.
+        target_layout: Option<String>,
         cfg_overrides: CfgOverrides,
         toolchain: Option<Version>,
     },
     /// Project workspace was manually specified using a `rust-project.json` file.
-    Json { project: ProjectJson, sysroot: Option<Sysroot>, rustc_cfg: Vec<CfgFlag> },
+    Json {
+        project: ProjectJson,
+        sysroot: Option<Sysroot>,
+        rustc_cfg: Vec<CfgFlag>,
+        target_layout: Option<String>,
+    },
 
     // FIXME: The primary limitation of this approach is that the set of detached files needs to be fixed at the beginning.
     // That's not the end user experience we should strive for.
@@ -93,7 +101,12 @@ pub enum ProjectWorkspace {
     // //
     /// Project with a set of disjoint files, not belonging to any particular workspace.
     /// Backed by basic sysroot crates for basic completion and highlighting.
-    DetachedFiles { files: Vec<AbsPathBuf>, sysroot: Sysroot, rustc_cfg: Vec<CfgFlag> },
+    DetachedFiles {
+        files: Vec<AbsPathBuf>,
+        sysroot: Sysroot,
+        rustc_cfg: Vec<CfgFlag>,
+        target_layout: Option<String>,
+    },
 }
 
 impl fmt::Debug for ProjectWorkspace {
@@ -106,6 +119,7 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
                 sysroot,
                 rustc,
                 rustc_cfg,
+                target_layout,
                 cfg_overrides,
                 toolchain,
             } => f
@@ -118,23 +132,26 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
                     &rustc.as_ref().map_or(0, |rc| rc.packages().len()),
                 )
                 .field("n_rustc_cfg", &rustc_cfg.len())
+                .field("target_layout", &target_layout.is_some())
                 .field("n_cfg_overrides", &cfg_overrides.len())
                 .field("toolchain", &toolchain)
                 .finish(),
-            ProjectWorkspace::Json { project, sysroot, rustc_cfg } => {
+            ProjectWorkspace::Json { project, sysroot, rustc_cfg, target_layout } => {
                 let mut debug_struct = f.debug_struct("Json");
                 debug_struct.field("n_crates", &project.n_crates());
                 if let Some(sysroot) = sysroot {
                     debug_struct.field("n_sysroot_crates", &sysroot.crates().len());
                 }
                 debug_struct.field("n_rustc_cfg", &rustc_cfg.len());
+                debug_struct.field("target_layout", &target_layout.is_some());
                 debug_struct.finish()
             }
-            ProjectWorkspace::DetachedFiles { files, sysroot, rustc_cfg } => f
+            ProjectWorkspace::DetachedFiles { files, sysroot, rustc_cfg, target_layout } => f
                 .debug_struct("DetachedFiles")
                 .field("n_files", &files.len())
                 .field("n_sysroot_crates", &sysroot.crates().len())
                 .field("n_rustc_cfg", &rustc_cfg.len())
+                .field("target_layout", &target_layout.is_some())
                 .finish(),
         }
     }
@@ -242,6 +259,8 @@ pub fn load(
 
                 let rustc_cfg =
                     rustc_cfg::get(Some(&cargo_toml), config.target.as_deref(), &config.extra_env);
+                let target_layout =
+                    target_data_layout::get(config.target.as_deref(), &config.extra_env);
 
                 let cfg_overrides = config.cfg_overrides();
                 ProjectWorkspace::Cargo {
@@ -250,6 +269,7 @@ pub fn load(
                     sysroot,
                     rustc,
                     rustc_cfg,
+                    target_layout,
                     cfg_overrides,
                     toolchain,
                 }
@@ -288,7 +308,8 @@ pub fn load_inline(
         }
 
         let rustc_cfg = rustc_cfg::get(None, target, extra_env);
-        Ok(ProjectWorkspace::Json { project: project_json, sysroot, rustc_cfg })
+        let target_layout = target_data_layout::get(target, extra_env);
+        Ok(ProjectWorkspace::Json { project: project_json, sysroot, rustc_cfg, target_layout })
     }
 
     pub fn load_detached_files(detached_files: Vec<AbsPathBuf>) -> Result<ProjectWorkspace> {
@@ -300,7 +321,13 @@ pub fn load_detached_files(detached_files: Vec<AbsPathBuf>) -> Result<ProjectWor
             &Default::default(),
         )?;
         let rustc_cfg = rustc_cfg::get(None, None, &Default::default());
-        Ok(ProjectWorkspace::DetachedFiles { files: detached_files, sysroot, rustc_cfg })
+        let target_layout = target_data_layout::get(None, &Default::default());
+        Ok(ProjectWorkspace::DetachedFiles {
+            files: detached_files,
+            sysroot,
+            rustc_cfg,
+            target_layout,
+        })
     }
 
     /// Runs the build scripts for this [`ProjectWorkspace`].
@@ -389,7 +416,7 @@ pub fn to_roots(&self) -> Vec<PackageRoot> {
             })
         };
         match self {
-            ProjectWorkspace::Json { project, sysroot, rustc_cfg: _ } => project
+            ProjectWorkspace::Json { project, sysroot, rustc_cfg: _, target_layout: _ } => project
                 .crates()
                 .map(|(_, krate)| PackageRoot {
                     is_local: krate.is_workspace_member,
@@ -405,6 +432,7 @@ pub fn to_roots(&self) -> Vec<PackageRoot> {
                 sysroot,
                 rustc,
                 rustc_cfg: _,
+                target_layout: _,
                 cfg_overrides: _,
                 build_scripts,
                 toolchain: _,
@@ -491,42 +519,56 @@ pub fn to_crate_graph(
     ) -> CrateGraph {
         let _p = profile::span("ProjectWorkspace::to_crate_graph");
 
-        let mut crate_graph = match self {
-            ProjectWorkspace::Json { project, sysroot, rustc_cfg } => project_json_to_crate_graph(
-                rustc_cfg.clone(),
-                load_proc_macro,
-                load,
-                project,
-                sysroot,
-                extra_env,
+        let (mut crate_graph, target_layout) = match self {
+            ProjectWorkspace::Json { project, sysroot, rustc_cfg, target_layout } => (
+                project_json_to_crate_graph(
+                    rustc_cfg.clone(),
+                    load_proc_macro,
+                    load,
+                    project,
+                    sysroot,
+                    extra_env,
+                ),
+                target_layout,
             ),
             ProjectWorkspace::Cargo {
                 cargo,
                 sysroot,
                 rustc,
                 rustc_cfg,
+                target_layout,
                 cfg_overrides,
                 build_scripts,
                 toolchain: _,
-            } => cargo_to_crate_graph(
-                rustc_cfg.clone(),
-                cfg_overrides,
-                load_proc_macro,
-                load,
-                cargo,
-                build_scripts,
-                sysroot.as_ref(),
-                rustc,
+            } => (
+                cargo_to_crate_graph(
+                    rustc_cfg.clone(),
+                    cfg_overrides,
+                    load_proc_macro,
+                    load,
+                    cargo,
+                    build_scripts,
+                    sysroot.as_ref(),
+                    rustc,
+                ),
+                target_layout,
+            ),
+            ProjectWorkspace::DetachedFiles { files, sysroot, rustc_cfg, target_layout } => (
+                detached_files_to_crate_graph(rustc_cfg.clone(), load, files, sysroot),
+                target_layout,
             ),
-            ProjectWorkspace::DetachedFiles { files, sysroot, rustc_cfg } => {
-                detached_files_to_crate_graph(rustc_cfg.clone(), load, files, sysroot)
-            }
         };
         if crate_graph.patch_cfg_if() {
             tracing::debug!("Patched std to depend on cfg-if")
         } else {
             tracing::debug!("Did not patch std to depend on cfg-if")
         }
+        // FIXME: like `rustc_cfg`, this should be per crate for build scripts and proc macros,
+        // which are compiled for the host.
+        let target_layout: Option<Arc<str>> = target_layout.as_deref().map(Into::into);
+        for krate in crate_graph.iter().collect::<Vec<_>>() {
+            crate_graph.set_target_layout(krate, target_layout.clone());
+        }
         crate_graph
     }
 }
@@ -292,6 +292,8 @@ struct ConfigData {
         hover_documentation_keywords_enable: bool  = "true",
         /// Use markdown syntax for links in hover.
         hover_links_enable: bool = "true",
+        /// Whether to show the size, alignment and field offsets of types on hover.
+        hover_memoryLayout_enable: bool = "true",
 
         /// Whether to enforce the import granularity setting for all files. If set to false rust-analyzer will try to keep import styles consistent per file.
         imports_granularity_enforce: bool              = "false",
@@ -323,6 +325,9 @@ struct ConfigData {
         inlayHints_lifetimeElisionHints_useParameterNames: bool    = "false",
         /// Maximum length for inlay hints. Set to null to have an unlimited length.
         inlayHints_maxLength: Option<usize>                        = "25",
+        /// Whether to show the size and alignment of structs, enums and unions, and the offsets of
+        /// their fields.
+        inlayHints_memoryLayoutHints_enable: bool                  = "false",
         /// Whether to show function parameter name inlay hints at the call
         /// site.
         inlayHints_parameterHints_enable: bool                     = "true",
@@ -1207,6 +1212,7 @@ pub fn inlay_hints(&self) -> InlayHintsConfig {
                 .data
                 .inlayHints_lifetimeElisionHints_useParameterNames,
             max_length: self.data.inlayHints_maxLength,
+            memory_layout_hints: self.data.inlayHints_memoryLayoutHints_enable,
             closing_brace_hints_min_lines: if self.data.inlayHints_closingBraceHints_enable {
                 Some(self.data.inlayHints_closingBraceHints_minLines)
             } else {
@@ -1356,6 +1362,7 @@ pub fn hover(&self) -> HoverConfig {
                 }
             }),
             keywords: self.data.hover_documentation_keywords_enable,
+            memory_layout: self.data.hover_memoryLayout_enable,
         }
     }
 
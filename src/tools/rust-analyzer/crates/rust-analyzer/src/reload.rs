@@ -215,11 +215,12 @@ fn eq_ignore_build_data<'a>(
                     sysroot,
                     rustc,
                     rustc_cfg,
+                    target_layout,
                     cfg_overrides,
 
                     build_scripts: _,
                     toolchain: _,
-                } => Some((cargo, sysroot, rustc, rustc_cfg, cfg_overrides)),
+                } => Some((cargo, sysroot, rustc, rustc_cfg, target_layout, cfg_overrides)),
                 _ => None,
             };
             match (key(left), key(right)) {
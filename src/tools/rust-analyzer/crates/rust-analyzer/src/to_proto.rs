@@ -449,11 +449,14 @@ pub(crate) fn inlay_hint(
             | InlayKind::ChainingHint
             | InlayKind::GenericParamListHint
             | InlayKind::LifetimeHint
+            | InlayKind::MemoryLayoutHint
             | InlayKind::ClosingBraceHint => position(line_index, inlay_hint.range.end()),
         },
         padding_left: Some(match inlay_hint.kind {
             InlayKind::TypeHint => !render_colons,
-            InlayKind::ChainingHint | InlayKind::ClosingBraceHint => true,
+            InlayKind::ChainingHint | InlayKind::ClosingBraceHint | InlayKind::MemoryLayoutHint => {
+                true
+            }
             InlayKind::BindingModeHint
             | InlayKind::ClosureReturnTypeHint
             | InlayKind::GenericParamListHint
@@ -467,7 +470,8 @@ pub(crate) fn inlay_hint(
             | InlayKind::GenericParamListHint
             | InlayKind::ImplicitReborrowHint
             | InlayKind::TypeHint
-            | InlayKind::ClosingBraceHint => false,
+            | InlayKind::ClosingBraceHint
+            | InlayKind::MemoryLayoutHint => false,
             InlayKind::BindingModeHint => inlay_hint.label.as_simple_str() != Some("&"),
             InlayKind::ParameterHint | InlayKind::LifetimeHint => true,
         }),
@@ -480,7 +484,8 @@ pub(crate) fn inlay_hint(
             | InlayKind::GenericParamListHint
             | InlayKind::LifetimeHint
             | InlayKind::ImplicitReborrowHint
-            | InlayKind::ClosingBraceHint => None,
+            | InlayKind::ClosingBraceHint
+            | InlayKind::MemoryLayoutHint => None,
         },
         text_edits: None,
         data: (|| match inlay_hint.tooltip {